@@ -1,5 +1,5 @@
 use thiserror::Error;
-use crate::Validator;
+use crate::{ErrorBudget, ErrorCode, ErrorList, ErrorPath, ValidationError, Validator, ValidatorWithContext};
 
 #[derive(Debug, Default, PartialEq)]
 pub struct And<A, B>(A, B);
@@ -24,6 +24,36 @@ impl<A, B> From<AndError<A, B>> for (Option<A>, Option<B>) {
     }
 }
 
+impl<A: ValidationError, B: ValidationError> ValidationError for AndError<A, B> {
+    fn collect_errors<'a>(&'a self, path: &mut ErrorPath, errors: &mut ErrorList<'a>) {
+        match self {
+            AndError::Left(left) => left.collect_errors(path, errors),
+            AndError::Right(right) => right.collect_errors(path, errors),
+            AndError::Both(left, right) => {
+                left.collect_errors(path, errors);
+                right.collect_errors(path, errors);
+            }
+        }
+    }
+}
+
+// the code and parameters are the ones of the first rule which failed
+impl<A: ErrorCode, B: ErrorCode> ErrorCode for AndError<A, B> {
+    fn code(&self) -> &str {
+        match self {
+            AndError::Left(left) | AndError::Both(left, _) => left.code(),
+            AndError::Right(right) => right.code(),
+        }
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        match self {
+            AndError::Left(left) | AndError::Both(left, _) => left.params(),
+            AndError::Right(right) => right.params(),
+        }
+    }
+}
+
 impl<A, B, T> Validator<T> for And<A, B> where A: Validator<T>, B: Validator<T> {
     type Error = AndError<A::Error, B::Error>;
 
@@ -35,6 +65,43 @@ impl<A, B, T> Validator<T> for And<A, B> where A: Validator<T>, B: Validator<T>
             (Err(left), Err(right)) => Err(AndError::Both(left, right))
         }
     }
+
+    fn validate_budgeted(&self, value: &T, budget: &mut ErrorBudget) -> Result<(), Self::Error> {
+        let left = self.0.validate_budgeted(value, budget);
+        match (left, self.1.validate_budgeted(value, budget)) {
+            (Ok(()), Ok(())) => Ok(()),
+            (Err(left), Ok(())) => Err(AndError::Left(left)),
+            (Ok(()), Err(right)) => Err(AndError::Right(right)),
+            (Err(left), Err(right)) => Err(AndError::Both(left, right))
+        }
+    }
+}
+
+impl<A, B, T, C: ?Sized> ValidatorWithContext<T, C> for And<A, B> where A: ValidatorWithContext<T, C>, B: ValidatorWithContext<T, C> {
+    type Error = AndError<A::Error, B::Error>;
+
+    fn validate_with(&self, value: &T, context: &C) -> Result<(), Self::Error> {
+        match (self.0.validate_with(value, context), self.1.validate_with(value, context)) {
+            (Ok(()), Ok(())) => Ok(()),
+            (Err(left), Ok(())) => Err(AndError::Left(left)),
+            (Ok(()), Err(right)) => Err(AndError::Right(right)),
+            (Err(left), Err(right)) => Err(AndError::Both(left, right))
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl<A, B, T> crate::AsyncValidator<T> for And<A, B> where A: crate::AsyncValidator<T>, B: crate::AsyncValidator<T> {
+    type Error = AndError<A::Error, B::Error>;
+
+    async fn validate_async(&self, value: &T) -> Result<(), Self::Error> {
+        match futures::join!(self.0.validate_async(value), self.1.validate_async(value)) {
+            (Ok(()), Ok(())) => Ok(()),
+            (Err(left), Ok(())) => Err(AndError::Left(left)),
+            (Ok(()), Err(right)) => Err(AndError::Right(right)),
+            (Err(left), Err(right)) => Err(AndError::Both(left, right))
+        }
+    }
 }
 
 impl<A, B> And<A, B> {
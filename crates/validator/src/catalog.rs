@@ -0,0 +1,256 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use thiserror::Error;
+
+use crate::{render_message, ErrorPath, LeafError, ValidationError};
+
+/// Renders the messages of errors in a given locale, the messages being found by the codes of the errors
+/// (see [ErrorCode](crate::ErrorCode)) and the parameters of the errors being interpolated into them.
+pub trait MessageCatalog {
+    /// Renders the message with the given id in the given locale, if the catalog has it
+    fn message(&self, locale: &str, id: &str, params: &[(&'static str, String)]) -> Option<String>;
+
+    /// Renders the message of an error, falling back to the message of the error itself
+    fn render(&self, locale: &str, error: &dyn LeafError) -> String {
+        self.message(locale, error.code(), &error.params()).unwrap_or_else(|| error.to_string())
+    }
+
+    /// Renders the messages of all the errors found in an error, along with their paths
+    fn render_all(&self, locale: &str, error: &dyn ValidationError) -> Vec<(ErrorPath, String)> {
+        error.errors().into_iter()
+            .map(|(path, error)| (path, self.render(locale, error)))
+            .collect()
+    }
+
+    /// Looks the messages missing from this catalog up in another one
+    fn or<C: MessageCatalog>(self, fallback: C) -> FallbackCatalog<Self, C>
+    where
+        Self: Sized
+    {
+        FallbackCatalog(self, fallback)
+    }
+}
+
+/// The English messages of the built-in errors, used for any locale
+#[derive(Debug, Default, Clone, Copy)]
+pub struct EnglishCatalog;
+
+/// A catalog looking the messages missing from the first catalog up in the second one
+#[derive(Debug, Clone)]
+pub struct FallbackCatalog<A, B>(A, B);
+
+/// A catalog loaded from files of `id = message` lines, one for each locale.
+///
+/// Empty lines and lines starting with `#` are ignored, the parameters of the errors are interpolated
+/// into the messages as `{name}`.
+#[derive(Debug, Default, Clone)]
+pub struct KeyValueCatalog {
+    locales: HashMap<String, HashMap<String, String>>,
+}
+
+#[derive(Debug, Error)]
+pub enum CatalogError {
+    #[error("failed to read the catalog: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("line {line} is not of the form `id = message`")]
+    Syntax {
+        line: usize,
+    },
+    #[error("invalid locale: {0}")]
+    Locale(String),
+    #[error("failed to parse the Fluent resource: {0}")]
+    Fluent(String),
+}
+
+impl MessageCatalog for EnglishCatalog {
+    fn message(&self, _: &str, id: &str, params: &[(&'static str, String)]) -> Option<String> {
+        let message = match id {
+            "not_empty" => "Value should not be empty",
+            "required" => "Value is required",
+            "email" => "Value is not a valid email address",
+            "url" => "Value is not a valid URL",
+            "ip" => "Value is not a valid IP address",
+            "length.too_long" => "value of length {len} exceeds maximum of {max}",
+            "length.too_short" => "value of length {len} falls short of minimum of {min}",
+            "chars.not_allowed" => "Character {character} at position {char_offset} is not allowed",
+            "chars.whitespace" => "Value should not start or end with whitespace",
+            "range" => "Value is not in the allowed range",
+            "must_match" => "{field} does not match {other}",
+            "required_if" => "{field} is required when {condition} is {value}",
+            "exactly_one_of" => "exactly one of {fields} must be set, found {present}",
+            "at_least_one_of" => "at least one of {fields} must be set",
+            "type" => "Value of type {found} should be of type {expected}",
+            "enum" => "Value should be one of {allowed}",
+            "not_allowed" => "Value is not allowed",
+            "pattern" => "Value does not match the pattern {pattern}",
+            "contains" => "Value should contain {substring}",
+            "starts_with" => "Value should start with {prefix}",
+            "ends_with" => "Value should end with {suffix}",
+            "none_of" => "Value should not be one of {forbidden}",
+            "one_of" => "Value matches {matched} of the alternatives instead of exactly one",
+            "not" => "Value should not satisfy {rule}",
+            _ => return None,
+        };
+        Some(render_message(message, params))
+    }
+}
+
+impl<A: MessageCatalog, B: MessageCatalog> MessageCatalog for FallbackCatalog<A, B> {
+    fn message(&self, locale: &str, id: &str, params: &[(&'static str, String)]) -> Option<String> {
+        self.0.message(locale, id, params).or_else(|| self.1.message(locale, id, params))
+    }
+}
+
+impl KeyValueCatalog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the messages of a locale, such as `fr` or `pt-BR`, from the content of a catalog file
+    pub fn with_messages(mut self, locale: &str, source: &str) -> Result<Self, CatalogError> {
+        let messages = self.locales.entry(locale.to_string()).or_default();
+        for (index, line) in source.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (id, message) = line.split_once('=').ok_or(CatalogError::Syntax { line: index + 1 })?;
+            messages.insert(id.trim().to_string(), message.trim().to_string());
+        }
+        Ok(self)
+    }
+
+    /// Adds the messages of a locale from a catalog file
+    pub fn with_file(self, locale: &str, path: impl AsRef<Path>) -> Result<Self, CatalogError> {
+        let source = std::fs::read_to_string(path)?;
+        self.with_messages(locale, &source)
+    }
+}
+
+impl MessageCatalog for KeyValueCatalog {
+    fn message(&self, locale: &str, id: &str, params: &[(&'static str, String)]) -> Option<String> {
+        locale_fallbacks(locale)
+            .filter_map(|locale| self.locales.get(locale))
+            .find_map(|messages| messages.get(id))
+            .map(|message| render_message(message, params))
+    }
+}
+
+/// The locale followed by the more general locales it falls back to, such as `pt-BR` then `pt`
+fn locale_fallbacks(locale: &str) -> impl Iterator<Item=&str> {
+    std::iter::successors(Some(locale), |locale| {
+        locale.rfind(['-', '_']).map(|end| &locale[..end])
+    })
+}
+
+#[cfg(feature = "fluent")]
+pub use fluent::FluentCatalog;
+
+#[cfg(feature = "fluent")]
+mod fluent {
+    use std::collections::HashMap;
+    use std::path::Path;
+
+    use fluent_bundle::{FluentArgs, FluentBundle, FluentResource, FluentValue};
+
+    use crate::{CatalogError, MessageCatalog};
+
+    /// A catalog loaded from [Fluent](https://projectfluent.org) resources, one for each locale.
+    ///
+    /// Fluent identifiers cannot contain dots, so the messages are looked up with the dots of the codes
+    /// replaced by dashes, such as `length-too_long`. The parameters are passed as variables, such as `{ $min }`,
+    /// numbers being passed as numbers so that they can select plural forms.
+    #[derive(Default)]
+    pub struct FluentCatalog {
+        bundles: HashMap<String, FluentBundle<FluentResource>>,
+    }
+
+    impl FluentCatalog {
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Adds the messages of a locale, such as `fr` or `pt-BR`, from the content of a Fluent resource
+        pub fn with_messages(mut self, locale: &str, source: &str) -> Result<Self, CatalogError> {
+            let language = locale.parse().map_err(|_| CatalogError::Locale(locale.to_string()))?;
+            let resource = FluentResource::try_new(source.to_string())
+                .map_err(|(_, errors)| CatalogError::Fluent(format!("{errors:?}")))?;
+            let bundle = self.bundles.entry(locale.to_string()).or_insert_with(|| {
+                let mut bundle = FluentBundle::new(vec![language]);
+                bundle.set_use_isolating(false);
+                bundle
+            });
+            bundle.add_resource(resource).map_err(|errors| CatalogError::Fluent(format!("{errors:?}")))?;
+            Ok(self)
+        }
+
+        /// Adds the messages of a locale from a Fluent file
+        pub fn with_file(self, locale: &str, path: impl AsRef<Path>) -> Result<Self, CatalogError> {
+            let source = std::fs::read_to_string(path)?;
+            self.with_messages(locale, &source)
+        }
+    }
+
+    impl MessageCatalog for FluentCatalog {
+        fn message(&self, locale: &str, id: &str, params: &[(&'static str, String)]) -> Option<String> {
+            let id = id.replace('.', "-");
+            let args: FluentArgs = params.iter()
+                .map(|(name, value)| (*name, FluentValue::try_number(value)))
+                .collect();
+            super::locale_fallbacks(locale)
+                .filter_map(|locale| self.bundles.get(locale))
+                .find_map(|bundle| {
+                    let pattern = bundle.get_message(&id)?.value()?;
+                    let mut errors = Vec::new();
+                    Some(bundle.format_pattern(pattern, Some(&args), &mut errors).into_owned())
+                })
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{EnglishCatalog, KeyValueCatalog, LengthValidator, MessageCatalog, NotEmptyValidator, Validator};
+
+    fn catalog() -> KeyValueCatalog {
+        KeyValueCatalog::new()
+            .with_messages("fr", "# French\nnot_empty = La valeur ne doit pas être vide\n\nlength.too_short = Au moins {min} caractères")
+            .unwrap()
+    }
+
+    #[test]
+    fn renders_messages_for_a_locale() {
+        let error = LengthValidator::new(Some(8), None).validate("secret").unwrap_err();
+        assert_eq!(catalog().render("fr", &error), "Au moins 8 caractères");
+        assert_eq!(catalog().render("fr-CA", &error), "Au moins 8 caractères");
+        // the message of the error itself is used when neither the catalog nor the locale have it
+        assert_eq!(catalog().render("de", &error), "value of length 6 falls short of minimum of 8");
+    }
+
+    #[test]
+    fn falls_back_to_another_catalog() {
+        let error = NotEmptyValidator.validate("").unwrap_err();
+        let catalog = KeyValueCatalog::new().or(EnglishCatalog);
+        assert_eq!(catalog.render("fr", &error), "Value should not be empty");
+        assert_eq!(self::catalog().or(EnglishCatalog).render("fr", &error), "La valeur ne doit pas être vide");
+    }
+
+    #[test]
+    fn rejects_invalid_lines() {
+        let error = KeyValueCatalog::new().with_messages("fr", "not_empty = vide\nnot a message").unwrap_err();
+        assert_eq!(error.to_string(), "line 2 is not of the form `id = message`");
+    }
+
+    #[cfg(feature = "fluent")]
+    #[test]
+    fn fluent() {
+        let catalog = crate::FluentCatalog::new()
+            .with_messages("en", "length-too_short = { $min ->\n    [one] Use at least one character\n   *[other] Use at least { $min } characters\n}")
+            .unwrap();
+        let error = LengthValidator::new(Some(1), None).validate("").unwrap_err();
+        assert_eq!(catalog.render("en-GB", &error), "Use at least one character");
+        let error = LengthValidator::new(Some(8), None).validate("secret").unwrap_err();
+        assert_eq!(catalog.render("en", &error), "Use at least 8 characters");
+    }
+}
@@ -0,0 +1,191 @@
+use std::fmt::{Display, Formatter};
+
+use thiserror::Error;
+
+use crate::{ErrorCode, Validator};
+
+/// Validates the characters of strings, each of them having to be in every class unless it is one of the extra
+/// characters, such as `#[validator(chars(ascii, alphanumeric, extra = "-_"))]` for ASCII letters, digits, dashes
+/// and underscores
+#[derive(Debug, Clone, Default)]
+pub struct CharsValidator {
+    classes: Vec<CharClass>,
+    extra: String,
+    trimmed: bool,
+}
+
+/// A class of characters
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum CharClass {
+    Ascii,
+    Alphabetic,
+    Alphanumeric,
+    Numeric,
+    /// Characters which are not uppercase, such as lowercase letters, digits and punctuation
+    Lowercase,
+    /// Characters which are not lowercase, such as uppercase letters, digits and punctuation
+    Uppercase,
+    /// Characters which are not control characters, such as newlines and tabs
+    NonControl,
+    /// Characters which are neither control characters nor whitespace other than spaces
+    Printable,
+}
+
+/// The first character which is not allowed, along with its offset in bytes and in characters
+#[derive(Debug, Error, PartialEq, Clone)]
+pub enum InvalidCharError {
+    #[error("character {character:?} at position {char_offset} is not allowed, expected {class} characters")]
+    NotAllowed {
+        character: char,
+        byte_offset: usize,
+        char_offset: usize,
+        class: CharClass,
+    },
+    #[error("value should not start or end with whitespace, found {character:?} at position {char_offset}")]
+    Whitespace {
+        character: char,
+        byte_offset: usize,
+        char_offset: usize,
+    },
+}
+
+impl CharClass {
+    pub fn contains(self, character: char) -> bool {
+        match self {
+            CharClass::Ascii => character.is_ascii(),
+            CharClass::Alphabetic => character.is_alphabetic(),
+            CharClass::Alphanumeric => character.is_alphanumeric(),
+            CharClass::Numeric => character.is_numeric(),
+            CharClass::Lowercase => !character.is_uppercase(),
+            CharClass::Uppercase => !character.is_lowercase(),
+            CharClass::NonControl => !character.is_control(),
+            CharClass::Printable => !character.is_control() && (character == ' ' || !character.is_whitespace()),
+        }
+    }
+}
+
+impl Display for CharClass {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CharClass::Ascii => write!(f, "ASCII"),
+            CharClass::Alphabetic => write!(f, "alphabetic"),
+            CharClass::Alphanumeric => write!(f, "alphanumeric"),
+            CharClass::Numeric => write!(f, "numeric"),
+            CharClass::Lowercase => write!(f, "lowercase"),
+            CharClass::Uppercase => write!(f, "uppercase"),
+            CharClass::NonControl => write!(f, "non-control"),
+            CharClass::Printable => write!(f, "printable"),
+        }
+    }
+}
+
+impl CharsValidator {
+    pub fn new(classes: impl IntoIterator<Item=CharClass>) -> Self {
+        Self { classes: classes.into_iter().collect(), ..Self::default() }
+    }
+
+    /// Allows the given characters whatever their classes
+    pub fn with_extra(self, extra: impl Into<String>) -> Self {
+        Self { extra: extra.into(), ..self }
+    }
+
+    /// Rejects strings starting or ending with whitespace
+    pub fn trimmed(self) -> Self {
+        Self { trimmed: true, ..self }
+    }
+}
+
+impl InvalidCharError {
+    pub fn character(&self) -> char {
+        match self {
+            InvalidCharError::NotAllowed { character, .. } | InvalidCharError::Whitespace { character, .. } => *character,
+        }
+    }
+
+    pub fn byte_offset(&self) -> usize {
+        match self {
+            InvalidCharError::NotAllowed { byte_offset, .. } | InvalidCharError::Whitespace { byte_offset, .. } => *byte_offset,
+        }
+    }
+
+    pub fn char_offset(&self) -> usize {
+        match self {
+            InvalidCharError::NotAllowed { char_offset, .. } | InvalidCharError::Whitespace { char_offset, .. } => *char_offset,
+        }
+    }
+}
+
+impl Validator<str> for CharsValidator {
+    type Error = InvalidCharError;
+
+    fn validate(&self, value: &str) -> Result<(), Self::Error> {
+        let count = if self.trimmed { value.chars().count() } else { 0 };
+        for (char_offset, (byte_offset, character)) in value.char_indices().enumerate() {
+            if self.trimmed && (char_offset == 0 || char_offset + 1 == count) && character.is_whitespace() {
+                return Err(InvalidCharError::Whitespace { character, byte_offset, char_offset });
+            }
+            if self.extra.contains(character) {
+                continue;
+            }
+            if let Some(&class) = self.classes.iter().find(|class| !class.contains(character)) {
+                return Err(InvalidCharError::NotAllowed { character, byte_offset, char_offset, class });
+            }
+        }
+        Ok(())
+    }
+}
+
+leaf_validator!(CharsValidator);
+leaf_error!(InvalidCharError);
+
+impl ErrorCode for InvalidCharError {
+    fn code(&self) -> &str {
+        match self {
+            InvalidCharError::NotAllowed { .. } => "chars.not_allowed",
+            InvalidCharError::Whitespace { .. } => "chars.whitespace",
+        }
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        let mut params = vec![
+            ("character", self.character().to_string()),
+            ("byte_offset", self.byte_offset().to_string()),
+            ("char_offset", self.char_offset().to_string()),
+        ];
+        if let InvalidCharError::NotAllowed { class, .. } = self {
+            params.push(("class", class.to_string()));
+        }
+        params
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{CharClass, CharsValidator, InvalidCharError, Validator};
+
+    #[test]
+    fn classes() {
+        let validator = CharsValidator::new([CharClass::Ascii, CharClass::Alphanumeric]).with_extra("-_");
+        validator.validate("snake_case-42").expect("should be valid");
+        assert_eq!(validator.validate("café au lait"), Err(InvalidCharError::NotAllowed {
+            character: 'é',
+            byte_offset: 3,
+            char_offset: 3,
+            class: CharClass::Ascii,
+        }));
+        let error = validator.validate("hello world").unwrap_err();
+        assert_eq!(error.to_string(), "character ' ' at position 5 is not allowed, expected alphanumeric characters");
+
+        CharsValidator::new([CharClass::Lowercase]).validate("user_42").expect("should be valid");
+        CharsValidator::new([CharClass::Printable]).validate("two\nlines").expect_err("should be invalid");
+    }
+
+    #[test]
+    fn trimmed() {
+        let validator = CharsValidator::default().trimmed();
+        validator.validate("a b").expect("should be valid");
+        validator.validate("").expect("should be valid");
+        assert_eq!(validator.validate("ü b ").unwrap_err().byte_offset(), 4);
+        assert_eq!(validator.validate(" b").unwrap_err().char_offset(), 0);
+    }
+}
@@ -0,0 +1,182 @@
+use std::borrow::Cow;
+
+use thiserror::Error;
+
+use crate::{ErrorCode, Validator};
+
+/// Validates that strings contain a substring, such as `#[validator(contains = "@")]`
+#[derive(Debug, Clone)]
+pub struct ContainsValidator {
+    substring: String,
+    ignore_case: bool,
+}
+
+/// Validates that strings start with a prefix, such as `#[validator(starts_with = "sk_")]`
+#[derive(Debug, Clone)]
+pub struct PrefixValidator {
+    prefix: String,
+    ignore_case: bool,
+}
+
+/// Validates that strings end with a suffix, such as `#[validator(ends_with(".com", ignore_case))]`
+#[derive(Debug, Clone)]
+pub struct SuffixValidator {
+    suffix: String,
+    ignore_case: bool,
+}
+
+#[derive(Debug, PartialEq, Clone, Error)]
+#[error("Value should contain {substring}")]
+pub struct MissingSubstringError {
+    pub substring: String,
+}
+
+#[derive(Debug, PartialEq, Clone, Error)]
+#[error("Value should start with {prefix}")]
+pub struct MissingPrefixError {
+    pub prefix: String,
+}
+
+#[derive(Debug, PartialEq, Clone, Error)]
+#[error("Value should end with {suffix}")]
+pub struct MissingSuffixError {
+    pub suffix: String,
+}
+
+/// Lowercases the string if the case is ignored, so that it can be compared with other lowercased strings
+fn fold_case(value: &str, ignore_case: bool) -> Cow<'_, str> {
+    if ignore_case {
+        Cow::Owned(value.to_lowercase())
+    } else {
+        Cow::Borrowed(value)
+    }
+}
+
+impl ContainsValidator {
+    pub fn new(substring: impl Into<String>) -> Self {
+        Self { substring: substring.into(), ignore_case: false }
+    }
+
+    /// Ignores the case of the strings, comparing their lowercase forms
+    pub fn ignore_case(self) -> Self {
+        Self { ignore_case: true, ..self }
+    }
+}
+
+impl PrefixValidator {
+    pub fn new(prefix: impl Into<String>) -> Self {
+        Self { prefix: prefix.into(), ignore_case: false }
+    }
+
+    /// Ignores the case of the strings, comparing their lowercase forms
+    pub fn ignore_case(self) -> Self {
+        Self { ignore_case: true, ..self }
+    }
+}
+
+impl SuffixValidator {
+    pub fn new(suffix: impl Into<String>) -> Self {
+        Self { suffix: suffix.into(), ignore_case: false }
+    }
+
+    /// Ignores the case of the strings, comparing their lowercase forms
+    pub fn ignore_case(self) -> Self {
+        Self { ignore_case: true, ..self }
+    }
+}
+
+impl Validator<str> for ContainsValidator {
+    type Error = MissingSubstringError;
+
+    fn validate(&self, value: &str) -> Result<(), Self::Error> {
+        if fold_case(value, self.ignore_case).contains(&*fold_case(&self.substring, self.ignore_case)) {
+            Ok(())
+        } else {
+            Err(MissingSubstringError { substring: self.substring.clone() })
+        }
+    }
+}
+
+impl Validator<str> for PrefixValidator {
+    type Error = MissingPrefixError;
+
+    fn validate(&self, value: &str) -> Result<(), Self::Error> {
+        if fold_case(value, self.ignore_case).starts_with(&*fold_case(&self.prefix, self.ignore_case)) {
+            Ok(())
+        } else {
+            Err(MissingPrefixError { prefix: self.prefix.clone() })
+        }
+    }
+}
+
+impl Validator<str> for SuffixValidator {
+    type Error = MissingSuffixError;
+
+    fn validate(&self, value: &str) -> Result<(), Self::Error> {
+        if fold_case(value, self.ignore_case).ends_with(&*fold_case(&self.suffix, self.ignore_case)) {
+            Ok(())
+        } else {
+            Err(MissingSuffixError { suffix: self.suffix.clone() })
+        }
+    }
+}
+
+leaf_validator!(ContainsValidator);
+leaf_validator!(PrefixValidator);
+leaf_validator!(SuffixValidator);
+leaf_error!(MissingSubstringError);
+leaf_error!(MissingPrefixError);
+leaf_error!(MissingSuffixError);
+
+impl ErrorCode for MissingSubstringError {
+    fn code(&self) -> &str {
+        "contains"
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        vec![("substring", self.substring.clone())]
+    }
+}
+
+impl ErrorCode for MissingPrefixError {
+    fn code(&self) -> &str {
+        "starts_with"
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        vec![("prefix", self.prefix.clone())]
+    }
+}
+
+impl ErrorCode for MissingSuffixError {
+    fn code(&self) -> &str {
+        "ends_with"
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        vec![("suffix", self.suffix.clone())]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{ContainsValidator, MissingPrefixError, MissingSubstringError, PrefixValidator, SuffixValidator, Validator};
+
+    #[test]
+    fn substrings() {
+        ContainsValidator::new("@").validate("someone@example.com").expect("should be valid");
+        assert_eq!(ContainsValidator::new("@").validate("someone"), Err(MissingSubstringError { substring: "@".to_string() }));
+        PrefixValidator::new("sk_").validate("sk_live_42").expect("should be valid");
+        assert_eq!(PrefixValidator::new("sk_").validate("pk_live_42"), Err(MissingPrefixError { prefix: "sk_".to_string() }));
+        SuffixValidator::new(".com").validate("example.com").expect("should be valid");
+        SuffixValidator::new(".com").validate("example.org").expect_err("should be invalid");
+    }
+
+    #[test]
+    fn ignore_case() {
+        PrefixValidator::new("sk_").validate("SK_LIVE_42").expect_err("should be invalid");
+        PrefixValidator::new("sk_").ignore_case().validate("SK_LIVE_42").expect("should be valid");
+        ContainsValidator::new("Straße").ignore_case().validate("HAUPTSTRASSE straße 1").expect("should be valid");
+        SuffixValidator::new(".COM").ignore_case().validate("example.com").expect("should be valid");
+    }
+}
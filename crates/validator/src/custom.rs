@@ -0,0 +1,73 @@
+use std::borrow::Cow;
+use std::fmt::Debug;
+use thiserror::Error;
+use crate::{ErrorCode, Validator};
+
+/// A validator which calls a function, such as `fn check_username(value: &String) -> Result<(), CustomError>`.
+///
+/// The derive creates one for the `custom = check_username` rule, whose function returns a [CustomError], and for
+/// the `custom(check_username, error = UsernameTaken)` rule, whose function returns `Result<(), UsernameTaken>`,
+/// `UsernameTaken` then being the error of the field.
+pub struct FnValidator<T: ?Sized, E>(fn(&T) -> Result<(), E>);
+
+/// An error for custom validation rules which do not need an error type of their own
+#[derive(Debug, PartialEq, Eq, Clone, Error)]
+#[error("{message}")]
+pub struct CustomError {
+    message: Cow<'static, str>,
+}
+
+impl CustomError {
+    pub fn new(message: impl Into<Cow<'static, str>>) -> Self {
+        Self { message: message.into() }
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl<T: ?Sized, E> FnValidator<T, E> {
+    pub fn new(function: fn(&T) -> Result<(), E>) -> Self {
+        Self(function)
+    }
+}
+
+impl<T: ?Sized, E: Debug> Validator<T> for FnValidator<T, E> {
+    type Error = E;
+
+    fn validate(&self, value: &T) -> Result<(), Self::Error> {
+        (self.0)(value)
+    }
+}
+
+leaf_validator!(<V: ?Sized, E> FnValidator<V, E>);
+leaf_error!(CustomError);
+
+impl ErrorCode for CustomError {
+    fn code(&self) -> &str {
+        "custom"
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{CustomError, FnValidator, Validator};
+
+    fn no_spaces(value: &str) -> Result<(), CustomError> {
+        if value.contains(' ') {
+            Err(CustomError::new("must not contain spaces"))
+        } else {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn calls_function() {
+        let validator = FnValidator::new(no_spaces);
+        validator.validate("username").expect("should be valid");
+        let error = validator.validate("user name").unwrap_err();
+        assert_eq!(error.to_string(), "must not contain spaces");
+        validator.validate(&"user name".to_string()).expect_err("strings should be validated as str");
+    }
+}
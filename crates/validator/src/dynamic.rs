@@ -0,0 +1,217 @@
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+
+use crate::{ErrorBudget, ErrorCode, ErrorList, ErrorPath, ValidationError, Validator};
+
+/// A validator whose type and error type are erased, so that validators of different types can be stored together,
+/// such as the rules configured for each customer:
+/// ```
+/// # use validator::{ContainsValidator, DynValidator, EmailValidator, LengthValidator, Validator};
+/// let rules: Vec<DynValidator<str>> = vec![
+///     DynValidator::new(EmailValidator),
+///     DynValidator::new(LengthValidator::new(None, Some(64))),
+///     DynValidator::new(ContainsValidator::new("@example.com")),
+/// ];
+/// let validator = DynValidator::all(rules);
+/// assert_eq!(validator.validate("someone@example.org").unwrap_err().to_string(), "Value should contain @example.com");
+/// ```
+///
+/// Its errors are flattened into a [ValidationFailure]. Being validated synchronously, it does not use any context.
+pub struct DynValidator<T: ?Sized>(Box<dyn ErasedValidator<T> + Send + Sync>);
+
+/// The errors found by a [DynValidator], along with their paths
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct ValidationFailure {
+    errors: Vec<(ErrorPath, FailedRule)>,
+}
+
+/// An error found by a [DynValidator], made of the code, the parameters and the message of the original error
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct FailedRule {
+    code: String,
+    params: Vec<(&'static str, String)>,
+    message: String,
+}
+
+// object-safe counterpart of `Validator`, whose error type is the same for every validator
+trait ErasedValidator<T: ?Sized> {
+    fn validate_erased(&self, value: &T, budget: &mut ErrorBudget) -> Result<(), ValidationFailure>;
+}
+
+impl<T: ?Sized, V> ErasedValidator<T> for V where V: Validator<T>, V::Error: ValidationError {
+    fn validate_erased(&self, value: &T, budget: &mut ErrorBudget) -> Result<(), ValidationFailure> {
+        self.validate_budgeted(value, budget).map_err(|error| ValidationFailure::new(&error))
+    }
+}
+
+// validates values with every validator of a rule set, merging their errors
+struct AllValidators<T: ?Sized>(Vec<DynValidator<T>>);
+
+impl<T: ?Sized> Validator<T> for AllValidators<T> {
+    type Error = ValidationFailure;
+
+    fn validate(&self, value: &T) -> Result<(), Self::Error> {
+        self.validate_budgeted(value, &mut ErrorBudget::unlimited())
+    }
+
+    fn validate_budgeted(&self, value: &T, budget: &mut ErrorBudget) -> Result<(), Self::Error> {
+        let mut failure = ValidationFailure::default();
+        for validator in &self.0 {
+            if budget.is_exhausted() {
+                break;
+            }
+            if let Err(error) = validator.validate_budgeted(value, budget) {
+                failure.errors.extend(error.errors);
+            }
+        }
+        if failure.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(failure)
+        }
+    }
+}
+
+impl<T: ?Sized> DynValidator<T> {
+    pub fn new<V>(validator: V) -> Self
+    where
+        V: Validator<T> + Send + Sync + 'static,
+        V::Error: ValidationError
+    {
+        Self(Box::new(validator))
+    }
+}
+
+impl<T: ?Sized + 'static> DynValidator<T> {
+    /// Validates values with every validator, reporting the errors of all of them
+    pub fn all(validators: impl IntoIterator<Item=DynValidator<T>>) -> Self {
+        Self::new(AllValidators(validators.into_iter().collect()))
+    }
+}
+
+impl<T: ?Sized> Debug for DynValidator<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DynValidator").finish_non_exhaustive()
+    }
+}
+
+impl<T: ?Sized> Validator<T> for DynValidator<T> {
+    type Error = ValidationFailure;
+
+    fn validate(&self, value: &T) -> Result<(), Self::Error> {
+        self.0.validate_erased(value, &mut ErrorBudget::unlimited())
+    }
+
+    fn validate_budgeted(&self, value: &T, budget: &mut ErrorBudget) -> Result<(), Self::Error> {
+        self.0.validate_erased(value, budget)
+    }
+}
+
+leaf_validator!(<V: ?Sized> DynValidator<V>);
+
+impl ValidationFailure {
+    /// Flattens the errors found in an error, keeping their paths, codes, parameters and messages
+    pub fn new<E: ValidationError + ?Sized>(error: &E) -> Self {
+        let errors = error.errors().into_iter()
+            .map(|(path, error)| {
+                let rule = FailedRule { code: error.code().to_string(), params: error.params(), message: error.to_string() };
+                (path, rule)
+            })
+            .collect();
+        Self { errors }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item=(&ErrorPath, &FailedRule)> {
+        self.errors.iter().map(|(path, rule)| (path, rule))
+    }
+}
+
+impl FailedRule {
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+// formatted as the errors generated by the derive, as `path: message` separated by semicolons
+impl Display for ValidationFailure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut separator = "";
+        for (path, rule) in &self.errors {
+            if path.is_root() {
+                write!(f, "{separator}{rule}")?;
+            } else {
+                write!(f, "{separator}{path}: {rule}")?;
+            }
+            separator = "; ";
+        }
+        Ok(())
+    }
+}
+
+// the source is the first error found
+impl Error for ValidationFailure {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.errors.first().map(|(_, rule)| rule as _)
+    }
+}
+
+// the paths of the errors are relative to the path of the failure
+impl ValidationError for ValidationFailure {
+    fn collect_errors<'a>(&'a self, path: &mut ErrorPath, errors: &mut ErrorList<'a>) {
+        for (relative, rule) in &self.errors {
+            let mut path = path.clone();
+            for segment in relative.segments() {
+                path.push(segment.clone());
+            }
+            errors.push((path, rule));
+        }
+    }
+}
+
+impl Display for FailedRule {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for FailedRule {}
+
+leaf_error!(FailedRule);
+
+impl ErrorCode for FailedRule {
+    fn code(&self) -> &str {
+        &self.code
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        self.params.clone()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{DynValidator, ElementsValidator, EmailValidator, LengthValidator, NotEmptyValidator, ValidationError, ValidationOptions, Validator};
+
+    #[test]
+    fn erases_errors() {
+        let validator: DynValidator<Vec<String>> = DynValidator::new(ElementsValidator::new(LengthValidator::new(Some(2), None)));
+        validator.validate(&vec!["ab".to_string()]).expect("should be valid");
+        let failure = validator.validate(&vec!["ab".to_string(), "c".to_string()]).unwrap_err();
+        let errors = failure.errors();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].0.to_string(), "[1]");
+        assert_eq!(errors[0].1.code(), "length.too_short");
+        assert!(errors[0].1.params().contains(&("min", "2".to_string())));
+        assert_eq!(failure.to_string(), "[1]: value of length 1 falls short of minimum of 2");
+    }
+
+    #[test]
+    fn merges_rule_sets() {
+        let rules = vec![DynValidator::<str>::new(NotEmptyValidator), DynValidator::new(EmailValidator)];
+        let validator = DynValidator::all(rules);
+        validator.validate("someone@example.com").expect("should be valid");
+        assert_eq!(validator.validate("").unwrap_err().iter().count(), 2);
+        let failure = validator.validate_with_options("", ValidationOptions::fail_fast()).unwrap_err();
+        assert_eq!(failure.iter().count(), 1);
+    }
+}
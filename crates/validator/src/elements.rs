@@ -1,24 +1,165 @@
-use std::ops::Index;
+use std::borrow::Borrow;
+use std::collections::{BTreeSet, HashSet, LinkedList, VecDeque};
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
 
-use crate::{Validate, Validator};
+use crate::{ErrorBudget, ErrorCode, ErrorList, ErrorPath, PathSegment, Validate, ValidationError, Validator, ValidatorWithContext};
 
 pub struct ElementsValidator<V>(V);
 
+/// The errors of the invalid elements of a sequence, along with their positions.
+/// Only the invalid elements are stored, so large collections with few errors stay cheap.
 #[derive(Debug, PartialEq, Clone)]
 pub struct ElementsInvalid<E> {
-    errors: Vec<Option<E>>
+    errors: Vec<(usize, E)>,
+}
+
+/// The errors of the invalid elements of a set, along with the elements themselves, as sets have no positions
+/// by which their elements could be found.
+/// Only the invalid elements are stored, in the order in which the set was iterated.
+#[derive(Debug, PartialEq, Clone)]
+pub struct SetInvalid<T, E> {
+    errors: Vec<(T, E)>,
 }
 
 pub trait HasElements {
     type Item;
+    /// The errors of the invalid elements, [ElementsInvalid] for sequences and [SetInvalid] for sets
+    type Invalid<E: Debug>: Debug;
     fn _iter(&self) -> impl Iterator<Item=&Self::Item>;
+    /// Collects the errors of the elements, given in the order in which they are iterated
+    fn _invalid<'a, E: Debug>(results: impl IntoIterator<Item=(&'a Self::Item, Option<E>)>) -> Result<(), Self::Invalid<E>>
+    where
+        Self::Item: 'a;
+}
+
+impl<E> ElementsInvalid<E> {
+    /// Collects the errors of the elements of a sequence, given in order, `None` standing for a valid element
+    pub fn from_results(results: impl IntoIterator<Item=Option<E>>) -> Result<(), Self> {
+        let errors: Vec<_> = results.into_iter()
+            .enumerate()
+            .filter_map(|(index, error)| Some((index, error?)))
+            .collect();
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(ElementsInvalid { errors })
+        }
+    }
+
+    /// Returns the error of the element at the given position, if it was invalid
+    pub fn get(&self, index: usize) -> Option<&E> {
+        let position = self.errors.binary_search_by_key(&index, |(index, _)| *index).ok()?;
+        Some(&self.errors[position].1)
+    }
+
+    /// Iterates over the invalid elements in order, along with their positions
+    pub fn iter(&self) -> impl Iterator<Item=(usize, &E)> {
+        self.errors.iter().map(|(index, error)| (*index, error))
+    }
+}
+
+impl<T, E> SetInvalid<T, E> {
+    /// Collects the errors of the elements of a set, `None` standing for a valid element
+    pub fn from_results<'a>(results: impl IntoIterator<Item=(&'a T, Option<E>)>) -> Result<(), Self>
+    where
+        T: Clone + 'a
+    {
+        let errors: Vec<_> = results.into_iter()
+            .filter_map(|(element, error)| Some((element.clone(), error?)))
+            .collect();
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(SetInvalid { errors })
+        }
+    }
+
+    /// Returns the error of the given element, if it was invalid
+    pub fn get<Q>(&self, element: &Q) -> Option<&E>
+    where
+        T: Borrow<Q>,
+        Q: PartialEq + ?Sized
+    {
+        self.errors.iter()
+            .find(|(other, _)| other.borrow() == element)
+            .map(|(_, error)| error)
+    }
+
+    /// Iterates over the invalid elements, along with their errors
+    pub fn iter(&self) -> impl Iterator<Item=(&T, &E)> {
+        self.errors.iter().map(|(element, error)| (element, error))
+    }
+}
+
+// formats the errors as `[index]: error`, separated by commas
+pub(crate) fn display_elements<'a>(f: &mut Formatter<'_>, errors: impl IntoIterator<Item=(usize, &'a dyn Display)>) -> std::fmt::Result {
+    let mut separator = "";
+    for (index, error) in errors {
+        write!(f, "{separator}[{index}]: {error}")?;
+        separator = ", ";
+    }
+    Ok(())
+}
+
+impl<E: Display> Display for ElementsInvalid<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        display_elements(f, self.iter().map(|(index, error)| (index, error as &dyn Display)))
+    }
+}
+
+// the source is the error of the first invalid element
+impl<E: Error + 'static> Error for ElementsInvalid<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.iter().next().map(|(_, error)| error as &(dyn Error + 'static))
+    }
 }
 
-impl<E> Index<usize> for ElementsInvalid<E> {
-    type Output = Option<E>;
+impl<E: ValidationError> ValidationError for ElementsInvalid<E> {
+    fn collect_errors<'a>(&'a self, path: &mut ErrorPath, errors: &mut ErrorList<'a>) {
+        for (index, error) in self.iter() {
+            path.collect_nested(PathSegment::Index(index), error, errors);
+        }
+    }
+}
 
-    fn index(&self, index: usize) -> &Self::Output {
-        &self.errors[index]
+impl<E> ErrorCode for ElementsInvalid<E> {
+    fn code(&self) -> &str {
+        "elements"
+    }
+}
+
+// formats the errors as `element: error`, separated by commas
+impl<T: Display, E: Display> Display for SetInvalid<T, E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut separator = "";
+        for (element, error) in &self.errors {
+            write!(f, "{separator}{element}: {error}")?;
+            separator = ", ";
+        }
+        Ok(())
+    }
+}
+
+// the source is the error of the first invalid element
+impl<T: Display + Debug, E: Error + 'static> Error for SetInvalid<T, E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.errors.first().map(|(_, error)| error as &(dyn Error + 'static))
+    }
+}
+
+// the errors of an element are reported at the path of the element itself
+impl<T: Display + Debug, E: ValidationError> ValidationError for SetInvalid<T, E> {
+    fn collect_errors<'a>(&'a self, path: &mut ErrorPath, errors: &mut ErrorList<'a>) {
+        for (element, error) in &self.errors {
+            path.collect_nested(PathSegment::Field(element.to_string().into()), error, errors);
+        }
+    }
+}
+
+impl<T, E> ErrorCode for SetInvalid<T, E> {
+    fn code(&self) -> &str {
+        "elements"
     }
 }
 
@@ -27,17 +168,51 @@ where
     E: HasElements,
     V: Validator<<E as HasElements>::Item>
 {
-    type Error = ElementsInvalid<V::Error>;
+    type Error = <E as HasElements>::Invalid<V::Error>;
 
-    fn validate(&self, slice: &E) -> Result<(), Self::Error> {
-        let errors: Vec<_> = slice._iter().map(|element| {
-            self.0.validate(element).err()
-        }).collect();
-        if errors.iter().all(Option::is_none) {
-            Ok(())
-        } else {
-            Err(ElementsInvalid { errors })
-        }
+    fn validate(&self, collection: &E) -> Result<(), Self::Error> {
+        E::_invalid(collection._iter().map(|element| {
+            (element, self.0.validate(element).err())
+        }))
+    }
+
+    // stops at the first element found once the budget is exhausted
+    fn validate_budgeted(&self, collection: &E, budget: &mut ErrorBudget) -> Result<(), Self::Error> {
+        E::_invalid(collection._iter()
+            .map_while(|element| {
+                (!budget.is_exhausted()).then(|| (element, self.0.validate_budgeted(element, budget).err()))
+            }))
+    }
+}
+
+impl<E, V, C> ValidatorWithContext<E, C> for ElementsValidator<V>
+where
+    E: HasElements,
+    V: ValidatorWithContext<<E as HasElements>::Item, C>,
+    C: ?Sized
+{
+    type Error = <E as HasElements>::Invalid<V::Error>;
+
+    fn validate_with(&self, collection: &E, context: &C) -> Result<(), Self::Error> {
+        E::_invalid(collection._iter().map(|element| {
+            (element, self.0.validate_with(element, context).err())
+        }))
+    }
+}
+
+#[cfg(feature = "async")]
+impl<E, V> crate::AsyncValidator<E> for ElementsValidator<V>
+where
+    E: HasElements,
+    V: crate::AsyncValidator<<E as HasElements>::Item>
+{
+    type Error = <E as HasElements>::Invalid<V::Error>;
+
+    async fn validate_async(&self, collection: &E) -> Result<(), Self::Error> {
+        let results = futures::future::join_all(collection._iter().map(|element| async move {
+            (element, self.0.validate_async(element).await.err())
+        })).await;
+        E::_invalid(results)
     }
 }
 
@@ -47,30 +222,86 @@ impl<V> ElementsValidator<V> {
     }
 }
 
-impl<T> HasElements for [T] {
-    type Item = T;
+// implements HasElements for sequences, whose elements are identified by their positions
+macro_rules! sequence_elements {
+    ($(<$($generic:ident),*> $ty:ty),* $(,)?) => {
+        $(
+        impl<$($generic),*> HasElements for $ty {
+            type Item = T;
+            type Invalid<E: Debug> = ElementsInvalid<E>;
 
-    fn _iter(&self) -> impl Iterator<Item=&Self::Item> {
-        self.iter()
-    }
+            fn _iter(&self) -> impl Iterator<Item=&Self::Item> {
+                self.iter()
+            }
+
+            fn _invalid<'a, E: Debug>(results: impl IntoIterator<Item=(&'a T, Option<E>)>) -> Result<(), Self::Invalid<E>>
+            where
+                T: 'a
+            {
+                ElementsInvalid::from_results(results.into_iter().map(|(_, error)| error))
+            }
+        }
+        )*
+    };
 }
 
-impl<T> HasElements for Vec<T> {
-    type Item = T;
+// implements HasElements for sets, whose elements are identified by themselves
+macro_rules! set_elements {
+    ($(<$($generic:ident),*> $ty:ty),* $(,)?) => {
+        $(
+        impl<T: Clone + Debug, $($generic),*> HasElements for $ty {
+            type Item = T;
+            type Invalid<E: Debug> = SetInvalid<T, E>;
 
-    fn _iter(&self) -> impl Iterator<Item=&Self::Item> {
-        self.iter()
-    }
+            fn _iter(&self) -> impl Iterator<Item=&Self::Item> {
+                self.iter()
+            }
+
+            fn _invalid<'a, E: Debug>(results: impl IntoIterator<Item=(&'a T, Option<E>)>) -> Result<(), Self::Invalid<E>>
+            where
+                T: 'a
+            {
+                SetInvalid::from_results(results)
+            }
+        }
+        )*
+    };
 }
 
+sequence_elements!(
+    <T> [T],
+    <T> Vec<T>,
+    <T> Box<[T]>,
+    <T> VecDeque<T>,
+    <T> LinkedList<T>,
+);
+
 impl<T, const N: usize> HasElements for [T; N] {
     type Item = T;
+    type Invalid<E: Debug> = ElementsInvalid<E>;
 
     fn _iter(&self) -> impl Iterator<Item=&Self::Item> {
         self.iter()
     }
+
+    fn _invalid<'a, E: Debug>(results: impl IntoIterator<Item=(&'a T, Option<E>)>) -> Result<(), Self::Invalid<E>>
+    where
+        T: 'a
+    {
+        ElementsInvalid::from_results(results.into_iter().map(|(_, error)| error))
+    }
 }
 
+set_elements!(
+    <S> HashSet<T, S>,
+    <> BTreeSet<T>,
+);
+
+#[cfg(feature = "indexmap")]
+set_elements!(
+    <S> indexmap::IndexSet<T, S>,
+);
+
 impl<E> Validate for E
 where
     E: HasElements,
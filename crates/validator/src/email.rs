@@ -4,7 +4,7 @@ use regex::Regex;
 use thiserror::Error;
 use crate::ip::IpAddressValidator;
 
-use crate::Validator;
+use crate::{ErrorCode, Validator};
 
 /// Validates whether the given string is an email based on the [HTML5 spec](https://html.spec.whatwg.org/multipage/forms.html#valid-e-mail-address).
 /// [RFC 5322](https://tools.ietf.org/html/rfc5322) is not practical in most circumstances and allows email addresses
@@ -81,4 +81,13 @@ impl Validator<str> for EmailValidator {
         }
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+leaf_validator!(EmailValidator);
+leaf_error!(InvalidEmailError);
+
+impl ErrorCode for InvalidEmailError {
+    fn code(&self) -> &str {
+        "email"
+    }
+}
@@ -0,0 +1,218 @@
+use std::borrow::Cow;
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+
+/// A step along the path from a validated value to one of its invalid parts
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub enum PathSegment {
+    /// A named field of a struct or enum variant
+    Field(Cow<'static, str>),
+    /// A position in a collection, a tuple or a tuple struct
+    Index(usize),
+}
+
+/// The location of an error within a validated value, such as `dogs[3].name`
+///
+/// It is displayed using dots and brackets, and can also be formatted as a
+/// [JSON Pointer](https://datatracker.ietf.org/doc/html/rfc6901) such as `/dogs/3/name`.
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone)]
+pub struct ErrorPath(Vec<PathSegment>);
+
+/// The errors found in a validated value, along with their paths
+pub type ErrorList<'a> = Vec<(ErrorPath, &'a (dyn LeafError + 'a))>;
+
+/// An error which is not made up of other errors, as listed by [ValidationError::errors]
+pub trait LeafError: Error + ErrorCode {}
+
+impl<E: Error + ErrorCode + ?Sized> LeafError for E {}
+
+impl ErrorPath {
+    /// The path of the validated value itself
+    pub fn root() -> Self {
+        Self::default()
+    }
+
+    pub fn is_root(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn segments(&self) -> &[PathSegment] {
+        &self.0
+    }
+
+    pub fn push(&mut self, segment: PathSegment) {
+        self.0.push(segment);
+    }
+
+    pub fn pop(&mut self) -> Option<PathSegment> {
+        self.0.pop()
+    }
+
+    /// Collects the errors of a nested value found at the given segment, relative to this path
+    pub fn collect_nested<'a, E: ValidationError + ?Sized>(&mut self, segment: PathSegment, error: &'a E, errors: &mut ErrorList<'a>) {
+        self.push(segment);
+        error.collect_errors(self, errors);
+        self.pop();
+    }
+
+    /// Formats this path as a JSON Pointer, the root being the empty string
+    pub fn to_json_pointer(&self) -> String {
+        let mut pointer = String::new();
+        for segment in &self.0 {
+            pointer.push('/');
+            match segment {
+                PathSegment::Field(name) => pointer.push_str(&name.replace('~', "~0").replace('/', "~1")),
+                PathSegment::Index(index) => pointer.push_str(&index.to_string()),
+            }
+        }
+        pointer
+    }
+}
+
+impl Display for PathSegment {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PathSegment::Field(name) => write!(f, "{name}"),
+            PathSegment::Index(index) => write!(f, "{index}"),
+        }
+    }
+}
+
+impl Display for ErrorPath {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (position, segment) in self.0.iter().enumerate() {
+            match segment {
+                PathSegment::Field(name) if position == 0 => write!(f, "{name}")?,
+                PathSegment::Field(name) => write!(f, ".{name}")?,
+                PathSegment::Index(index) => write!(f, "[{index}]")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromIterator<PathSegment> for ErrorPath {
+    fn from_iter<I: IntoIterator<Item=PathSegment>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+/// An error returned by a validator, which may be made up of the errors of nested values.
+///
+/// This is implemented by every built-in error and by the errors generated by the derive,
+/// so that any of them can be flattened into a list of errors along with their paths.
+/// Errors of custom validators usually do not contain any nested error and can implement it,
+/// along with [ErrorCode], as
+/// ```
+/// # use validator::{ErrorCode, ErrorList, ErrorPath, ValidationError};
+/// # #[derive(Debug)]
+/// # struct MyError;
+/// # impl std::fmt::Display for MyError {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result { f.write_str("my error") }
+/// # }
+/// # impl std::error::Error for MyError {}
+/// impl ValidationError for MyError {
+///     fn collect_errors<'a>(&'a self, path: &mut ErrorPath, errors: &mut ErrorList<'a>) {
+///         errors.push((path.clone(), self));
+///     }
+/// }
+///
+/// impl ErrorCode for MyError {
+///     fn code(&self) -> &str {
+///         "my_error"
+///     }
+/// }
+/// ```
+pub trait ValidationError: Debug {
+    /// Adds every error found in this error to the list, `path` being the path of this error
+    fn collect_errors<'a>(&'a self, path: &mut ErrorPath, errors: &mut ErrorList<'a>);
+
+    /// Flattens this error into the list of errors it is made up of, along with their paths
+    fn errors(&self) -> ErrorList<'_> {
+        let mut errors = Vec::new();
+        self.collect_errors(&mut ErrorPath::root(), &mut errors);
+        errors
+    }
+}
+
+/// A stable code identifying the kind of an error, along with the parameters of the rule which failed.
+///
+/// The parameters can be interpolated into custom messages, such as `Use at least {min} characters`,
+/// they are implemented by every built-in error.
+pub trait ErrorCode {
+    fn code(&self) -> &str;
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        Vec::new()
+    }
+}
+
+impl ErrorCode for std::convert::Infallible {
+    fn code(&self) -> &str {
+        match *self {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ElementsValidator, ErrorPath, LengthValidator, NotEmptyValidator, PathSegment, ValidationError, Validator};
+
+    fn path() -> ErrorPath {
+        [
+            PathSegment::Field("dogs".into()),
+            PathSegment::Index(3),
+            PathSegment::Field("name".into()),
+        ].into_iter().collect()
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(path().to_string(), "dogs[3].name");
+        assert_eq!(ErrorPath::root().to_string(), "");
+    }
+
+    #[test]
+    fn json_pointer() {
+        assert_eq!(path().to_json_pointer(), "/dogs/3/name");
+        assert_eq!(ErrorPath::root().to_json_pointer(), "");
+        let escaped: ErrorPath = [PathSegment::Field("a/b~c".into())].into_iter().collect();
+        assert_eq!(escaped.to_json_pointer(), "/a~1b~0c");
+    }
+
+    #[test]
+    fn flatten_elements() {
+        let error = ElementsValidator::new(NotEmptyValidator)
+            .validate(&vec!["a".to_string(), String::new(), "b".to_string(), String::new()])
+            .unwrap_err();
+        let paths: Vec<_> = error.errors().into_iter()
+            .map(|(path, error)| (path.to_string(), error.to_string()))
+            .collect();
+        assert_eq!(paths, vec![
+            ("[1]".to_string(), "Value should not be empty".to_string()),
+            ("[3]".to_string(), "Value should not be empty".to_string()),
+        ]);
+    }
+
+    #[test]
+    fn flatten_set() {
+        // the elements of a set are reported at the path of the element itself, whatever the order of the set
+        let set: std::collections::HashSet<_> = ["de", "c", "ab", "f", "gh"].into_iter().collect();
+        let error = ElementsValidator::new(LengthValidator::new(Some(2), None)).validate(&set).unwrap_err();
+        assert!(error.get("c").is_some());
+        assert!(error.get("f").is_some());
+        assert_eq!(error.get("ab"), None);
+        assert_eq!(error.get("x"), None);
+        let mut paths: Vec<_> = error.errors().into_iter()
+            .map(|(path, error)| (path.to_string(), error.to_string()))
+            .collect();
+        paths.sort();
+        assert_eq!(paths, vec![
+            ("c".to_string(), "value of length 1 falls short of minimum of 2".to_string()),
+            ("f".to_string(), "value of length 1 falls short of minimum of 2".to_string()),
+        ]);
+
+        let set: std::collections::BTreeSet<_> = ["de", "c", "ab", "f"].into_iter().collect();
+        let error = ElementsValidator::new(LengthValidator::new(Some(2), None)).validate(&set).unwrap_err();
+        assert_eq!(error.iter().map(|(element, _)| *element).collect::<Vec<_>>(), ["c", "f"]);
+    }
+}
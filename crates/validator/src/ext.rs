@@ -0,0 +1,218 @@
+use crate::{
+    AllOf, And, AnyOf, ElementsValidator, EntriesValidator, ErrorBudget, ExactlyOne, KeysValidator, MessageValidator, Not,
+    OptionValidator, Or, Then, Validator, ValidatorWithContext, ValuesValidator,
+};
+
+/// Combinators to build validators at runtime without nesting constructors, such as
+/// `LengthValidator::new(Some(1), None).and(EmailValidator)`.
+///
+/// This is implemented for the validators of this crate, their combinations and the derived validators, rather than
+/// only for the implementations of [Validator], as most validators validate several types of values and which one is
+/// validated is only known once the combined validator is used. Other validators can implement it with an empty
+/// `impl ValidatorExt for MyValidator {}`.
+///
+/// Other types do not get these methods, so that `and` or `not` are not added to every value in scope:
+/// ```compile_fail
+/// # use validator::ValidatorExt;
+/// let _ = 42u32.not();
+/// ```
+pub trait ValidatorExt: Sized {
+    /// Validates values with both validators, reporting the errors of both
+    fn and<B>(self, other: B) -> And<Self, B> {
+        And::new(self, other)
+    }
+
+    /// Validates values with the other validator only if this one fails
+    fn or<B>(self, other: B) -> Or<Self, B> {
+        Or::new(self, other)
+    }
+
+    /// Validates values with the other validator only if this one passes
+    fn then<B>(self, next: B) -> Then<Self, B> {
+        Then::new(self, next)
+    }
+
+    /// Validates that values fail this validator
+    fn not(self) -> Not<Self> {
+        Not::new(self)
+    }
+
+    /// Converts the errors of this validator
+    fn map_err<F>(self, map: F) -> MapErr<Self, F> {
+        MapErr::new(self, map)
+    }
+
+    /// Replaces the code of the errors of this validator
+    fn with_code(self, code: &'static str) -> MessageValidator<Self> {
+        MessageValidator::new(self).with_code(code)
+    }
+
+    /// Validates only the values for which the predicate holds, such as `.when(|value: &String| !value.is_empty())`
+    fn when<P>(self, predicate: P) -> When<Self, P> {
+        When::new(self, predicate)
+    }
+
+    /// Validates the value of options, `None` being valid
+    fn optional(self) -> OptionValidator<Self> {
+        OptionValidator::new(self)
+    }
+
+    /// Validates each element of collections
+    fn each(self) -> ElementsValidator<Self> {
+        ElementsValidator::new(self)
+    }
+}
+
+// the combinations of validators are validators whatever they combine
+impl<A, B> ValidatorExt for And<A, B> {}
+impl<A, B> ValidatorExt for Or<A, B> {}
+impl<A, B> ValidatorExt for Then<A, B> {}
+impl<V> ValidatorExt for Not<V> {}
+impl<V, F> ValidatorExt for MapErr<V, F> {}
+impl<V> ValidatorExt for MessageValidator<V> {}
+impl<V, P> ValidatorExt for When<V, P> {}
+impl<V> ValidatorExt for OptionValidator<V> {}
+impl<V> ValidatorExt for ElementsValidator<V> {}
+impl<V> ValidatorExt for KeysValidator<V> {}
+impl<V> ValidatorExt for ValuesValidator<V> {}
+impl<K, V> ValidatorExt for EntriesValidator<K, V> {}
+impl<V> ValidatorExt for AllOf<V> {}
+impl<V> ValidatorExt for AnyOf<V> {}
+impl<V> ValidatorExt for ExactlyOne<V> {}
+
+/// Converts the errors of a validator with a function
+#[derive(Debug, Clone)]
+pub struct MapErr<V, F> {
+    validator: V,
+    map: F,
+}
+
+/// Validates the values for which a predicate holds, any other value being valid
+#[derive(Debug, Clone)]
+pub struct When<V, P> {
+    validator: V,
+    predicate: P,
+}
+
+impl<V, F> MapErr<V, F> {
+    pub fn new(validator: V, map: F) -> Self {
+        Self { validator, map }
+    }
+}
+
+impl<V, P> When<V, P> {
+    pub fn new(validator: V, predicate: P) -> Self {
+        Self { validator, predicate }
+    }
+}
+
+impl<V, F, T, E> Validator<T> for MapErr<V, F>
+where
+    V: Validator<T>,
+    F: Fn(V::Error) -> E,
+    E: std::fmt::Debug
+{
+    type Error = E;
+
+    fn validate(&self, value: &T) -> Result<(), Self::Error> {
+        self.validator.validate(value).map_err(&self.map)
+    }
+
+    fn validate_budgeted(&self, value: &T, budget: &mut ErrorBudget) -> Result<(), Self::Error> {
+        self.validator.validate_budgeted(value, budget).map_err(&self.map)
+    }
+}
+
+impl<V, F, T, C: ?Sized, E> ValidatorWithContext<T, C> for MapErr<V, F>
+where
+    V: ValidatorWithContext<T, C>,
+    F: Fn(V::Error) -> E,
+    E: std::fmt::Debug
+{
+    type Error = E;
+
+    fn validate_with(&self, value: &T, context: &C) -> Result<(), Self::Error> {
+        self.validator.validate_with(value, context).map_err(&self.map)
+    }
+}
+
+#[cfg(feature = "async")]
+impl<V, F, T, E> crate::AsyncValidator<T> for MapErr<V, F>
+where
+    V: crate::AsyncValidator<T>,
+    F: Fn(V::Error) -> E,
+    E: std::fmt::Debug
+{
+    type Error = E;
+
+    async fn validate_async(&self, value: &T) -> Result<(), Self::Error> {
+        self.validator.validate_async(value).await.map_err(&self.map)
+    }
+}
+
+impl<V, P, T> Validator<T> for When<V, P> where V: Validator<T>, P: Fn(&T) -> bool {
+    type Error = V::Error;
+
+    fn validate(&self, value: &T) -> Result<(), Self::Error> {
+        if (self.predicate)(value) {
+            self.validator.validate(value)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn validate_budgeted(&self, value: &T, budget: &mut ErrorBudget) -> Result<(), Self::Error> {
+        if (self.predicate)(value) {
+            self.validator.validate_budgeted(value, budget)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<V, P, T, C: ?Sized> ValidatorWithContext<T, C> for When<V, P> where V: ValidatorWithContext<T, C>, P: Fn(&T) -> bool {
+    type Error = V::Error;
+
+    fn validate_with(&self, value: &T, context: &C) -> Result<(), Self::Error> {
+        if (self.predicate)(value) {
+            self.validator.validate_with(value, context)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl<V, P, T> crate::AsyncValidator<T> for When<V, P> where V: crate::AsyncValidator<T>, P: Fn(&T) -> bool {
+    type Error = V::Error;
+
+    async fn validate_async(&self, value: &T) -> Result<(), Self::Error> {
+        if (self.predicate)(value) {
+            self.validator.validate_async(value).await
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{CustomError, EmailValidator, InvalidEmailError, LengthValidator, Validator, ValidatorExt};
+
+    #[test]
+    fn maps_errors() {
+        let validator = EmailValidator.map_err(|_| CustomError::new("not an email"));
+        validator.validate(&"someone@example.com".to_string()).expect("should be valid");
+        assert_eq!(validator.validate(&"someone".to_string()), Err(CustomError::new("not an email")));
+    }
+
+    #[test]
+    fn validates_when_predicate_holds() {
+        let validator = LengthValidator::new(Some(8), None).when(|value: &String| !value.starts_with("legacy:"));
+        validator.validate(&"legacy:1".to_string()).expect("should be valid");
+        validator.validate(&"short".to_string()).expect_err("should be invalid");
+        let validator = EmailValidator.when(|value: &String| value.contains('@'));
+        validator.validate(&"someone".to_string()).expect("should be valid");
+        assert_eq!(validator.validate(&"someone@".to_string()), Err(InvalidEmailError::InvalidDomain));
+    }
+}
@@ -1,5 +1,5 @@
 use std::net::{AddrParseError, IpAddr};
-use crate::Validator;
+use crate::{ErrorCode, Validator};
 
 pub struct IpAddressValidator;
 
@@ -9,4 +9,13 @@ impl Validator<str> for IpAddressValidator {
     fn validate(&self, value: &str) -> Result<(), Self::Error> {
         value.parse().map(|_: IpAddr| ())
     }
-}
\ No newline at end of file
+}
+
+leaf_validator!(IpAddressValidator);
+leaf_error!(AddrParseError);
+
+impl ErrorCode for AddrParseError {
+    fn code(&self) -> &str {
+        "ip"
+    }
+}
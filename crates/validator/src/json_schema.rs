@@ -0,0 +1,477 @@
+//! Generation of [JSON Schema 2020-12](https://json-schema.org/draft/2020-12/json-schema-core) documents,
+//! enabled by the `json-schema` feature.
+//!
+//! The derive implements [JsonSchema] for the derived type, the schema of each field being the schema of its type
+//! along with the keywords matching its rules: `minItems` and `maxItems` for arrays (or `minLength` and `maxLength`
+//! for strings measured in characters), `minimum` and `maximum`, `format` for `email`, `url` and `ip`, `pattern` for
+//! `regex`, `items` for `elements` and so on. Rules which cannot be described, such as `custom`, add nothing. The
+//! derived types are defined in `$defs` and referred to with `$ref`, except for generic types which are inlined.
+
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque};
+use std::ops::Bound;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use serde_json::{json, Map, Value};
+
+/// The URI of the dialect of the generated schemas
+pub const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// A type which can be described by a JSON Schema
+pub trait JsonSchema {
+    /// The name under which the schema is defined in `$defs`, the schema being inlined if there is none
+    fn schema_name() -> Option<Cow<'static, str>> {
+        None
+    }
+
+    /// The schema of this type, the schemas of the named types it uses being added to the definitions
+    fn json_schema(defs: &mut SchemaDefinitions) -> Value;
+
+    /// The schema of this type as a standalone document, along with its `$schema` and `$defs`
+    fn root_json_schema() -> Value {
+        let mut defs = SchemaDefinitions::new();
+        let mut schema = Self::json_schema(&mut defs);
+        if let Value::Object(object) = &mut schema {
+            object.insert("$schema".to_string(), JSON_SCHEMA_DIALECT.into());
+            if !defs.0.is_empty() {
+                object.insert("$defs".to_string(), Value::Object(defs.0));
+            }
+        }
+        schema
+    }
+}
+
+/// The schemas of the named types, found in the `$defs` of the root schema
+#[derive(Debug, Default, Clone)]
+pub struct SchemaDefinitions(Map<String, Value>);
+
+impl SchemaDefinitions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The schema of a type, which is a reference to its definition if it has a name
+    pub fn subschema<T: JsonSchema + ?Sized>(&mut self) -> Value {
+        let Some(name) = T::schema_name() else {
+            return T::json_schema(self);
+        };
+        if !self.0.contains_key(name.as_ref()) {
+            // the name is taken before the schema is generated so that recursive types refer to their definition
+            self.0.insert(name.to_string(), Value::Bool(true));
+            let schema = T::json_schema(self);
+            self.0.insert(name.to_string(), schema);
+        }
+        json!({ "$ref": format!("#/$defs/{name}") })
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        self.0.get(name)
+    }
+
+    pub fn into_map(self) -> Map<String, Value> {
+        self.0
+    }
+}
+
+fn schema_type(schema: &Value) -> Option<&str> {
+    schema.get("type").and_then(Value::as_str)
+}
+
+fn insert(schema: &mut Value, keyword: &str, value: Value) {
+    if let Value::Object(object) = schema {
+        object.insert(keyword.to_string(), value);
+    }
+}
+
+/// Describes a length rule measuring strings in bytes, as `minItems` and `maxItems` for arrays and `minProperties`
+/// and `maxProperties` for objects, strings being left undescribed as JSON Schema measures them in characters
+pub fn describe_length(schema: &mut Value, min: Option<usize>, max: Option<usize>) {
+    let keywords = match schema_type(schema) {
+        Some("array") => ("minItems", "maxItems"),
+        Some("object") => ("minProperties", "maxProperties"),
+        _ => return,
+    };
+    insert_length(schema, keywords, min, max);
+}
+
+/// Describes a length rule measuring strings in characters, as [describe_length] does along with `minLength` and
+/// `maxLength` for strings
+pub fn describe_char_length(schema: &mut Value, min: Option<usize>, max: Option<usize>) {
+    let keywords = match schema_type(schema) {
+        Some("array") => ("minItems", "maxItems"),
+        Some("object") => ("minProperties", "maxProperties"),
+        _ => ("minLength", "maxLength"),
+    };
+    insert_length(schema, keywords, min, max);
+}
+
+fn insert_length(schema: &mut Value, (min_keyword, max_keyword): (&str, &str), min: Option<usize>, max: Option<usize>) {
+    if let Some(min) = min {
+        insert(schema, min_keyword, min.into());
+    }
+    if let Some(max) = max {
+        insert(schema, max_keyword, max.into());
+    }
+}
+
+/// Describes a range rule as `minimum`, `maximum` and `exclusiveMaximum`, bounds which are not numbers being ignored
+pub fn describe_range(schema: &mut Value, start: Bound<&Value>, end: Bound<&Value>) {
+    match start {
+        Bound::Included(start) if start.is_number() => insert(schema, "minimum", start.clone()),
+        Bound::Excluded(start) if start.is_number() => insert(schema, "exclusiveMinimum", start.clone()),
+        _ => {}
+    }
+    match end {
+        Bound::Included(end) if end.is_number() => insert(schema, "maximum", end.clone()),
+        Bound::Excluded(end) if end.is_number() => insert(schema, "exclusiveMaximum", end.clone()),
+        _ => {}
+    }
+}
+
+/// Describes a rule checking the format of strings, such as `email` or `uri`
+pub fn describe_format(schema: &mut Value, format: &str) {
+    insert(schema, "format", format.into());
+}
+
+/// Describes a regex rule as `pattern`
+pub fn describe_pattern(schema: &mut Value, pattern: &str) {
+    insert(schema, "pattern", pattern.into());
+}
+
+/// Describes a rule allowing only the given values as `enum`
+pub fn describe_enum(schema: &mut Value, allowed: Vec<Value>) {
+    insert(schema, "enum", allowed.into());
+}
+
+/// Describes the rules of the elements of an array, found in its `items`
+pub fn describe_items(schema: &mut Value, describe: impl FnOnce(&mut Value)) {
+    if let Some(items) = schema.get_mut("items") {
+        describe(items);
+    }
+}
+
+/// Describes the rules of an element of a tuple, found in its `prefixItems`
+pub fn describe_prefix_item(schema: &mut Value, index: usize, describe: impl FnOnce(&mut Value)) {
+    if let Some(item) = schema.get_mut("prefixItems").and_then(|items| items.get_mut(index)) {
+        describe(item);
+    }
+}
+
+/// Describes the rules of the keys of a map, found in its `propertyNames`
+pub fn describe_keys(schema: &mut Value, describe: impl FnOnce(&mut Value)) {
+    if let Value::Object(object) = schema {
+        describe(object.entry("propertyNames").or_insert_with(|| json!({ "type": "string" })));
+    }
+}
+
+/// Describes the rules of the values of a map, found in its `additionalProperties`
+pub fn describe_values(schema: &mut Value, describe: impl FnOnce(&mut Value)) {
+    if let Some(values) = schema.get_mut("additionalProperties") {
+        describe(values);
+    }
+}
+
+/// Describes rules of which any must pass as `anyOf`, each alternative keeping the type of the schema
+pub fn describe_any_of(schema: &mut Value, alternatives: &[&dyn Fn(&mut Value)]) {
+    if let Some(alternatives) = describe_subschemas(schema, alternatives) {
+        insert_subschemas(schema, "anyOf", alternatives.into());
+    }
+}
+
+/// Describes rules of which exactly one must pass as `oneOf`, each alternative keeping the type of the schema
+pub fn describe_one_of(schema: &mut Value, alternatives: &[&dyn Fn(&mut Value)]) {
+    if let Some(alternatives) = describe_subschemas(schema, alternatives) {
+        insert_subschemas(schema, "oneOf", alternatives.into());
+    }
+}
+
+/// Describes a rule which must fail as `not`, the negated schema keeping the type of the schema
+pub fn describe_not(schema: &mut Value, describe: impl Fn(&mut Value)) {
+    if let Some(mut negated) = describe_subschemas(schema, &[&describe]) {
+        insert_subschemas(schema, "not", negated.remove(0));
+    }
+}
+
+/// Describes a rule forbidding the given values as `not` holding their `enum`
+pub fn describe_forbidden(schema: &mut Value, forbidden: Vec<Value>) {
+    insert_subschemas(schema, "not", json!({ "enum": forbidden }));
+}
+
+// the subschemas of a combination of rules, each of them keeping the type of the schema, or None if a rule added
+// nothing to its subschema, such as a length in bytes of a string, as the combination could then not be described
+fn describe_subschemas(schema: &Value, rules: &[&dyn Fn(&mut Value)]) -> Option<Vec<Value>> {
+    rules.iter()
+        .map(|describe| {
+            let untouched = match schema.get("type") {
+                Some(ty) => json!({ "type": ty }),
+                None => json!({}),
+            };
+            let mut subschema = untouched.clone();
+            describe(&mut subschema);
+            (subschema != untouched).then_some(subschema)
+        })
+        .collect()
+}
+
+// a schema already combining some subschemas with the keyword requires both combinations, which are then
+// moved to its `allOf`
+fn insert_subschemas(schema: &mut Value, keyword: &str, subschemas: Value) {
+    let Value::Object(object) = schema else { return; };
+    match object.remove(keyword) {
+        Some(existing) => {
+            let all_of = object.entry("allOf").or_insert_with(|| json!([]));
+            if let Value::Array(all_of) = all_of {
+                all_of.push(json!({ keyword: existing }));
+                all_of.push(json!({ keyword: subschemas }));
+            }
+        }
+        None => {
+            object.insert(keyword.to_string(), subschemas);
+        }
+    }
+}
+
+/// The schema of an `Option`, which is either the given schema or `null`
+#[doc(hidden)]
+pub fn nullable_schema(schema: Value) -> Value {
+    json!({ "anyOf": [schema, { "type": "null" }] })
+}
+
+/// The schema of an object made up of the given properties, along with whether they are required
+#[doc(hidden)]
+pub fn object_schema<const N: usize>(properties: [(&str, Value, bool); N]) -> Value {
+    let required: Vec<&str> = properties.iter()
+        .filter(|(_, _, required)| *required)
+        .map(|(name, _, _)| *name)
+        .collect();
+    let properties: Map<String, Value> = properties.into_iter()
+        .map(|(name, schema, _)| (name.to_string(), schema))
+        .collect();
+    json!({ "type": "object", "properties": properties, "required": required })
+}
+
+/// The schema of an array made up of exactly the given items
+#[doc(hidden)]
+pub fn tuple_schema<const N: usize>(items: [Value; N]) -> Value {
+    json!({ "type": "array", "prefixItems": Vec::from(items), "items": false, "minItems": N, "maxItems": N })
+}
+
+/// The schema of a variant of an enum, represented as its name or as an object holding its value under its name
+#[doc(hidden)]
+pub fn variant_schema(name: &str, value: Option<Value>) -> Value {
+    match value {
+        None => json!({ "const": name }),
+        Some(value) => json!({
+            "type": "object",
+            "properties": { (name): value },
+            "required": [name],
+            "additionalProperties": false,
+        }),
+    }
+}
+
+macro_rules! primitive_schema {
+    ($($ty:ty => $schema:tt),* $(,)?) => {
+        $(
+        impl JsonSchema for $ty {
+            fn json_schema(_: &mut SchemaDefinitions) -> Value {
+                json!($schema)
+            }
+        }
+        )*
+    };
+}
+
+primitive_schema!(
+    bool => { "type": "boolean" },
+    i8 => { "type": "integer" },
+    i16 => { "type": "integer" },
+    i32 => { "type": "integer" },
+    i64 => { "type": "integer" },
+    i128 => { "type": "integer" },
+    isize => { "type": "integer" },
+    u8 => { "type": "integer", "minimum": 0 },
+    u16 => { "type": "integer", "minimum": 0 },
+    u32 => { "type": "integer", "minimum": 0 },
+    u64 => { "type": "integer", "minimum": 0 },
+    u128 => { "type": "integer", "minimum": 0 },
+    usize => { "type": "integer", "minimum": 0 },
+    f32 => { "type": "number" },
+    f64 => { "type": "number" },
+    char => { "type": "string", "minLength": 1, "maxLength": 1 },
+    str => { "type": "string" },
+    String => { "type": "string" },
+);
+
+// implements JsonSchema for types which are serialised as the type they point to
+macro_rules! pointer_schema {
+    ($(<$generic:ident> $ty:ty),* $(,)?) => {
+        $(
+        impl<$generic: JsonSchema + ?Sized> JsonSchema for $ty {
+            fn json_schema(defs: &mut SchemaDefinitions) -> Value {
+                defs.subschema::<$generic>()
+            }
+        }
+        )*
+    };
+}
+
+pointer_schema!(<T> &T, <T> Box<T>, <T> Rc<T>, <T> Arc<T>);
+
+impl<T: JsonSchema + ToOwned + ?Sized> JsonSchema for Cow<'_, T> {
+    fn json_schema(defs: &mut SchemaDefinitions) -> Value {
+        defs.subschema::<T>()
+    }
+}
+
+impl<T: JsonSchema> JsonSchema for Option<T> {
+    fn json_schema(defs: &mut SchemaDefinitions) -> Value {
+        nullable_schema(defs.subschema::<T>())
+    }
+}
+
+// implements JsonSchema for collections which are serialised as arrays
+macro_rules! array_schema {
+    ($($ty:ty $(=> $unique:literal)?),* $(,)?) => {
+        $(
+        impl<T: JsonSchema> JsonSchema for $ty {
+            fn json_schema(defs: &mut SchemaDefinitions) -> Value {
+                #[allow(unused_mut)]
+                let mut schema = json!({ "type": "array", "items": defs.subschema::<T>() });
+                $(insert(&mut schema, "uniqueItems", $unique.into());)?
+                schema
+            }
+        }
+        )*
+    };
+}
+
+array_schema!([T], Vec<T>, VecDeque<T>, LinkedList<T>, BinaryHeap<T>, BTreeSet<T> => true);
+
+impl<T: JsonSchema, S> JsonSchema for HashSet<T, S> {
+    fn json_schema(defs: &mut SchemaDefinitions) -> Value {
+        json!({ "type": "array", "items": defs.subschema::<T>(), "uniqueItems": true })
+    }
+}
+
+#[cfg(feature = "indexmap")]
+impl<T: JsonSchema, S> JsonSchema for indexmap::IndexSet<T, S> {
+    fn json_schema(defs: &mut SchemaDefinitions) -> Value {
+        json!({ "type": "array", "items": defs.subschema::<T>(), "uniqueItems": true })
+    }
+}
+
+impl<T: JsonSchema, const N: usize> JsonSchema for [T; N] {
+    fn json_schema(defs: &mut SchemaDefinitions) -> Value {
+        json!({ "type": "array", "items": defs.subschema::<T>(), "minItems": N, "maxItems": N })
+    }
+}
+
+impl<K, V: JsonSchema, S> JsonSchema for HashMap<K, V, S> {
+    fn json_schema(defs: &mut SchemaDefinitions) -> Value {
+        json!({ "type": "object", "additionalProperties": defs.subschema::<V>() })
+    }
+}
+
+impl<K, V: JsonSchema> JsonSchema for BTreeMap<K, V> {
+    fn json_schema(defs: &mut SchemaDefinitions) -> Value {
+        json!({ "type": "object", "additionalProperties": defs.subschema::<V>() })
+    }
+}
+
+#[cfg(feature = "indexmap")]
+impl<K, V: JsonSchema, S> JsonSchema for indexmap::IndexMap<K, V, S> {
+    fn json_schema(defs: &mut SchemaDefinitions) -> Value {
+        json!({ "type": "object", "additionalProperties": defs.subschema::<V>() })
+    }
+}
+
+macro_rules! tuple_schema {
+    ($(($($t:ident),+)),* $(,)?) => {
+        $(
+        impl<$($t: JsonSchema),+> JsonSchema for ($($t,)+) {
+            fn json_schema(defs: &mut SchemaDefinitions) -> Value {
+                tuple_schema([$(defs.subschema::<$t>()),+])
+            }
+        }
+        )*
+    };
+}
+
+tuple_schema!(
+    (T0),
+    (T0, T1),
+    (T0, T1, T2),
+    (T0, T1, T2, T3),
+    (T0, T1, T2, T3, T4),
+    (T0, T1, T2, T3, T4, T5),
+    (T0, T1, T2, T3, T4, T5, T6),
+    (T0, T1, T2, T3, T4, T5, T6, T7),
+    (T0, T1, T2, T3, T4, T5, T6, T7, T8),
+    (T0, T1, T2, T3, T4, T5, T6, T7, T8, T9),
+    (T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10),
+    (T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11),
+    (T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12),
+);
+
+#[cfg(test)]
+mod test {
+    use std::collections::{HashMap, HashSet};
+    use std::ops::Bound;
+
+    use serde_json::json;
+
+    use crate::{
+        describe_any_of, describe_char_length, describe_format, describe_items, describe_length, describe_not, describe_range,
+        JsonSchema,
+    };
+
+    #[test]
+    fn std_types() {
+        assert_eq!(Vec::<Option<String>>::root_json_schema(), json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "type": "array",
+            "items": { "anyOf": [{ "type": "string" }, { "type": "null" }] },
+        }));
+        assert_eq!(HashMap::<String, HashSet<u8>>::root_json_schema()["additionalProperties"], json!({
+            "type": "array",
+            "items": { "type": "integer", "minimum": 0 },
+            "uniqueItems": true,
+        }));
+        assert_eq!(<(bool, f64)>::root_json_schema()["prefixItems"], json!([{ "type": "boolean" }, { "type": "number" }]));
+    }
+
+    #[test]
+    fn describes_rules() {
+        let mut schema = Vec::<String>::root_json_schema();
+        describe_length(&mut schema, Some(1), None);
+        describe_items(&mut schema, |schema| {
+            describe_char_length(schema, None, Some(10));
+            describe_any_of(schema, &[&|schema| describe_format(schema, "ipv4"), &|schema| describe_format(schema, "ipv6")]);
+        });
+        assert_eq!(schema["minItems"], json!(1));
+        assert_eq!(schema["items"], json!({
+            "type": "string",
+            "maxLength": 10,
+            "anyOf": [{ "type": "string", "format": "ipv4" }, { "type": "string", "format": "ipv6" }],
+        }));
+
+        let mut schema = u32::root_json_schema();
+        describe_range(&mut schema, Bound::Included(&json!(18)), Bound::Excluded(&json!(120)));
+        assert_eq!(schema["minimum"], json!(18));
+        assert_eq!(schema["exclusiveMaximum"], json!(120));
+    }
+
+    #[test]
+    fn describes_lengths_in_characters_only() {
+        let mut schema = json!({ "type": "string" });
+        describe_length(&mut schema, Some(1), Some(10));
+        describe_not(&mut schema, |schema| describe_length(schema, None, Some(3)));
+        describe_any_of(&mut schema, &[&|schema| describe_length(schema, Some(5), None), &|schema| describe_format(schema, "email")]);
+        assert_eq!(schema, json!({ "type": "string" }));
+
+        describe_char_length(&mut schema, Some(1), Some(10));
+        assert_eq!(schema, json!({ "type": "string", "minLength": 1, "maxLength": 10 }));
+    }
+}
@@ -0,0 +1,726 @@
+//! Validation of JSON values against JSON Schema documents, enabled by the `json-schema` feature.
+//!
+//! A document is compiled once into a [JsonSchemaValidator], the keywords being checked by the built-in validators
+//! so that the errors are the same as for statically declared rules: `minLength` fails with an
+//! [InvalidLengthError], `minimum` with a [NotInRangeError], `pattern` with a [PatternMismatchError] and so on.
+//!
+//! The supported keywords are `type`, `enum`, `const`, `properties`, `required`, `additionalProperties`,
+//! `propertyNames`, `items`, `prefixItems`, `minLength`, `maxLength`, `minItems`, `maxItems`, `minProperties`,
+//! `maxProperties`, `minimum`, `maximum`, `exclusiveMinimum`, `exclusiveMaximum`, `pattern`, `format` (`email`,
+//! `uri`, `ipv4` and `ipv6`), `allOf`, `anyOf`, `oneOf` and `$ref` to the document itself, such as `#/$defs/Address`.
+//! Other keywords and formats are ignored, as annotations.
+
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::net::{AddrParseError, Ipv4Addr, Ipv6Addr};
+use std::ops::{Bound, RangeFrom, RangeTo, RangeToInclusive};
+
+use regex::Regex;
+use serde_json::{Map, Value};
+use thiserror::Error;
+
+use crate::{
+    EmailValidator, ErrorCode, ErrorList, ErrorPath, HasLength, InvalidEmailError, InvalidLengthError, InvalidUrlError,
+    LengthUnit, LengthValidator, MissingValueError, NotInRangeError, PathSegment, PatternMismatchError, RangeValidator,
+    RegexValidator, SeveralMatchedError, UrlValidator, ValidationError, Validator,
+};
+
+/// Validates JSON values against a compiled JSON Schema document
+pub struct JsonSchemaValidator {
+    root: Schema,
+    // the schemas referred to with `$ref`, by index
+    definitions: Vec<Schema>,
+}
+
+/// The errors found in a JSON value, those of its properties and items being nested under their name and index
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct JsonSchemaErrors {
+    errors: Vec<JsonError>,
+    nested: Vec<(PathSegment, JsonSchemaErrors)>,
+}
+
+/// A keyword which failed, along with the error of the validator which checked it
+#[derive(Debug, PartialEq, Clone, Error)]
+pub enum JsonError {
+    #[error(transparent)]
+    Type(#[from] InvalidTypeError),
+    #[error(transparent)]
+    Enum(#[from] UnexpectedValueError),
+    #[error(transparent)]
+    Required(#[from] MissingValueError),
+    #[error(transparent)]
+    NotAllowed(#[from] NotAllowedError),
+    #[error(transparent)]
+    Length(#[from] InvalidLengthError),
+    #[error(transparent)]
+    Minimum(#[from] NotInRangeError<RangeFrom<f64>>),
+    #[error(transparent)]
+    ExclusiveMinimum(#[from] NotInRangeError<(Bound<f64>, Bound<f64>)>),
+    #[error(transparent)]
+    Maximum(#[from] NotInRangeError<RangeToInclusive<f64>>),
+    #[error(transparent)]
+    ExclusiveMaximum(#[from] NotInRangeError<RangeTo<f64>>),
+    #[error(transparent)]
+    Pattern(#[from] PatternMismatchError),
+    #[error(transparent)]
+    Email(#[from] InvalidEmailError),
+    #[error(transparent)]
+    Url(#[from] InvalidUrlError),
+    #[error(transparent)]
+    Ip(#[from] AddrParseError),
+    /// None of the schemas of `anyOf` or `oneOf` matched, along with the errors found by each of them
+    #[error("{}", display_alternatives(.0))]
+    NoneMatched(Vec<JsonSchemaErrors>),
+    /// Several of the schemas of `oneOf` matched
+    #[error(transparent)]
+    SeveralMatched(#[from] SeveralMatchedError),
+}
+
+/// The types of JSON values, `integer` standing for numbers without a fractional part
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum JsonType {
+    Null,
+    Boolean,
+    Integer,
+    Number,
+    String,
+    Array,
+    Object,
+}
+
+#[derive(Debug, PartialEq, Clone, Error)]
+#[error("Value of type {found} should be of type {}", display_types(.expected))]
+pub struct InvalidTypeError {
+    pub expected: Vec<JsonType>,
+    pub found: JsonType,
+}
+
+#[derive(Debug, PartialEq, Clone, Error)]
+#[error("Value should be one of {}", display_values(.allowed))]
+pub struct UnexpectedValueError {
+    pub allowed: Vec<Value>,
+}
+
+/// Fails for any value, such as the properties which are not listed when `additionalProperties` is `false`
+#[derive(Debug, PartialEq, Clone, Error)]
+#[error("Value is not allowed")]
+pub struct NotAllowedError;
+
+/// A JSON Schema document which cannot be compiled
+#[derive(Debug, Error)]
+pub enum SchemaCompileError {
+    #[error("invalid value for `{keyword}`: expected {expected}")]
+    InvalidKeyword {
+        keyword: String,
+        expected: &'static str,
+    },
+    #[error("invalid pattern: {0}")]
+    Pattern(#[from] regex::Error),
+    #[error("unsupported reference `{0}`, only references within the document are supported")]
+    UnsupportedReference(String),
+    #[error("reference `{0}` does not point to a schema of the document")]
+    UnresolvedReference(String),
+}
+
+enum Schema {
+    Bool(bool),
+    Keywords(Vec<Keyword>),
+}
+
+enum Keyword {
+    Type(Vec<JsonType>),
+    Enum(Vec<Value>),
+    StringLength(LengthValidator),
+    ArrayLength(LengthValidator),
+    ObjectLength(LengthValidator),
+    Minimum(RangeValidator<RangeFrom<f64>>),
+    ExclusiveMinimum(RangeValidator<(Bound<f64>, Bound<f64>)>),
+    Maximum(RangeValidator<RangeToInclusive<f64>>),
+    ExclusiveMaximum(RangeValidator<RangeTo<f64>>),
+    Pattern(RegexValidator),
+    Format(Format),
+    Required(Vec<String>),
+    Properties {
+        properties: Vec<(String, Schema)>,
+        additional: Option<Box<Schema>>,
+    },
+    PropertyNames(Box<Schema>),
+    Items {
+        prefix: Vec<Schema>,
+        items: Option<Box<Schema>>,
+    },
+    AllOf(Vec<Schema>),
+    AnyOf(Vec<Schema>),
+    OneOf(Vec<Schema>),
+    Ref(usize),
+}
+
+enum Format {
+    Email,
+    Uri,
+    Ipv4,
+    Ipv6,
+}
+
+impl JsonSchemaValidator {
+    /// Compiles a JSON Schema document, references being resolved within the document
+    pub fn compile(document: &Value) -> Result<Self, SchemaCompileError> {
+        let mut compiler = Compiler { document, references: HashMap::new(), definitions: Vec::new() };
+        let root = compiler.compile(document)?;
+        let definitions = compiler.definitions.into_iter()
+            .map(|definition| definition.unwrap_or(Schema::Bool(true)))
+            .collect();
+        Ok(Self { root, definitions })
+    }
+}
+
+impl Validator<Value> for JsonSchemaValidator {
+    type Error = JsonSchemaErrors;
+
+    fn validate(&self, value: &Value) -> Result<(), Self::Error> {
+        let mut errors = JsonSchemaErrors::default();
+        self.root.check(value, &self.definitions, &mut errors);
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+leaf_validator!(JsonSchemaValidator);
+
+struct Compiler<'a> {
+    document: &'a Value,
+    references: HashMap<String, usize>,
+    // a definition is only missing while it is being compiled
+    definitions: Vec<Option<Schema>>,
+}
+
+impl Compiler<'_> {
+    fn compile(&mut self, schema: &Value) -> Result<Schema, SchemaCompileError> {
+        let object = match schema {
+            Value::Bool(schema) => return Ok(Schema::Bool(*schema)),
+            Value::Object(object) => object,
+            _ => return Err(invalid("schema", "an object or a boolean")),
+        };
+        let mut keywords = Vec::new();
+        if let Some(reference) = object.get("$ref") {
+            let reference = reference.as_str().ok_or_else(|| invalid("$ref", "a string"))?;
+            keywords.push(Keyword::Ref(self.reference(reference)?));
+        }
+        if let Some(types) = object.get("type") {
+            let types = match types {
+                Value::Array(types) => types.iter().map(json_type).collect::<Result<_, _>>()?,
+                ty => vec![json_type(ty)?],
+            };
+            keywords.push(Keyword::Type(types));
+        }
+        if let Some(values) = object.get("enum") {
+            let values = values.as_array().ok_or_else(|| invalid("enum", "an array"))?;
+            keywords.push(Keyword::Enum(values.clone()));
+        }
+        if let Some(value) = object.get("const") {
+            keywords.push(Keyword::Enum(vec![value.clone()]));
+        }
+        // JSON Schema measures strings in characters
+        if let Some(length) = length(object, "minLength", "maxLength")? {
+            keywords.push(Keyword::StringLength(length.with_unit(LengthUnit::Chars)));
+        }
+        if let Some(length) = length(object, "minItems", "maxItems")? {
+            keywords.push(Keyword::ArrayLength(length));
+        }
+        if let Some(length) = length(object, "minProperties", "maxProperties")? {
+            keywords.push(Keyword::ObjectLength(length));
+        }
+        if let Some(minimum) = number(object, "minimum")? {
+            keywords.push(Keyword::Minimum(RangeValidator::new(minimum..)));
+        }
+        if let Some(minimum) = number(object, "exclusiveMinimum")? {
+            keywords.push(Keyword::ExclusiveMinimum(RangeValidator::new((Bound::Excluded(minimum), Bound::Unbounded))));
+        }
+        if let Some(maximum) = number(object, "maximum")? {
+            keywords.push(Keyword::Maximum(RangeValidator::new(..=maximum)));
+        }
+        if let Some(maximum) = number(object, "exclusiveMaximum")? {
+            keywords.push(Keyword::ExclusiveMaximum(RangeValidator::new(..maximum)));
+        }
+        if let Some(pattern) = object.get("pattern") {
+            let pattern = pattern.as_str().ok_or_else(|| invalid("pattern", "a string"))?;
+            keywords.push(Keyword::Pattern(RegexValidator::new(Regex::new(pattern)?)));
+        }
+        let format = match object.get("format").and_then(Value::as_str) {
+            Some("email") => Some(Format::Email),
+            Some("uri") => Some(Format::Uri),
+            Some("ipv4") => Some(Format::Ipv4),
+            Some("ipv6") => Some(Format::Ipv6),
+            _ => None,
+        };
+        keywords.extend(format.map(Keyword::Format));
+        if let Some(required) = object.get("required") {
+            let required = required.as_array()
+                .and_then(|required| required.iter().map(|name| name.as_str().map(str::to_string)).collect())
+                .ok_or_else(|| invalid("required", "an array of strings"))?;
+            keywords.push(Keyword::Required(required));
+        }
+        let properties = match object.get("properties") {
+            Some(Value::Object(properties)) => properties.iter()
+                .map(|(name, schema)| Ok((name.clone(), self.compile(schema)?)))
+                .collect::<Result<_, SchemaCompileError>>()?,
+            Some(_) => return Err(invalid("properties", "an object")),
+            None => Vec::new(),
+        };
+        let additional = self.subschema(object, "additionalProperties")?;
+        if !properties.is_empty() || additional.is_some() {
+            keywords.push(Keyword::Properties { properties, additional });
+        }
+        if let Some(names) = self.subschema(object, "propertyNames")? {
+            keywords.push(Keyword::PropertyNames(names));
+        }
+        let prefix = self.subschemas(object, "prefixItems")?.unwrap_or_default();
+        let items = self.subschema(object, "items")?;
+        if !prefix.is_empty() || items.is_some() {
+            keywords.push(Keyword::Items { prefix, items });
+        }
+        if let Some(schemas) = self.subschemas(object, "allOf")? {
+            keywords.push(Keyword::AllOf(schemas));
+        }
+        if let Some(schemas) = self.subschemas(object, "anyOf")? {
+            keywords.push(Keyword::AnyOf(schemas));
+        }
+        if let Some(schemas) = self.subschemas(object, "oneOf")? {
+            keywords.push(Keyword::OneOf(schemas));
+        }
+        Ok(Schema::Keywords(keywords))
+    }
+
+    fn subschema(&mut self, object: &Map<String, Value>, keyword: &str) -> Result<Option<Box<Schema>>, SchemaCompileError> {
+        object.get(keyword)
+            .map(|schema| Ok(Box::new(self.compile(schema)?)))
+            .transpose()
+    }
+
+    fn subschemas(&mut self, object: &Map<String, Value>, keyword: &str) -> Result<Option<Vec<Schema>>, SchemaCompileError> {
+        let Some(schemas) = object.get(keyword) else {
+            return Ok(None);
+        };
+        let schemas = schemas.as_array().ok_or_else(|| invalid(keyword, "an array of schemas"))?;
+        schemas.iter().map(|schema| self.compile(schema)).collect::<Result<_, _>>().map(Some)
+    }
+
+    // each referenced schema is compiled once, even if it refers to itself
+    fn reference(&mut self, reference: &str) -> Result<usize, SchemaCompileError> {
+        if let Some(index) = self.references.get(reference) {
+            return Ok(*index);
+        }
+        let pointer = reference.strip_prefix('#')
+            .ok_or_else(|| SchemaCompileError::UnsupportedReference(reference.to_string()))?;
+        let document = self.document;
+        let schema = document.pointer(pointer)
+            .ok_or_else(|| SchemaCompileError::UnresolvedReference(reference.to_string()))?;
+        let index = self.definitions.len();
+        self.references.insert(reference.to_string(), index);
+        self.definitions.push(None);
+        self.definitions[index] = Some(self.compile(schema)?);
+        Ok(index)
+    }
+}
+
+fn invalid(keyword: &str, expected: &'static str) -> SchemaCompileError {
+    SchemaCompileError::InvalidKeyword { keyword: keyword.to_string(), expected }
+}
+
+fn json_type(ty: &Value) -> Result<JsonType, SchemaCompileError> {
+    match ty.as_str() {
+        Some("null") => Ok(JsonType::Null),
+        Some("boolean") => Ok(JsonType::Boolean),
+        Some("integer") => Ok(JsonType::Integer),
+        Some("number") => Ok(JsonType::Number),
+        Some("string") => Ok(JsonType::String),
+        Some("array") => Ok(JsonType::Array),
+        Some("object") => Ok(JsonType::Object),
+        _ => Err(invalid("type", "a type name or an array of type names")),
+    }
+}
+
+fn number(object: &Map<String, Value>, keyword: &str) -> Result<Option<f64>, SchemaCompileError> {
+    object.get(keyword)
+        .map(|number| number.as_f64().ok_or_else(|| invalid(keyword, "a number")))
+        .transpose()
+}
+
+fn length(object: &Map<String, Value>, min: &str, max: &str) -> Result<Option<LengthValidator>, SchemaCompileError> {
+    let count = |keyword| object.get(keyword)
+        .map(|count: &Value| count.as_u64().map(|count| count as usize).ok_or_else(|| invalid(keyword, "a non-negative integer")))
+        .transpose();
+    let (min, max) = (count(min)?, count(max)?);
+    Ok((min.is_some() || max.is_some()).then(|| LengthValidator::new(min, max)))
+}
+
+impl Schema {
+    fn check(&self, value: &Value, definitions: &[Schema], errors: &mut JsonSchemaErrors) {
+        let keywords = match self {
+            Schema::Bool(true) => return,
+            Schema::Bool(false) => return errors.errors.push(NotAllowedError.into()),
+            Schema::Keywords(keywords) => keywords,
+        };
+        for keyword in keywords {
+            keyword.check(value, definitions, errors);
+        }
+    }
+
+    fn matches(&self, value: &Value, definitions: &[Schema]) -> Result<(), JsonSchemaErrors> {
+        let mut errors = JsonSchemaErrors::default();
+        self.check(value, definitions, &mut errors);
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+impl Keyword {
+    // the keywords only apply to values of their type, such as `minLength` to strings
+    fn check(&self, value: &Value, definitions: &[Schema], errors: &mut JsonSchemaErrors) {
+        let result: Result<(), JsonError> = match (self, value) {
+            (Keyword::Type(types), value) => {
+                let found = JsonType::of(value);
+                let matches = types.iter().any(|ty| *ty == found || (*ty == JsonType::Number && found == JsonType::Integer));
+                if matches {
+                    Ok(())
+                } else {
+                    Err(InvalidTypeError { expected: types.clone(), found }.into())
+                }
+            }
+            (Keyword::Enum(allowed), value) => {
+                if allowed.iter().any(|allowed| json_equal(allowed, value)) {
+                    Ok(())
+                } else {
+                    Err(UnexpectedValueError { allowed: allowed.clone() }.into())
+                }
+            }
+            (Keyword::StringLength(length), Value::String(string)) => length.validate(string.as_str()).map_err(Into::into),
+            (Keyword::ArrayLength(length), Value::Array(array)) => length.validate(array.as_slice()).map_err(Into::into),
+            (Keyword::ObjectLength(length), Value::Object(object)) => length.validate(object).map_err(Into::into),
+            (Keyword::Minimum(range), Value::Number(number)) => range.validate(&as_f64(number)).map_err(Into::into),
+            (Keyword::ExclusiveMinimum(range), Value::Number(number)) => range.validate(&as_f64(number)).map_err(Into::into),
+            (Keyword::Maximum(range), Value::Number(number)) => range.validate(&as_f64(number)).map_err(Into::into),
+            (Keyword::ExclusiveMaximum(range), Value::Number(number)) => range.validate(&as_f64(number)).map_err(Into::into),
+            (Keyword::Pattern(pattern), Value::String(string)) => pattern.validate(string.as_str()).map_err(Into::into),
+            (Keyword::Format(format), Value::String(string)) => match format {
+                Format::Email => EmailValidator.validate(string.as_str()).map_err(Into::into),
+                Format::Uri => UrlValidator.validate(string.as_str()).map_err(Into::into),
+                Format::Ipv4 => string.parse::<Ipv4Addr>().map(|_| ()).map_err(Into::into),
+                Format::Ipv6 => string.parse::<Ipv6Addr>().map(|_| ()).map_err(Into::into),
+            },
+            (Keyword::Required(required), Value::Object(object)) => {
+                for name in required.iter().filter(|name| !object.contains_key(*name)) {
+                    errors.nested(PathSegment::Field(name.clone().into())).errors.push(MissingValueError.into());
+                }
+                Ok(())
+            }
+            (Keyword::Properties { properties, additional }, Value::Object(object)) => {
+                for (name, value) in object {
+                    let schema = properties.iter()
+                        .find(|(property, _)| property == name)
+                        .map(|(_, schema)| schema)
+                        .or(additional.as_deref());
+                    if let Some(schema) = schema {
+                        schema.check(value, definitions, errors.nested(PathSegment::Field(name.clone().into())));
+                    }
+                }
+                Ok(())
+            }
+            (Keyword::PropertyNames(names), Value::Object(object)) => {
+                for name in object.keys() {
+                    names.check(&Value::String(name.clone()), definitions, errors.nested(PathSegment::Field(name.clone().into())));
+                }
+                Ok(())
+            }
+            (Keyword::Items { prefix, items }, Value::Array(array)) => {
+                for (index, value) in array.iter().enumerate() {
+                    if let Some(schema) = prefix.get(index).or(items.as_deref()) {
+                        schema.check(value, definitions, errors.nested(PathSegment::Index(index)));
+                    }
+                }
+                Ok(())
+            }
+            (Keyword::AllOf(schemas), value) => {
+                for schema in schemas {
+                    schema.check(value, definitions, errors);
+                }
+                Ok(())
+            }
+            (Keyword::AnyOf(schemas), value) => {
+                let mut alternatives = Vec::new();
+                for schema in schemas {
+                    match schema.matches(value, definitions) {
+                        Ok(()) => {
+                            alternatives.clear();
+                            break;
+                        }
+                        Err(errors) => alternatives.push(errors),
+                    }
+                }
+                if alternatives.is_empty() {
+                    Ok(())
+                } else {
+                    Err(JsonError::NoneMatched(alternatives))
+                }
+            }
+            (Keyword::OneOf(schemas), value) => {
+                let results: Vec<_> = schemas.iter().map(|schema| schema.matches(value, definitions)).collect();
+                match results.iter().filter(|result| result.is_ok()).count() {
+                    1 => Ok(()),
+                    0 => Err(JsonError::NoneMatched(results.into_iter().filter_map(Result::err).collect())),
+                    matched => Err(SeveralMatchedError { matched }.into()),
+                }
+            }
+            (Keyword::Ref(index), value) => {
+                definitions[*index].check(value, definitions, errors);
+                Ok(())
+            }
+            _ => Ok(()),
+        };
+        if let Err(error) = result {
+            errors.errors.push(error);
+        }
+    }
+}
+
+fn as_f64(number: &serde_json::Number) -> f64 {
+    number.as_f64().unwrap_or(f64::NAN)
+}
+
+// JSON Schema compares numbers by their value, so that `1` and `1.0` are equal, including in arrays and objects,
+// integers being compared exactly rather than as floats
+fn json_equal(left: &Value, right: &Value) -> bool {
+    fn integer(number: &serde_json::Number) -> Option<i128> {
+        number.as_i64().map(i128::from).or_else(|| number.as_u64().map(i128::from))
+    }
+
+    match (left, right) {
+        (Value::Number(left), Value::Number(right)) => match (integer(left), integer(right)) {
+            (Some(left), Some(right)) => left == right,
+            _ => as_f64(left) == as_f64(right),
+        },
+        (Value::Array(left), Value::Array(right)) => {
+            left.len() == right.len() && left.iter().zip(right).all(|(left, right)| json_equal(left, right))
+        }
+        (Value::Object(left), Value::Object(right)) => {
+            left.len() == right.len()
+                && left.iter().all(|(key, left)| right.get(key).is_some_and(|right| json_equal(left, right)))
+        }
+        (left, right) => left == right,
+    }
+}
+
+impl JsonType {
+    pub fn of(value: &Value) -> Self {
+        match value {
+            Value::Null => JsonType::Null,
+            Value::Bool(_) => JsonType::Boolean,
+            Value::Number(number) if number.is_i64() || number.is_u64() => JsonType::Integer,
+            Value::Number(number) if number.as_f64().is_some_and(|number| number.fract() == 0.0) => JsonType::Integer,
+            Value::Number(_) => JsonType::Number,
+            Value::String(_) => JsonType::String,
+            Value::Array(_) => JsonType::Array,
+            Value::Object(_) => JsonType::Object,
+        }
+    }
+}
+
+impl Display for JsonType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            JsonType::Null => "null",
+            JsonType::Boolean => "boolean",
+            JsonType::Integer => "integer",
+            JsonType::Number => "number",
+            JsonType::String => "string",
+            JsonType::Array => "array",
+            JsonType::Object => "object",
+        };
+        write!(f, "{name}")
+    }
+}
+
+fn display_types(types: &[JsonType]) -> String {
+    types.iter().map(ToString::to_string).collect::<Vec<_>>().join(" or ")
+}
+
+fn display_values(values: &[Value]) -> String {
+    values.iter().map(ToString::to_string).collect::<Vec<_>>().join(", ")
+}
+
+fn display_alternatives(alternatives: &[JsonSchemaErrors]) -> String {
+    alternatives.iter().map(ToString::to_string).collect::<Vec<_>>().join(" or ")
+}
+
+impl HasLength for Map<String, Value> {
+    fn _len(&self) -> usize {
+        self.len()
+    }
+}
+
+impl JsonSchemaErrors {
+    /// The errors of the value itself, not including the errors of its properties and items
+    pub fn errors(&self) -> &[JsonError] {
+        &self.errors
+    }
+
+    /// The errors of a property, if it is invalid
+    pub fn property(&self, name: &str) -> Option<&JsonSchemaErrors> {
+        self.get(&PathSegment::Field(name.to_string().into()))
+    }
+
+    /// The errors of an item, if it is invalid
+    pub fn item(&self, index: usize) -> Option<&JsonSchemaErrors> {
+        self.get(&PathSegment::Index(index))
+    }
+
+    fn get(&self, segment: &PathSegment) -> Option<&JsonSchemaErrors> {
+        self.nested.iter().find(|(other, _)| other == segment).map(|(_, errors)| errors)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.errors.is_empty() && self.nested.iter().all(|(_, errors)| errors.is_empty())
+    }
+
+    // the errors of a property or an item, which are gathered from all the keywords
+    fn nested(&mut self, segment: PathSegment) -> &mut JsonSchemaErrors {
+        let index = match self.nested.iter().position(|(other, _)| *other == segment) {
+            Some(index) => index,
+            None => {
+                self.nested.push((segment, JsonSchemaErrors::default()));
+                self.nested.len() - 1
+            }
+        };
+        &mut self.nested[index].1
+    }
+}
+
+impl Display for JsonSchemaErrors {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut separator = "";
+        for (path, error) in ValidationError::errors(self) {
+            if path == ErrorPath::root() {
+                write!(f, "{separator}{error}")?;
+            } else {
+                write!(f, "{separator}{path}: {error}")?;
+            }
+            separator = "; ";
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for JsonSchemaErrors {}
+
+impl ValidationError for JsonSchemaErrors {
+    fn collect_errors<'a>(&'a self, path: &mut ErrorPath, errors: &mut ErrorList<'a>) {
+        for error in &self.errors {
+            error.collect_errors(path, errors);
+        }
+        for (segment, nested) in &self.nested {
+            if !nested.is_empty() {
+                path.collect_nested(segment.clone(), nested, errors);
+            }
+        }
+    }
+}
+
+// the errors are reported as the errors of the validators which checked the keywords
+impl ValidationError for JsonError {
+    fn collect_errors<'a>(&'a self, path: &mut ErrorPath, errors: &mut ErrorList<'a>) {
+        match self {
+            JsonError::Type(error) => error.collect_errors(path, errors),
+            JsonError::Enum(error) => error.collect_errors(path, errors),
+            JsonError::Required(error) => error.collect_errors(path, errors),
+            JsonError::NotAllowed(error) => error.collect_errors(path, errors),
+            JsonError::Length(error) => error.collect_errors(path, errors),
+            JsonError::Minimum(error) => error.collect_errors(path, errors),
+            JsonError::ExclusiveMinimum(error) => error.collect_errors(path, errors),
+            JsonError::Maximum(error) => error.collect_errors(path, errors),
+            JsonError::ExclusiveMaximum(error) => error.collect_errors(path, errors),
+            JsonError::Pattern(error) => error.collect_errors(path, errors),
+            JsonError::Email(error) => error.collect_errors(path, errors),
+            JsonError::Url(error) => error.collect_errors(path, errors),
+            JsonError::Ip(error) => error.collect_errors(path, errors),
+            JsonError::NoneMatched(alternatives) => {
+                for alternative in alternatives {
+                    alternative.collect_errors(path, errors);
+                }
+            }
+            JsonError::SeveralMatched(error) => error.collect_errors(path, errors),
+        }
+    }
+}
+
+leaf_error!(InvalidTypeError);
+leaf_error!(UnexpectedValueError);
+leaf_error!(NotAllowedError);
+
+impl ErrorCode for InvalidTypeError {
+    fn code(&self) -> &str {
+        "type"
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        vec![("expected", display_types(&self.expected)), ("found", self.found.to_string())]
+    }
+}
+
+impl ErrorCode for UnexpectedValueError {
+    fn code(&self) -> &str {
+        "enum"
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        vec![("allowed", display_values(&self.allowed))]
+    }
+}
+
+impl ErrorCode for NotAllowedError {
+    fn code(&self) -> &str {
+        "not_allowed"
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use crate::{InvalidLengthError, JsonError, JsonSchemaValidator, LengthUnit, SchemaCompileError, Validator};
+
+    #[test]
+    fn checks_keywords_of_the_value_type() {
+        let validator = JsonSchemaValidator::compile(&json!({ "minLength": 2, "minimum": 3 })).unwrap();
+        validator.validate(&json!(5)).expect("should be valid");
+        validator.validate(&json!("ab")).expect("should be valid");
+        let errors = validator.validate(&json!("é")).unwrap_err();
+        assert_eq!(errors.errors(), [JsonError::Length(InvalidLengthError::TooShort { min: 2, len: 1, unit: Some(LengthUnit::Chars) })]);
+    }
+
+    #[test]
+    fn compares_numbers_by_value() {
+        let validator = JsonSchemaValidator::compile(&json!({ "enum": [1, [2, { "a": 3 }]] })).unwrap();
+        validator.validate(&json!(1.0)).expect("should be valid");
+        validator.validate(&json!([2.0, { "a": 3.0 }])).expect("should be valid");
+        validator.validate(&json!(1.5)).expect_err("should be invalid");
+        validator.validate(&json!("1")).expect_err("should be invalid");
+        let validator = JsonSchemaValidator::compile(&json!({ "const": u64::MAX })).unwrap();
+        validator.validate(&json!(u64::MAX)).expect("should be valid");
+        validator.validate(&json!(u64::MAX - 1)).expect_err("should be invalid");
+    }
+
+    #[test]
+    fn rejects_invalid_documents() {
+        let error = JsonSchemaValidator::compile(&json!({ "type": "text" })).err().unwrap();
+        assert_eq!(error.to_string(), "invalid value for `type`: expected a type name or an array of type names");
+        let error = JsonSchemaValidator::compile(&json!({ "$ref": "https://example.com/schema.json" })).err().unwrap();
+        assert!(matches!(error, SchemaCompileError::UnsupportedReference(_)));
+        assert!(matches!(JsonSchemaValidator::compile(&json!({ "pattern": "[" })), Err(SchemaCompileError::Pattern(_))));
+    }
+}
@@ -1,29 +1,92 @@
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque};
+use std::fmt::{Display, Formatter};
+use std::rc::Rc;
+use std::sync::Arc;
+
 use thiserror::Error;
-use crate::Validator;
+use crate::{ErrorCode, Validator};
+
+pub struct LengthValidator(Option<usize>, Option<usize>, Option<LengthUnit>);
 
-pub struct LengthValidator(Option<usize>, Option<usize>);
+/// The unit in which the length of a string is measured, the length of other values ignoring it.
+///
+/// It is non-exhaustive as `Graphemes` only exists with the `graphemes` feature, which any crate of a build may enable.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum LengthUnit {
+    /// UTF-8 bytes, the default for strings
+    Bytes,
+    /// Unicode scalar values
+    Chars,
+    /// Extended grapheme clusters, the characters perceived by users
+    #[cfg(feature = "graphemes")]
+    Graphemes,
+    /// UTF-16 code units, as counted by JavaScript
+    Utf16,
+}
 
 #[derive(Debug, Error, PartialEq, Clone)]
 pub enum InvalidLengthError {
-    #[error("value of length {len} exceeds maximum of {max}")]
+    #[error("value of length {len}{} exceeds maximum of {max}", Unit(.unit))]
     TooLong {
         max: usize,
-        len: usize
+        len: usize,
+        unit: Option<LengthUnit>,
     },
-    #[error("value of length {len} falls short of minimum of {min}")]
+    #[error("value of length {len}{} falls short of minimum of {min}", Unit(.unit))]
     TooShort {
         min: usize,
-        len: usize
+        len: usize,
+        unit: Option<LengthUnit>,
+    }
+}
+
+impl Display for LengthUnit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LengthUnit::Bytes => write!(f, "bytes"),
+            LengthUnit::Chars => write!(f, "characters"),
+            #[cfg(feature = "graphemes")]
+            LengthUnit::Graphemes => write!(f, "graphemes"),
+            LengthUnit::Utf16 => write!(f, "UTF-16 code units"),
+        }
+    }
+}
+
+// formats the unit of a length after the length, if it was set
+struct Unit<'a>(&'a Option<LengthUnit>);
+
+impl Display for Unit<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            Some(unit) => write!(f, " {unit}"),
+            None => Ok(()),
+        }
     }
 }
 
-pub(crate) trait HasLength {
+/// A value with a length, checked by [LengthValidator] and [NotEmptyValidator](crate::NotEmptyValidator).
+///
+/// `String`, `Vec<T>` and arrays are validated through `str` and `[T]`, other collections can implement this
+/// to be validated as well.
+pub trait HasLength {
     fn _len(&self) -> usize;
+
+    /// The length in the given unit, only strings having several ways of measuring their length
+    fn _len_in(&self, _unit: LengthUnit) -> usize {
+        self._len()
+    }
 }
 
 impl LengthValidator {
     pub fn new(min: Option<usize>, max: Option<usize>) -> Self {
-        Self(min, max)
+        Self(min, max, None)
+    }
+
+    /// Measures the length of strings in the given unit rather than in bytes
+    pub fn with_unit(self, unit: LengthUnit) -> Self {
+        Self(self.0, self.1, Some(unit))
     }
 }
 
@@ -31,30 +94,176 @@ impl<T: HasLength + ?Sized> Validator<T> for LengthValidator {
     type Error = InvalidLengthError;
 
     fn validate(&self, value: &T) -> Result<(), Self::Error> {
-        let len = value._len();
-        let Self(min, max) = self;
-        if let Some(&min) = min.as_ref() {
+        let &Self(min, max, unit) = self;
+        let len = match unit {
+            Some(unit) => value._len_in(unit),
+            None => value._len(),
+        };
+        if let Some(min) = min {
             if len < min {
-                return Err(InvalidLengthError::TooShort { min, len })
+                return Err(InvalidLengthError::TooShort { min, len, unit })
             }
         }
-        if let Some(&max) = max.as_ref() {
+        if let Some(max) = max {
             if len > max {
-                return Err(InvalidLengthError::TooLong { max, len })
+                return Err(InvalidLengthError::TooLong { max, len, unit })
             }
         }
         Ok(())
     }
 }
 
+leaf_validator!(LengthValidator);
+leaf_error!(InvalidLengthError);
+
+impl ErrorCode for InvalidLengthError {
+    fn code(&self) -> &str {
+        match self {
+            InvalidLengthError::TooLong { .. } => "length.too_long",
+            InvalidLengthError::TooShort { .. } => "length.too_short",
+        }
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        let (limit, len, unit) = match self {
+            InvalidLengthError::TooLong { max, len, unit } => (("max", max.to_string()), len, unit),
+            InvalidLengthError::TooShort { min, len, unit } => (("min", min.to_string()), len, unit),
+        };
+        let mut params = vec![limit, ("len", len.to_string())];
+        params.extend(unit.map(|unit| ("unit", unit.to_string())));
+        params
+    }
+}
+
 impl HasLength for str {
     fn _len(&self) -> usize {
         self.len()
     }
+
+    fn _len_in(&self, unit: LengthUnit) -> usize {
+        match unit {
+            LengthUnit::Bytes => self.len(),
+            LengthUnit::Chars => self.chars().count(),
+            #[cfg(feature = "graphemes")]
+            LengthUnit::Graphemes => unicode_segmentation::UnicodeSegmentation::graphemes(self, true).count(),
+            LengthUnit::Utf16 => self.encode_utf16().count(),
+        }
+    }
 }
 
 impl<T> HasLength for [T] {
     fn _len(&self) -> usize {
         self.len()
     }
-}
\ No newline at end of file
+}
+impl<T: HasLength + ?Sized> HasLength for &T {
+    fn _len(&self) -> usize {
+        (**self)._len()
+    }
+
+    fn _len_in(&self, unit: LengthUnit) -> usize {
+        (**self)._len_in(unit)
+    }
+}
+
+impl<T: HasLength + ?Sized> HasLength for Box<T> {
+    fn _len(&self) -> usize {
+        (**self)._len()
+    }
+
+    fn _len_in(&self, unit: LengthUnit) -> usize {
+        (**self)._len_in(unit)
+    }
+}
+
+impl<T: HasLength + ?Sized> HasLength for Rc<T> {
+    fn _len(&self) -> usize {
+        (**self)._len()
+    }
+
+    fn _len_in(&self, unit: LengthUnit) -> usize {
+        (**self)._len_in(unit)
+    }
+}
+
+impl<T: HasLength + ?Sized> HasLength for Arc<T> {
+    fn _len(&self) -> usize {
+        (**self)._len()
+    }
+
+    fn _len_in(&self, unit: LengthUnit) -> usize {
+        (**self)._len_in(unit)
+    }
+}
+
+impl<T: HasLength + ToOwned + ?Sized> HasLength for Cow<'_, T> {
+    fn _len(&self) -> usize {
+        (**self)._len()
+    }
+
+    fn _len_in(&self, unit: LengthUnit) -> usize {
+        (**self)._len_in(unit)
+    }
+}
+
+// implements HasLength for collections with a `len` method
+macro_rules! collection_length {
+    ($(<$($generic:ident),*> $collection:ty),* $(,)?) => {
+        $(
+        impl<$($generic),*> HasLength for $collection {
+            fn _len(&self) -> usize {
+                self.len()
+            }
+        }
+        )*
+    };
+}
+
+collection_length!(
+    <T> VecDeque<T>,
+    <T> LinkedList<T>,
+    <T> BinaryHeap<T>,
+    <T, S> HashSet<T, S>,
+    <T> BTreeSet<T>,
+    <K, V, S> HashMap<K, V, S>,
+    <K, V> BTreeMap<K, V>,
+);
+
+#[cfg(feature = "indexmap")]
+collection_length!(
+    <K, V, S> indexmap::IndexMap<K, V, S>,
+    <T, S> indexmap::IndexSet<T, S>,
+);
+
+#[cfg(test)]
+mod test {
+    use crate::{InvalidLengthError, LengthUnit, LengthValidator, Validator};
+
+    #[test]
+    fn units() {
+        let name = "山田太郎さん";
+        LengthValidator::new(None, Some(10)).validate(name).expect_err("18 bytes should be too long");
+        LengthValidator::new(None, Some(10)).with_unit(LengthUnit::Chars).validate(name).expect("should be valid");
+        assert_eq!(LengthValidator::new(None, Some(1)).with_unit(LengthUnit::Utf16).validate("😀"), Err(InvalidLengthError::TooLong {
+            max: 1,
+            len: 2,
+            unit: Some(LengthUnit::Utf16),
+        }));
+    }
+
+    #[cfg(feature = "graphemes")]
+    #[test]
+    fn graphemes() {
+        let family = "👨‍👩‍👧";
+        LengthValidator::new(None, Some(1)).with_unit(LengthUnit::Chars).validate(family).expect_err("should be too long");
+        LengthValidator::new(None, Some(1)).with_unit(LengthUnit::Graphemes).validate(family).expect("should be valid");
+    }
+
+    #[test]
+    fn display() {
+        let error = LengthValidator::new(Some(3), None).with_unit(LengthUnit::Chars).validate("ab").unwrap_err();
+        assert_eq!(error.to_string(), "value of length 2 characters falls short of minimum of 3");
+        let error = LengthValidator::new(Some(3), None).validate(&[1, 2]).unwrap_err();
+        assert_eq!(error.to_string(), "value of length 2 falls short of minimum of 3");
+    }
+}
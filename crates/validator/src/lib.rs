@@ -1,8 +1,60 @@
 use std::convert::Infallible;
 use std::fmt::Debug;
+#[cfg(feature = "async")]
+use std::future::Future;
 
 #[cfg(feature = "derive")]
 pub use ::validator_derive::Validator;
+// implements ValidatorWithContext, AsyncValidator and ValidatorExt for validators which only need the value itself
+// implements ValidatorWithContext and AsyncValidator for validators which only need the value itself
+macro_rules! leaf_validator {
+    ($validator:ident) => {
+        leaf_validator!(<> $validator);
+    };
+    (<$($generic:ident $(: ?$sized:ident)?),*> $validator:ty) => {
+        impl<$($generic $(: ?$sized)?,)* T: ?Sized, C: ?Sized> crate::ValidatorWithContext<T, C> for $validator
+        where
+            $validator: crate::Validator<T>
+        {
+            type Error = <$validator as crate::Validator<T>>::Error;
+
+            fn validate_with(&self, value: &T, _: &C) -> Result<(), Self::Error> {
+                crate::Validator::validate(self, value)
+            }
+        }
+
+        #[cfg(feature = "async")]
+        impl<$($generic $(: ?$sized)?,)* T: ?Sized> crate::AsyncValidator<T> for $validator
+        where
+            $validator: crate::Validator<T>
+        {
+            type Error = <$validator as crate::Validator<T>>::Error;
+
+            async fn validate_async(&self, value: &T) -> Result<(), Self::Error> {
+                crate::Validator::validate(self, value)
+            }
+        }
+
+        impl<$($generic $(: ?$sized)?),*> crate::ValidatorExt for $validator {}
+    };
+}
+
+// implements ValidationError for errors which are not made up of other errors
+macro_rules! leaf_error {
+    ($error:ident) => {
+        leaf_error!(<> $error);
+    };
+    (<$($generic:ident),*> $error:ty) => {
+        impl<$($generic),*> crate::ValidationError for $error
+        where
+            $error: std::error::Error + crate::ErrorCode
+        {
+            fn collect_errors<'a>(&'a self, path: &mut crate::ErrorPath, errors: &mut crate::ErrorList<'a>) {
+                errors.push((path.clone(), self));
+            }
+        }
+    };
+}
 
 macro_rules! modules {
     ($($module:ident),*) => {
@@ -13,20 +65,212 @@ macro_rules! modules {
     };
 }
 
-modules!(not_empty, and, or, email, url, ip, length, elements, tuple, range);
+modules!(not_empty, and, or, then, email, url, ip, length, chars, elements, tuple, range, options, errors, custom, schema, optional, map, message, catalog, problem, pattern, content, one_of, not, variadic, ext, dynamic);
+
+#[cfg(feature = "serde")]
+mod serialize;
+#[cfg(feature = "serde")]
+pub use serialize::*;
+
+#[cfg(feature = "json-schema")]
+mod json_schema;
+#[cfg(feature = "json-schema")]
+pub use json_schema::*;
+#[cfg(feature = "json-schema")]
+mod json_validator;
+#[cfg(feature = "json-schema")]
+pub use json_validator::*;
 
 pub trait Validate {
-    type Validator: Validator<Self>;
+    type Validator;
 
     fn validator() -> Self::Validator;
-    fn validate(&self) -> Result<(), <Self::Validator as Validator<Self>>::Error> {
+    fn validate(&self) -> Result<(), <Self::Validator as Validator<Self>>::Error>
+    where
+        Self::Validator: Validator<Self>
+    {
         Self::validator().validate(self)
     }
+
+    /// Validates this value, stopping early as configured by the given options
+    fn validate_with_options(&self, options: ValidationOptions) -> Result<(), <Self::Validator as Validator<Self>>::Error>
+    where
+        Self::Validator: Validator<Self>
+    {
+        Self::validator().validate_with_options(self, options)
+    }
+
+    /// Validates this value, passing the given context down to every nested validator
+    fn validate_with<C: ?Sized>(&self, context: &C) -> Result<(), <Self::Validator as ValidatorWithContext<Self, C>>::Error>
+    where
+        Self::Validator: ValidatorWithContext<Self, C>
+    {
+        Self::validator().validate_with(self, context)
+    }
+
+    /// Validates this value using its asynchronous validator
+    #[cfg(feature = "async")]
+    fn validate_async(&self) -> impl Future<Output=Result<(), <Self::Validator as AsyncValidator<Self>>::Error>>
+    where
+        Self::Validator: AsyncValidator<Self>
+    {
+        async move {
+            Self::validator().validate_async(self).await
+        }
+    }
 }
 
 pub trait Validator<T: ?Sized>: Sized {
     type Error: Debug;
     fn validate(&self, value: &T) -> Result<(), Self::Error>;
+
+    /// Validates the value, skipping any validation left once the budget is exhausted.
+    ///
+    /// Validators made up of other validators should override this to pass the budget on to them,
+    /// any other validator spends one error from the budget when it fails.
+    fn validate_budgeted(&self, value: &T, budget: &mut ErrorBudget) -> Result<(), Self::Error> {
+        if budget.is_exhausted() {
+            return Ok(());
+        }
+        let result = self.validate(value);
+        if result.is_err() {
+            budget.spend();
+        }
+        result
+    }
+
+    fn validate_with_options(&self, value: &T, options: ValidationOptions) -> Result<(), Self::Error> {
+        self.validate_budgeted(value, &mut options.budget())
+    }
+}
+
+/// A validator which has access to some runtime state (per-tenant limits, feature flags, the current user, ...)
+/// while validating a value.
+///
+/// Validators which do not need a context implement this for any context type `C` and simply ignore it,
+/// while combinators such as [And], [Or], [ElementsValidator] and tuples pass the context on to their children.
+pub trait ValidatorWithContext<T: ?Sized, C: ?Sized>: Sized {
+    type Error: Debug;
+    fn validate_with(&self, value: &T, context: &C) -> Result<(), Self::Error>;
+}
+
+/// A validator which may need to perform I/O, such as checking that a username is not already taken.
+///
+/// All the built-in validators implement this, [And], [ElementsValidator] and tuples run the validation of their
+/// children concurrently, while [Or] only runs the right validator if the left one fails.
+#[cfg(feature = "async")]
+pub trait AsyncValidator<T: ?Sized>: Sized {
+    type Error: Debug;
+    fn validate_async(&self, value: &T) -> impl Future<Output=Result<(), Self::Error>>;
+}
+
+#[doc(hidden)]
+pub mod __private {
+    use std::fmt::{Display, Formatter};
+
+    #[cfg(feature = "async")]
+    pub use futures::join;
+    pub use once_cell::sync::Lazy;
+    pub use regex::Regex;
+    #[cfg(feature = "serde")]
+    pub use serde;
+    #[cfg(feature = "json-schema")]
+    pub use serde_json;
+
+    /// The result of the function of a `custom` rule, whose error must be the one declared by the rule
+    #[diagnostic::on_unimplemented(
+        message = "the function of a `custom` rule returns `{Self}` rather than `Result<(), {E}>`",
+        label = "expected a function returning `Result<(), {E}>`",
+        note = "functions returning another error are declared as `custom(function, error = MyError)`"
+    )]
+    pub trait CustomResult<E> {
+        fn into_result(self) -> Result<(), E>;
+    }
+
+    impl<E> CustomResult<E> for Result<(), E> {
+        fn into_result(self) -> Result<(), E> {
+            self
+        }
+    }
+
+    /// Formats the errors of the invalid fields as `name: error`, separated by semicolons,
+    /// errors of the whole value having an empty name
+    pub fn display_fields(f: &mut Formatter<'_>, fields: &[(&str, Option<&dyn Display>)]) -> std::fmt::Result {
+        let mut separator = "";
+        for (name, error) in fields {
+            if let Some(error) = error {
+                if name.is_empty() {
+                    write!(f, "{separator}{error}")?;
+                } else {
+                    write!(f, "{separator}{name}: {error}")?;
+                }
+                separator = "; ";
+            }
+        }
+        Ok(())
+    }
+}
+
+// used by the derive so that the generated code does not depend on whether the async feature is enabled
+#[doc(hidden)]
+#[cfg(feature = "async")]
+#[macro_export]
+macro_rules! __if_async {
+    ($($tokens:tt)*) => { $($tokens)* };
+}
+
+#[doc(hidden)]
+#[cfg(not(feature = "async"))]
+#[macro_export]
+macro_rules! __if_async {
+    ($($tokens:tt)*) => {};
+}
+
+// used by the derive so that the generated code does not depend on whether the serde feature is enabled
+#[doc(hidden)]
+#[cfg(feature = "serde")]
+#[macro_export]
+macro_rules! __if_serde {
+    ($($tokens:tt)*) => { $($tokens)* };
+}
+
+#[doc(hidden)]
+#[cfg(not(feature = "serde"))]
+#[macro_export]
+macro_rules! __if_serde {
+    ($($tokens:tt)*) => {};
+}
+
+// used by the derive so that the generated code does not depend on whether the json-schema feature is enabled
+#[doc(hidden)]
+#[cfg(feature = "json-schema")]
+#[macro_export]
+macro_rules! __if_json_schema {
+    ($($tokens:tt)*) => { $($tokens)* };
+}
+
+#[doc(hidden)]
+#[cfg(not(feature = "json-schema"))]
+#[macro_export]
+macro_rules! __if_json_schema {
+    ($($tokens:tt)*) => {};
+}
+
+// used by the derive for the graphemes length unit, which only exists with the graphemes feature
+#[doc(hidden)]
+#[cfg(feature = "graphemes")]
+#[macro_export]
+macro_rules! __require_graphemes {
+    ($($tokens:tt)*) => { $($tokens)* };
+}
+
+#[doc(hidden)]
+#[cfg(not(feature = "graphemes"))]
+#[macro_export]
+macro_rules! __require_graphemes {
+    ($($tokens:tt)*) => {
+        compile_error!(r#"unit = "graphemes" requires the "graphemes" feature of the validator crate"#)
+    };
 }
 
 #[doc(hidden)]
@@ -41,12 +285,20 @@ impl<T> Validator<T> for IgnoreValidator {
     }
 }
 
+leaf_validator!(IgnoreValidator);
+
+leaf_error!(Infallible);
+
 impl<V> Validator<String> for V where V: Validator<str> {
     type Error = V::Error;
 
     fn validate(&self, value: &String) -> Result<(), Self::Error> {
         self.validate(value)
     }
+
+    fn validate_budgeted(&self, value: &String, budget: &mut ErrorBudget) -> Result<(), Self::Error> {
+        self.validate_budgeted(value, budget)
+    }
 }
 
 impl<V, T> Validator<Vec<T>> for V where V: Validator<[T]> {
@@ -55,6 +307,10 @@ impl<V, T> Validator<Vec<T>> for V where V: Validator<[T]> {
     fn validate(&self, value: &Vec<T>) -> Result<(), Self::Error> {
         self.validate(value)
     }
+
+    fn validate_budgeted(&self, value: &Vec<T>, budget: &mut ErrorBudget) -> Result<(), Self::Error> {
+        self.validate_budgeted(value, budget)
+    }
 }
 
 impl<V, T, const N: usize> Validator<[T; N]> for V where V: Validator<[T]> {
@@ -63,4 +319,8 @@ impl<V, T, const N: usize> Validator<[T; N]> for V where V: Validator<[T]> {
     fn validate(&self, value: &[T; N]) -> Result<(), Self::Error> {
         self.validate(value)
     }
+
+    fn validate_budgeted(&self, value: &[T; N], budget: &mut ErrorBudget) -> Result<(), Self::Error> {
+        self.validate_budgeted(value, budget)
+    }
 }
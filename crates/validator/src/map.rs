@@ -0,0 +1,444 @@
+use std::borrow::Borrow;
+use std::collections::{BTreeMap, HashMap};
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::BuildHasher;
+
+use thiserror::Error;
+
+use crate::{ErrorBudget, ErrorCode, ErrorList, ErrorPath, PathSegment, Validate, ValidationError, Validator, ValidatorWithContext};
+
+/// Validates the keys of a map
+pub struct KeysValidator<V>(V);
+
+/// Validates the values of a map
+pub struct ValuesValidator<V>(V);
+
+/// Validates both the key and the value of each entry of a map
+pub struct EntriesValidator<K, V>(K, V);
+
+/// The errors of the invalid entries of a map, along with their keys.
+/// Only the invalid entries are stored, in the order in which the map was iterated.
+#[derive(Debug, PartialEq, Clone)]
+pub struct MapInvalid<K, E> {
+    errors: Vec<(K, E)>,
+}
+
+/// The error of an invalid entry of a map, its key, its value or both being invalid
+#[derive(Debug, PartialEq, Clone, Error)]
+pub enum EntryError<K, V> {
+    #[error("key: {0}")]
+    Key(K),
+    #[error("value: {0}")]
+    Value(V),
+    #[error("key: {0}, value: {1}")]
+    Both(K, V),
+}
+
+pub trait HasEntries {
+    type Key;
+    type Value;
+    fn _entries(&self) -> impl Iterator<Item=(&Self::Key, &Self::Value)>;
+}
+
+impl<K, E> MapInvalid<K, E> {
+    fn from_results<'a>(results: impl IntoIterator<Item=(&'a K, Option<E>)>) -> Result<(), Self>
+    where
+        K: Clone + 'a
+    {
+        let errors: Vec<_> = results.into_iter()
+            .filter_map(|(key, error)| Some((key.clone(), error?)))
+            .collect();
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(MapInvalid { errors })
+        }
+    }
+
+    /// Returns the error of the entry with the given key, if it was invalid
+    pub fn get<Q>(&self, key: &Q) -> Option<&E>
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + ?Sized
+    {
+        self.errors.iter()
+            .find(|(other, _)| other.borrow() == key)
+            .map(|(_, error)| error)
+    }
+
+    /// Iterates over the invalid entries, along with their keys
+    pub fn iter(&self) -> impl Iterator<Item=(&K, &E)> {
+        self.errors.iter().map(|(key, error)| (key, error))
+    }
+}
+
+impl<K, V> EntryError<K, V> {
+    fn from_results(key: Option<K>, value: Option<V>) -> Option<Self> {
+        match (key, value) {
+            (None, None) => None,
+            (Some(key), None) => Some(EntryError::Key(key)),
+            (None, Some(value)) => Some(EntryError::Value(value)),
+            (Some(key), Some(value)) => Some(EntryError::Both(key, value)),
+        }
+    }
+
+    pub fn key(&self) -> Option<&K> {
+        match self {
+            EntryError::Key(key) | EntryError::Both(key, _) => Some(key),
+            EntryError::Value(_) => None,
+        }
+    }
+
+    pub fn value(&self) -> Option<&V> {
+        match self {
+            EntryError::Value(value) | EntryError::Both(_, value) => Some(value),
+            EntryError::Key(_) => None,
+        }
+    }
+}
+
+// formats the errors as `key: error`, separated by commas
+impl<K: Display, E: Display> Display for MapInvalid<K, E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut separator = "";
+        for (key, error) in &self.errors {
+            write!(f, "{separator}{key}: {error}")?;
+            separator = ", ";
+        }
+        Ok(())
+    }
+}
+
+// the source is the error of the first invalid entry
+impl<K: Display + Debug, E: Error + 'static> Error for MapInvalid<K, E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.errors.first().map(|(_, error)| error as &(dyn Error + 'static))
+    }
+}
+
+// the errors of an entry are reported at the path of its key
+impl<K: Display + Debug, E: ValidationError> ValidationError for MapInvalid<K, E> {
+    fn collect_errors<'a>(&'a self, path: &mut ErrorPath, errors: &mut ErrorList<'a>) {
+        for (key, error) in &self.errors {
+            path.collect_nested(PathSegment::Field(key.to_string().into()), error, errors);
+        }
+    }
+}
+
+impl<K: ValidationError, V: ValidationError> ValidationError for EntryError<K, V> {
+    fn collect_errors<'a>(&'a self, path: &mut ErrorPath, errors: &mut ErrorList<'a>) {
+        if let Some(key) = self.key() {
+            key.collect_errors(path, errors);
+        }
+        if let Some(value) = self.value() {
+            value.collect_errors(path, errors);
+        }
+    }
+}
+
+impl<K, E> ErrorCode for MapInvalid<K, E> {
+    fn code(&self) -> &str {
+        "entries"
+    }
+}
+
+// the code and parameters are the ones of the key if it is invalid
+impl<K: ErrorCode, V: ErrorCode> ErrorCode for EntryError<K, V> {
+    fn code(&self) -> &str {
+        match self {
+            EntryError::Key(key) | EntryError::Both(key, _) => key.code(),
+            EntryError::Value(value) => value.code(),
+        }
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        match self {
+            EntryError::Key(key) | EntryError::Both(key, _) => key.params(),
+            EntryError::Value(value) => value.params(),
+        }
+    }
+}
+
+impl<M, V> Validator<M> for KeysValidator<V>
+where
+    M: HasEntries,
+    M::Key: Clone + Debug,
+    V: Validator<M::Key>
+{
+    type Error = MapInvalid<M::Key, V::Error>;
+
+    fn validate(&self, map: &M) -> Result<(), Self::Error> {
+        MapInvalid::from_results(map._entries().map(|(key, _)| {
+            (key, self.0.validate(key).err())
+        }))
+    }
+
+    // stops at the first entry found once the budget is exhausted
+    fn validate_budgeted(&self, map: &M, budget: &mut ErrorBudget) -> Result<(), Self::Error> {
+        MapInvalid::from_results(map._entries()
+            .map_while(|(key, _)| {
+                (!budget.is_exhausted()).then(|| (key, self.0.validate_budgeted(key, budget).err()))
+            }))
+    }
+}
+
+impl<M, V, C> ValidatorWithContext<M, C> for KeysValidator<V>
+where
+    M: HasEntries,
+    M::Key: Clone + Debug,
+    V: ValidatorWithContext<M::Key, C>,
+    C: ?Sized
+{
+    type Error = MapInvalid<M::Key, V::Error>;
+
+    fn validate_with(&self, map: &M, context: &C) -> Result<(), Self::Error> {
+        MapInvalid::from_results(map._entries().map(|(key, _)| {
+            (key, self.0.validate_with(key, context).err())
+        }))
+    }
+}
+
+#[cfg(feature = "async")]
+impl<M, V> crate::AsyncValidator<M> for KeysValidator<V>
+where
+    M: HasEntries,
+    M::Key: Clone + Debug,
+    V: crate::AsyncValidator<M::Key>
+{
+    type Error = MapInvalid<M::Key, V::Error>;
+
+    async fn validate_async(&self, map: &M) -> Result<(), Self::Error> {
+        let results = futures::future::join_all(map._entries().map(|(key, _)| async move {
+            (key, self.0.validate_async(key).await.err())
+        })).await;
+        MapInvalid::from_results(results)
+    }
+}
+
+impl<M, V> Validator<M> for ValuesValidator<V>
+where
+    M: HasEntries,
+    M::Key: Clone + Debug,
+    V: Validator<M::Value>
+{
+    type Error = MapInvalid<M::Key, V::Error>;
+
+    fn validate(&self, map: &M) -> Result<(), Self::Error> {
+        MapInvalid::from_results(map._entries().map(|(key, value)| {
+            (key, self.0.validate(value).err())
+        }))
+    }
+
+    // stops at the first entry found once the budget is exhausted
+    fn validate_budgeted(&self, map: &M, budget: &mut ErrorBudget) -> Result<(), Self::Error> {
+        MapInvalid::from_results(map._entries()
+            .map_while(|(key, value)| {
+                (!budget.is_exhausted()).then(|| (key, self.0.validate_budgeted(value, budget).err()))
+            }))
+    }
+}
+
+impl<M, V, C> ValidatorWithContext<M, C> for ValuesValidator<V>
+where
+    M: HasEntries,
+    M::Key: Clone + Debug,
+    V: ValidatorWithContext<M::Value, C>,
+    C: ?Sized
+{
+    type Error = MapInvalid<M::Key, V::Error>;
+
+    fn validate_with(&self, map: &M, context: &C) -> Result<(), Self::Error> {
+        MapInvalid::from_results(map._entries().map(|(key, value)| {
+            (key, self.0.validate_with(value, context).err())
+        }))
+    }
+}
+
+#[cfg(feature = "async")]
+impl<M, V> crate::AsyncValidator<M> for ValuesValidator<V>
+where
+    M: HasEntries,
+    M::Key: Clone + Debug,
+    V: crate::AsyncValidator<M::Value>
+{
+    type Error = MapInvalid<M::Key, V::Error>;
+
+    async fn validate_async(&self, map: &M) -> Result<(), Self::Error> {
+        let results = futures::future::join_all(map._entries().map(|(key, value)| async move {
+            (key, self.0.validate_async(value).await.err())
+        })).await;
+        MapInvalid::from_results(results)
+    }
+}
+
+impl<M, KV, VV> Validator<M> for EntriesValidator<KV, VV>
+where
+    M: HasEntries,
+    M::Key: Clone + Debug,
+    KV: Validator<M::Key>,
+    VV: Validator<M::Value>
+{
+    type Error = MapInvalid<M::Key, EntryError<KV::Error, VV::Error>>;
+
+    fn validate(&self, map: &M) -> Result<(), Self::Error> {
+        MapInvalid::from_results(map._entries().map(|(key, value)| {
+            (key, EntryError::from_results(self.0.validate(key).err(), self.1.validate(value).err()))
+        }))
+    }
+
+    // stops at the first entry found once the budget is exhausted
+    fn validate_budgeted(&self, map: &M, budget: &mut ErrorBudget) -> Result<(), Self::Error> {
+        MapInvalid::from_results(map._entries()
+            .map_while(|(key, value)| {
+                (!budget.is_exhausted()).then(|| {
+                    let key_error = self.0.validate_budgeted(key, budget).err();
+                    (key, EntryError::from_results(key_error, self.1.validate_budgeted(value, budget).err()))
+                })
+            }))
+    }
+}
+
+impl<M, KV, VV, C> ValidatorWithContext<M, C> for EntriesValidator<KV, VV>
+where
+    M: HasEntries,
+    M::Key: Clone + Debug,
+    KV: ValidatorWithContext<M::Key, C>,
+    VV: ValidatorWithContext<M::Value, C>,
+    C: ?Sized
+{
+    type Error = MapInvalid<M::Key, EntryError<KV::Error, VV::Error>>;
+
+    fn validate_with(&self, map: &M, context: &C) -> Result<(), Self::Error> {
+        MapInvalid::from_results(map._entries().map(|(key, value)| {
+            (key, EntryError::from_results(self.0.validate_with(key, context).err(), self.1.validate_with(value, context).err()))
+        }))
+    }
+}
+
+#[cfg(feature = "async")]
+impl<M, KV, VV> crate::AsyncValidator<M> for EntriesValidator<KV, VV>
+where
+    M: HasEntries,
+    M::Key: Clone + Debug,
+    KV: crate::AsyncValidator<M::Key>,
+    VV: crate::AsyncValidator<M::Value>
+{
+    type Error = MapInvalid<M::Key, EntryError<KV::Error, VV::Error>>;
+
+    async fn validate_async(&self, map: &M) -> Result<(), Self::Error> {
+        let results = futures::future::join_all(map._entries().map(|(key, value)| async move {
+            let (key_result, value_result) = futures::join!(self.0.validate_async(key), self.1.validate_async(value));
+            (key, EntryError::from_results(key_result.err(), value_result.err()))
+        })).await;
+        MapInvalid::from_results(results)
+    }
+}
+
+impl<V> KeysValidator<V> {
+    pub fn new(validator: V) -> Self {
+        Self(validator)
+    }
+}
+
+impl<V> ValuesValidator<V> {
+    pub fn new(validator: V) -> Self {
+        Self(validator)
+    }
+}
+
+impl<K, V> EntriesValidator<K, V> {
+    pub fn new(keys: K, values: V) -> Self {
+        Self(keys, values)
+    }
+}
+
+impl<K, V, S> HasEntries for HashMap<K, V, S> {
+    type Key = K;
+    type Value = V;
+
+    fn _entries(&self) -> impl Iterator<Item=(&Self::Key, &Self::Value)> {
+        self.iter()
+    }
+}
+
+impl<K, V> HasEntries for BTreeMap<K, V> {
+    type Key = K;
+    type Value = V;
+
+    fn _entries(&self) -> impl Iterator<Item=(&Self::Key, &Self::Value)> {
+        self.iter()
+    }
+}
+
+#[cfg(feature = "indexmap")]
+impl<K, V, S> HasEntries for indexmap::IndexMap<K, V, S> {
+    type Key = K;
+    type Value = V;
+
+    fn _entries(&self) -> impl Iterator<Item=(&Self::Key, &Self::Value)> {
+        self.iter()
+    }
+}
+
+// maps validate their values by default
+impl<K: Clone + Debug, V: Validate, S: BuildHasher> Validate for HashMap<K, V, S> {
+    type Validator = ValuesValidator<V::Validator>;
+
+    fn validator() -> Self::Validator {
+        ValuesValidator(V::validator())
+    }
+}
+
+impl<K: Clone + Debug, V: Validate> Validate for BTreeMap<K, V> {
+    type Validator = ValuesValidator<V::Validator>;
+
+    fn validator() -> Self::Validator {
+        ValuesValidator(V::validator())
+    }
+}
+
+#[cfg(feature = "indexmap")]
+impl<K: Clone + Debug, V: Validate, S: BuildHasher> Validate for indexmap::IndexMap<K, V, S> {
+    type Validator = ValuesValidator<V::Validator>;
+
+    fn validator() -> Self::Validator {
+        ValuesValidator(V::validator())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::BTreeMap;
+
+    use crate::{EmptyValueError, EntriesValidator, EntryError, KeysValidator, NotEmptyValidator, RangeValidator, ValidationError, Validator, ValuesValidator};
+
+    fn map() -> BTreeMap<String, u32> {
+        [("a".to_string(), 1), ("".to_string(), 2), ("c".to_string(), 30)].into_iter().collect()
+    }
+
+    #[test]
+    fn keys() {
+        let error = KeysValidator::new(NotEmptyValidator).validate(&map()).unwrap_err();
+        assert_eq!(error.get(""), Some(&EmptyValueError));
+        assert_eq!(error.get("a"), None);
+    }
+
+    #[test]
+    fn values() {
+        let error = ValuesValidator::new(RangeValidator::new(0..10)).validate(&map()).unwrap_err();
+        assert_eq!(error.iter().map(|(key, _)| key.as_str()).collect::<Vec<_>>(), vec!["c"]);
+        let paths: Vec<_> = error.errors().into_iter().map(|(path, _)| path.to_string()).collect();
+        assert_eq!(paths, vec!["c"]);
+    }
+
+    #[test]
+    fn entries() {
+        let error = EntriesValidator::new(NotEmptyValidator, RangeValidator::new(0..10))
+            .validate(&map())
+            .unwrap_err();
+        assert!(matches!(error.get(""), Some(EntryError::Key(_))));
+        assert!(matches!(error.get("c"), Some(EntryError::Value(_))));
+        assert_eq!(error.iter().count(), 2);
+    }
+}
@@ -0,0 +1,184 @@
+use std::borrow::Cow;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use crate::{ErrorBudget, ErrorCode, ErrorList, ErrorPath, ValidationError, Validator, ValidatorWithContext};
+
+/// Replaces the code and the message of the errors of a validator,
+/// such as `#[validator(length(min = 8), code = "password_short", message = "Use at least {min} characters")]`
+pub struct MessageValidator<V> {
+    validator: V,
+    code: Option<&'static str>,
+    message: Option<&'static str>,
+}
+
+/// An error along with the code and the message which replace its own
+#[derive(Debug, PartialEq, Clone)]
+pub struct MessageError<E> {
+    error: E,
+    code: Option<&'static str>,
+    // already rendered, as the parameters of the error are known once it is found
+    message: Option<String>,
+}
+
+impl<V> MessageValidator<V> {
+    pub fn new(validator: V) -> Self {
+        Self { validator, code: None, message: None }
+    }
+
+    pub fn with_code(self, code: &'static str) -> Self {
+        Self { code: Some(code), ..self }
+    }
+
+    /// Sets the message of the errors, in which the parameters of the error such as `{min}` are interpolated
+    pub fn with_message(self, message: &'static str) -> Self {
+        Self { message: Some(message), ..self }
+    }
+
+    fn wrap<E: ErrorCode>(&self, error: E) -> MessageError<E> {
+        let message = self.message.map(|message| render_message(message, &error.params()));
+        MessageError { error, code: self.code, message }
+    }
+}
+
+impl<E> MessageError<E> {
+    /// The error found by the validator
+    pub fn error(&self) -> &E {
+        &self.error
+    }
+
+    pub fn into_inner(self) -> E {
+        self.error
+    }
+}
+
+impl<E: Display> MessageError<E> {
+    /// The rendered message, or the message of the error if none was set
+    pub fn message(&self) -> Cow<'_, str> {
+        match &self.message {
+            Some(message) => Cow::Borrowed(message),
+            None => Cow::Owned(self.error.to_string()),
+        }
+    }
+}
+
+/// Interpolates the parameters into the message, placeholders such as `{min}` without a parameter being kept as is
+pub fn render_message(message: &str, params: &[(&str, String)]) -> String {
+    let mut rendered = String::with_capacity(message.len());
+    let mut rest = message;
+    while let Some(start) = rest.find('{') {
+        rendered.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let Some(end) = rest.find('}') else { break; };
+        let name = &rest[1..end];
+        match params.iter().find(|(param, _)| *param == name) {
+            Some((_, value)) => rendered.push_str(value),
+            None => rendered.push_str(&rest[..=end]),
+        }
+        rest = &rest[end + 1..];
+    }
+    rendered.push_str(rest);
+    rendered
+}
+
+impl<E: ErrorCode> ErrorCode for MessageError<E> {
+    fn code(&self) -> &str {
+        self.code.unwrap_or_else(|| self.error.code())
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        self.error.params()
+    }
+}
+
+impl<E: Display> Display for MessageError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.message {
+            Some(message) => write!(f, "{message}"),
+            None => write!(f, "{}", self.error),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for MessageError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+// the message replaces the ones of any error nested in the error, so it is reported as a single error
+impl<E: Error + ErrorCode + 'static> ValidationError for MessageError<E> {
+    fn collect_errors<'a>(&'a self, path: &mut ErrorPath, errors: &mut ErrorList<'a>) {
+        errors.push((path.clone(), self));
+    }
+}
+
+impl<T, V> Validator<T> for MessageValidator<V>
+where
+    V: Validator<T>,
+    V::Error: ErrorCode
+{
+    type Error = MessageError<V::Error>;
+
+    fn validate(&self, value: &T) -> Result<(), Self::Error> {
+        self.validator.validate(value).map_err(|error| self.wrap(error))
+    }
+
+    fn validate_budgeted(&self, value: &T, budget: &mut ErrorBudget) -> Result<(), Self::Error> {
+        self.validator.validate_budgeted(value, budget).map_err(|error| self.wrap(error))
+    }
+}
+
+impl<T, V, C: ?Sized> ValidatorWithContext<T, C> for MessageValidator<V>
+where
+    V: ValidatorWithContext<T, C>,
+    V::Error: ErrorCode
+{
+    type Error = MessageError<V::Error>;
+
+    fn validate_with(&self, value: &T, context: &C) -> Result<(), Self::Error> {
+        self.validator.validate_with(value, context).map_err(|error| self.wrap(error))
+    }
+}
+
+#[cfg(feature = "async")]
+impl<T, V> crate::AsyncValidator<T> for MessageValidator<V>
+where
+    V: crate::AsyncValidator<T>,
+    V::Error: ErrorCode
+{
+    type Error = MessageError<V::Error>;
+
+    async fn validate_async(&self, value: &T) -> Result<(), Self::Error> {
+        self.validator.validate_async(value).await.map_err(|error| self.wrap(error))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{render_message, ErrorCode, LengthValidator, MessageValidator, RangeValidator, Validator};
+
+    #[test]
+    fn renders_params() {
+        let params = [("min", "8".to_string())];
+        assert_eq!(render_message("Use at least {min} characters", &params), "Use at least 8 characters");
+        assert_eq!(render_message("{unknown} {min}", &params), "{unknown} 8");
+        assert_eq!(render_message("unclosed {min", &params), "unclosed {min");
+    }
+
+    #[test]
+    fn replaces_code_and_message() {
+        let validator = MessageValidator::new(LengthValidator::new(Some(8), None))
+            .with_code("password_short")
+            .with_message("Use at least {min} characters, not {len}");
+        let error = validator.validate(&"secret".to_string()).unwrap_err();
+        assert_eq!(error.code(), "password_short");
+        assert_eq!(error.message(), "Use at least 8 characters, not 6");
+        assert_eq!(error.to_string(), "Use at least 8 characters, not 6");
+
+        let error = MessageValidator::new(RangeValidator::new(18..120)).validate(&12).unwrap_err();
+        assert_eq!(error.code(), "range");
+        assert_eq!(error.params(), vec![("start", "18".to_string()), ("end", "120".to_string())]);
+        assert_eq!(error.message(), "Value is not in range 18..120");
+    }
+}
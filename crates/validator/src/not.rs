@@ -0,0 +1,90 @@
+use thiserror::Error;
+
+use crate::{ErrorCode, Validator, ValidatorWithContext};
+
+/// Validates that values fail a validator, such as `#[validator(not(contains = ".."))]`
+#[derive(Debug)]
+pub struct Not<V> {
+    validator: V,
+    rule: String,
+}
+
+/// The value passed the negated rule, which is named in the error as the error of the rule is not known
+#[derive(Debug, PartialEq, Clone, Error)]
+#[error("Value should not satisfy {rule}")]
+pub struct NegatedRuleError {
+    pub rule: String,
+}
+
+impl<V> Not<V> {
+    /// Negates a validator, the rule being named after the type of the validator, such as `ContainsValidator`
+    pub fn new(validator: V) -> Self {
+        let name = std::any::type_name::<V>();
+        let name = name.split('<').next().unwrap_or(name);
+        let rule = name.rsplit("::").next().unwrap_or(name).to_string();
+        Self { validator, rule }
+    }
+
+    /// Names the negated rule in the errors, such as `contains = ".."`
+    pub fn with_rule(self, rule: impl Into<String>) -> Self {
+        Self { rule: rule.into(), ..self }
+    }
+
+    fn negate<E>(&self, result: Result<(), E>) -> Result<(), NegatedRuleError> {
+        match result {
+            Ok(()) => Err(NegatedRuleError { rule: self.rule.clone() }),
+            Err(_) => Ok(()),
+        }
+    }
+}
+
+impl<V: Validator<T>, T> Validator<T> for Not<V> {
+    type Error = NegatedRuleError;
+
+    fn validate(&self, value: &T) -> Result<(), Self::Error> {
+        self.negate(self.validator.validate(value))
+    }
+}
+
+impl<V: ValidatorWithContext<T, C>, T, C: ?Sized> ValidatorWithContext<T, C> for Not<V> {
+    type Error = NegatedRuleError;
+
+    fn validate_with(&self, value: &T, context: &C) -> Result<(), Self::Error> {
+        self.negate(self.validator.validate_with(value, context))
+    }
+}
+
+#[cfg(feature = "async")]
+impl<V: crate::AsyncValidator<T>, T> crate::AsyncValidator<T> for Not<V> {
+    type Error = NegatedRuleError;
+
+    async fn validate_async(&self, value: &T) -> Result<(), Self::Error> {
+        self.negate(self.validator.validate_async(value).await)
+    }
+}
+
+leaf_error!(NegatedRuleError);
+
+impl ErrorCode for NegatedRuleError {
+    fn code(&self) -> &str {
+        "not"
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        vec![("rule", self.rule.clone())]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{ContainsValidator, NegatedRuleError, Not, Validator};
+
+    #[test]
+    fn negates() {
+        let validator = Not::new(ContainsValidator::new(".."));
+        validator.validate(&"docs/index.md".to_string()).expect("should be valid");
+        assert_eq!(validator.validate(&"../secrets".to_string()), Err(NegatedRuleError { rule: "ContainsValidator".to_string() }));
+        let error = validator.with_rule(r#"contains = "..""#).validate(&"../secrets".to_string()).unwrap_err();
+        assert_eq!(error.to_string(), r#"Value should not satisfy contains = "..""#);
+    }
+}
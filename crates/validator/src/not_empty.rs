@@ -1,6 +1,5 @@
 use thiserror::Error;
-use crate::length::HasLength;
-use crate::Validator;
+use crate::{ErrorCode, HasLength, Validator};
 
 pub struct NotEmptyValidator;
 
@@ -20,6 +19,15 @@ impl<T: ?Sized> Validator<T> for NotEmptyValidator where T: HasLength {
     }
 }
 
+leaf_validator!(NotEmptyValidator);
+leaf_error!(EmptyValueError);
+
+impl ErrorCode for EmptyValueError {
+    fn code(&self) -> &str {
+        "not_empty"
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::not_empty::NotEmptyValidator;
@@ -41,10 +49,18 @@ mod test {
         assert_is_empty(&String::default());
         assert_is_empty(&String::with_capacity(5));
         assert_is_empty(&Vec::<u8>::with_capacity(5));
+        assert_is_empty(&std::collections::HashSet::<u8>::new());
+        assert_is_empty(&std::collections::VecDeque::<u8>::new());
+        assert_is_empty(&Box::<str>::from(""));
 
         assert_not_empty("foo");
         assert_not_empty(&[1]);
         assert_not_empty(&String::from("foo"));
-        assert_not_empty(&vec![1, 2, 3])
+        assert_not_empty(&vec![1, 2, 3]);
+        assert_not_empty(&std::collections::BTreeSet::from([1]));
+        assert_not_empty(&std::collections::LinkedList::from([1]));
+        assert_not_empty(&Box::<[u8]>::from([1]));
+        assert_not_empty(&std::borrow::Cow::Borrowed("foo"));
+        assert_not_empty(&std::sync::Arc::<str>::from("foo"));
     }
 }
\ No newline at end of file
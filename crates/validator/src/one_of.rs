@@ -0,0 +1,269 @@
+use std::borrow::Borrow;
+use std::collections::{BTreeSet, HashSet};
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::{BuildHasher, Hash};
+
+use crate::{ErrorCode, Validator};
+
+/// Validates that values are one of the given values, such as `#[validator(one_of("draft", "published"))]`
+#[derive(Debug, Clone)]
+pub struct OneOfValidator<S> {
+    values: S,
+}
+
+/// Validates that values are none of the given values, such as `#[validator(none_of("admin", "root"))]`
+#[derive(Debug, Clone)]
+pub struct NoneOfValidator<S> {
+    values: S,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct NotOneOfError<S> {
+    pub allowed: S,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct ForbiddenValueError<S> {
+    pub forbidden: S,
+}
+
+/// A set of values which can be looked up by the values of type `T`, such as arrays and vectors of values
+/// comparable to `T` or hash sets of values which `T` can be borrowed from
+pub trait ValueSet<T: ?Sized> {
+    fn contains_value(&self, value: &T) -> bool;
+}
+
+/// A list of strings compared to the values while ignoring their case, comparing their lowercase forms
+#[derive(Debug, PartialEq, Clone)]
+pub struct IgnoreCase<S>(pub S);
+
+impl<S> OneOfValidator<S> {
+    pub fn new(values: S) -> Self {
+        Self { values }
+    }
+
+    /// Ignores the case of the strings, which must then be given as a list rather than a set
+    pub fn ignore_case(self) -> OneOfValidator<IgnoreCase<S>> {
+        OneOfValidator { values: IgnoreCase(self.values) }
+    }
+}
+
+impl<S> NoneOfValidator<S> {
+    pub fn new(values: S) -> Self {
+        Self { values }
+    }
+
+    /// Ignores the case of the strings, which must then be given as a list rather than a set
+    pub fn ignore_case(self) -> NoneOfValidator<IgnoreCase<S>> {
+        NoneOfValidator { values: IgnoreCase(self.values) }
+    }
+}
+
+impl<T, S: ValueSet<T> + Clone + Debug> Validator<T> for OneOfValidator<S> {
+    type Error = NotOneOfError<S>;
+
+    fn validate(&self, value: &T) -> Result<(), Self::Error> {
+        if self.values.contains_value(value) {
+            Ok(())
+        } else {
+            Err(NotOneOfError { allowed: self.values.clone() })
+        }
+    }
+}
+
+impl<T, S: ValueSet<T> + Clone + Debug> Validator<T> for NoneOfValidator<S> {
+    type Error = ForbiddenValueError<S>;
+
+    fn validate(&self, value: &T) -> Result<(), Self::Error> {
+        if self.values.contains_value(value) {
+            Err(ForbiddenValueError { forbidden: self.values.clone() })
+        } else {
+            Ok(())
+        }
+    }
+}
+
+leaf_validator!(<S> OneOfValidator<S>);
+leaf_validator!(<S> NoneOfValidator<S>);
+leaf_error!(<S> NotOneOfError<S>);
+leaf_error!(<S> ForbiddenValueError<S>);
+
+impl<T: ?Sized, E: PartialEq<T>> ValueSet<T> for [E] {
+    fn contains_value(&self, value: &T) -> bool {
+        self.iter().any(|element| element == value)
+    }
+}
+
+impl<T: ?Sized, E: PartialEq<T>, const N: usize> ValueSet<T> for [E; N] {
+    fn contains_value(&self, value: &T) -> bool {
+        self.as_slice().contains_value(value)
+    }
+}
+
+impl<T: ?Sized, E: PartialEq<T>> ValueSet<T> for Vec<E> {
+    fn contains_value(&self, value: &T) -> bool {
+        self.as_slice().contains_value(value)
+    }
+}
+
+impl<T: ?Sized, S: ValueSet<T> + ?Sized> ValueSet<T> for &S {
+    fn contains_value(&self, value: &T) -> bool {
+        (**self).contains_value(value)
+    }
+}
+
+impl<T, E, H> ValueSet<T> for HashSet<E, H>
+where
+    T: Hash + Eq + ?Sized,
+    E: Borrow<T> + Hash + Eq,
+    H: BuildHasher
+{
+    fn contains_value(&self, value: &T) -> bool {
+        self.contains(value)
+    }
+}
+
+impl<T, E> ValueSet<T> for BTreeSet<E>
+where
+    T: Ord + ?Sized,
+    E: Borrow<T> + Ord
+{
+    fn contains_value(&self, value: &T) -> bool {
+        self.contains(value)
+    }
+}
+
+#[cfg(feature = "indexmap")]
+impl<T, E, H> ValueSet<T> for indexmap::IndexSet<E, H>
+where
+    T: Hash + indexmap::Equivalent<E> + ?Sized,
+    H: BuildHasher
+{
+    fn contains_value(&self, value: &T) -> bool {
+        self.contains(value)
+    }
+}
+
+// the case is ignored for lists only, sets being looked up by the values as they are
+impl<T: AsRef<str> + ?Sized, E: AsRef<str>> ValueSet<T> for IgnoreCase<&[E]> {
+    fn contains_value(&self, value: &T) -> bool {
+        let value = value.as_ref().to_lowercase();
+        self.0.iter().any(|element| element.as_ref().to_lowercase() == value)
+    }
+}
+
+impl<T: AsRef<str> + ?Sized, E: AsRef<str>, const N: usize> ValueSet<T> for IgnoreCase<[E; N]> {
+    fn contains_value(&self, value: &T) -> bool {
+        IgnoreCase(self.0.as_slice()).contains_value(value)
+    }
+}
+
+impl<T: AsRef<str> + ?Sized, E: AsRef<str>> ValueSet<T> for IgnoreCase<Vec<E>> {
+    fn contains_value(&self, value: &T) -> bool {
+        IgnoreCase(self.0.as_slice()).contains_value(value)
+    }
+}
+
+impl<'a, S> IntoIterator for &'a IgnoreCase<S>
+where
+    &'a S: IntoIterator
+{
+    type Item = <&'a S as IntoIterator>::Item;
+    type IntoIter = <&'a S as IntoIterator>::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+/// The values of a set separated by commas, such as `"draft", "published"`, formatted with `Debug` so that any value
+/// can be listed, strings being quoted as in the errors of JSON Schema
+fn format_values(values: impl IntoIterator<Item: Debug>) -> String {
+    values.into_iter().map(|value| format!("{value:?}")).collect::<Vec<_>>().join(", ")
+}
+
+impl<S> Display for NotOneOfError<S>
+where
+    for<'a> &'a S: IntoIterator<Item: Debug>
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Value should be one of {}", format_values(&self.allowed))
+    }
+}
+
+impl<S> Display for ForbiddenValueError<S>
+where
+    for<'a> &'a S: IntoIterator<Item: Debug>
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Value should not be one of {}", format_values(&self.forbidden))
+    }
+}
+
+impl<S: Debug> Error for NotOneOfError<S> where for<'a> &'a S: IntoIterator<Item: Debug> {}
+
+impl<S: Debug> Error for ForbiddenValueError<S> where for<'a> &'a S: IntoIterator<Item: Debug> {}
+
+// the same code as the `enum` keyword of JSON Schema, so that catalogs have a single message for both
+impl<S> ErrorCode for NotOneOfError<S>
+where
+    for<'a> &'a S: IntoIterator<Item: Debug>
+{
+    fn code(&self) -> &str {
+        "enum"
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        vec![("allowed", format_values(&self.allowed))]
+    }
+}
+
+impl<S> ErrorCode for ForbiddenValueError<S>
+where
+    for<'a> &'a S: IntoIterator<Item: Debug>
+{
+    fn code(&self) -> &str {
+        "none_of"
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        vec![("forbidden", format_values(&self.forbidden))]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use crate::{ErrorCode, ForbiddenValueError, NoneOfValidator, NotOneOfError, OneOfValidator, Validator};
+
+    #[test]
+    fn one_of() {
+        let validator = OneOfValidator::new(["draft", "published"]);
+        validator.validate(&"draft".to_string()).expect("should be valid");
+        let error = validator.validate(&"archived".to_string()).unwrap_err();
+        assert_eq!(error, NotOneOfError { allowed: ["draft", "published"] });
+        assert_eq!(error.to_string(), r#"Value should be one of "draft", "published""#);
+        assert_eq!(error.params(), [("allowed", r#""draft", "published""#.to_string())]);
+
+        OneOfValidator::new(vec![1, 2, 3]).validate(&2).expect("should be valid");
+        OneOfValidator::new(vec![1, 2, 3]).validate(&4).expect_err("should be invalid");
+    }
+
+    #[test]
+    fn none_of() {
+        let validator = NoneOfValidator::new(HashSet::from(["admin".to_string(), "root".to_string()]));
+        validator.validate(&"someone".to_string()).expect("should be valid");
+        let error = validator.validate(&"root".to_string()).unwrap_err();
+        assert!(matches!(error, ForbiddenValueError { forbidden } if forbidden.contains("root")));
+    }
+
+    #[test]
+    fn ignore_case() {
+        let validator = OneOfValidator::new(["draft", "published"]).ignore_case();
+        validator.validate(&"Draft".to_string()).expect("should be valid");
+        validator.validate(&"archived").expect_err("should be invalid");
+        NoneOfValidator::new(vec!["Admin"]).ignore_case().validate(&"ADMIN").expect_err("should be invalid");
+    }
+}
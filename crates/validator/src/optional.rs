@@ -0,0 +1,109 @@
+use thiserror::Error;
+
+use crate::{ErrorBudget, ErrorCode, Presence, Validate, Validator, ValidatorWithContext};
+
+/// Validates the value of an `Option` with the inner validator, `None` always being valid
+#[derive(Debug, Default, PartialEq)]
+pub struct OptionValidator<V>(V);
+
+/// Fails for values which are not set, such as `None`
+pub struct RequiredValidator;
+
+#[derive(Debug, PartialEq, Clone, Error)]
+#[error("Value is required")]
+pub struct MissingValueError;
+
+impl<V> OptionValidator<V> {
+    pub fn new(validator: V) -> Self {
+        Self(validator)
+    }
+}
+
+impl<T, V: Validator<T>> Validator<Option<T>> for OptionValidator<V> {
+    type Error = V::Error;
+
+    fn validate(&self, value: &Option<T>) -> Result<(), Self::Error> {
+        match value {
+            Some(value) => self.0.validate(value),
+            None => Ok(()),
+        }
+    }
+
+    fn validate_budgeted(&self, value: &Option<T>, budget: &mut ErrorBudget) -> Result<(), Self::Error> {
+        match value {
+            Some(value) => self.0.validate_budgeted(value, budget),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T, V: ValidatorWithContext<T, C>, C: ?Sized> ValidatorWithContext<Option<T>, C> for OptionValidator<V> {
+    type Error = V::Error;
+
+    fn validate_with(&self, value: &Option<T>, context: &C) -> Result<(), Self::Error> {
+        match value {
+            Some(value) => self.0.validate_with(value, context),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl<T, V: crate::AsyncValidator<T>> crate::AsyncValidator<Option<T>> for OptionValidator<V> {
+    type Error = V::Error;
+
+    async fn validate_async(&self, value: &Option<T>) -> Result<(), Self::Error> {
+        match value {
+            Some(value) => self.0.validate_async(value).await,
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T: Presence + ?Sized> Validator<T> for RequiredValidator {
+    type Error = MissingValueError;
+
+    fn validate(&self, value: &T) -> Result<(), Self::Error> {
+        if value.is_present() {
+            Ok(())
+        } else {
+            Err(MissingValueError)
+        }
+    }
+}
+
+leaf_validator!(RequiredValidator);
+leaf_error!(MissingValueError);
+
+impl ErrorCode for MissingValueError {
+    fn code(&self) -> &str {
+        "required"
+    }
+}
+
+impl<T: Validate> Validate for Option<T> {
+    type Validator = OptionValidator<T::Validator>;
+
+    fn validator() -> Self::Validator {
+        OptionValidator(T::validator())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{EmailValidator, MissingValueError, OptionValidator, RequiredValidator, Validator};
+
+    #[test]
+    fn none_is_valid() {
+        let validator = OptionValidator::new(EmailValidator);
+        validator.validate(&None::<String>).expect("should be valid");
+        validator.validate(&Some("someone@example.com".to_string())).expect("should be valid");
+        validator.validate(&Some("someone".to_string())).expect_err("should be invalid");
+    }
+
+    #[test]
+    fn required() {
+        assert_eq!(RequiredValidator.validate(&None::<u32>), Err(MissingValueError));
+        assert_eq!(RequiredValidator.validate(&Some(3)), Ok(()));
+    }
+}
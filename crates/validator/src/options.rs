@@ -0,0 +1,55 @@
+/// Configures how much of a value is validated once errors have been found
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub struct ValidationOptions {
+    max_errors: Option<usize>,
+}
+
+impl ValidationOptions {
+    /// Validates everything, reporting every error found
+    pub fn all() -> Self {
+        Self { max_errors: None }
+    }
+
+    /// Stops validating at the first error found
+    pub fn fail_fast() -> Self {
+        Self::max_errors(1)
+    }
+
+    /// Stops validating once the given number of errors have been found, a maximum of 0 is treated as 1
+    pub fn max_errors(max_errors: usize) -> Self {
+        Self { max_errors: Some(max_errors.max(1)) }
+    }
+
+    /// Creates a new budget, to be shared by every validator of a value
+    pub fn budget(&self) -> ErrorBudget {
+        ErrorBudget { remaining: self.max_errors }
+    }
+}
+
+/// The number of errors which may still be found before validation stops
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ErrorBudget {
+    remaining: Option<usize>,
+}
+
+impl ErrorBudget {
+    pub fn unlimited() -> Self {
+        ValidationOptions::all().budget()
+    }
+
+    pub fn is_exhausted(&self) -> bool {
+        self.remaining == Some(0)
+    }
+
+    /// Records that an error was found
+    pub fn spend(&mut self) {
+        if let Some(remaining) = self.remaining.as_mut() {
+            *remaining = remaining.saturating_sub(1);
+        }
+    }
+
+    /// The number of errors left in the budget, `None` if it is unlimited
+    pub fn remaining(&self) -> Option<usize> {
+        self.remaining
+    }
+}
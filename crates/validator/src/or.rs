@@ -1,5 +1,5 @@
 use thiserror::Error;
-use crate::Validator;
+use crate::{ErrorBudget, ErrorCode, ErrorList, ErrorPath, ValidationError, Validator, ValidatorWithContext};
 
 #[derive(Debug, Default)]
 pub struct Or<A, B>(A, B);
@@ -8,6 +8,25 @@ pub struct Or<A, B>(A, B);
 #[error("{0} and {1}")]
 pub struct OrError<A, B>(A, B);
 
+// the value is at fault for both alternatives, so the errors of both are reported
+impl<A: ValidationError, B: ValidationError> ValidationError for OrError<A, B> {
+    fn collect_errors<'a>(&'a self, path: &mut ErrorPath, errors: &mut ErrorList<'a>) {
+        self.0.collect_errors(path, errors);
+        self.1.collect_errors(path, errors);
+    }
+}
+
+// the code and parameters are the ones of the first alternative
+impl<A: ErrorCode, B: ErrorCode> ErrorCode for OrError<A, B> {
+    fn code(&self) -> &str {
+        self.0.code()
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        self.0.params()
+    }
+}
+
 impl<A, B, T> Validator<T> for Or<A, B> where A: Validator<T>, B: Validator<T> {
     type Error = OrError<A::Error, B::Error>;
 
@@ -16,6 +35,49 @@ impl<A, B, T> Validator<T> for Or<A, B> where A: Validator<T>, B: Validator<T> {
         let Err(right) = self.1.validate(value) else { return Ok(()) };
         Err(OrError(left, right))
     }
+
+    // both alternatives are validated against the remaining budget, which is only spent if both of them fail as
+    // their errors are then reported, an alternative which fails before the other one passes not counting
+    fn validate_budgeted(&self, value: &T, budget: &mut ErrorBudget) -> Result<(), Self::Error> {
+        let mut left_budget = budget.clone();
+        let Err(left) = self.0.validate_budgeted(value, &mut left_budget) else { return Ok(()) };
+        let mut right_budget = budget.clone();
+        let Err(right) = self.1.validate_budgeted(value, &mut right_budget) else { return Ok(()) };
+        for _ in 0..spent(budget, &left_budget) + spent(budget, &right_budget) {
+            budget.spend();
+        }
+        Err(OrError(left, right))
+    }
+}
+
+// the number of errors spent from a copy of the budget, also used by the variadic alternatives
+pub(crate) fn spent(budget: &ErrorBudget, copy: &ErrorBudget) -> usize {
+    match (budget.remaining(), copy.remaining()) {
+        (Some(before), Some(after)) => before - after,
+        _ => 0,
+    }
+}
+
+impl<A, B, T, C: ?Sized> ValidatorWithContext<T, C> for Or<A, B> where A: ValidatorWithContext<T, C>, B: ValidatorWithContext<T, C> {
+    type Error = OrError<A::Error, B::Error>;
+
+    fn validate_with(&self, value: &T, context: &C) -> Result<(), Self::Error> {
+        let Err(left) = self.0.validate_with(value, context) else { return Ok(()) };
+        let Err(right) = self.1.validate_with(value, context) else { return Ok(()) };
+        Err(OrError(left, right))
+    }
+}
+
+// as with the synchronous implementation, the right validator is only run if the left one fails
+#[cfg(feature = "async")]
+impl<A, B, T> crate::AsyncValidator<T> for Or<A, B> where A: crate::AsyncValidator<T>, B: crate::AsyncValidator<T> {
+    type Error = OrError<A::Error, B::Error>;
+
+    async fn validate_async(&self, value: &T) -> Result<(), Self::Error> {
+        let Err(left) = self.0.validate_async(value).await else { return Ok(()) };
+        let Err(right) = self.1.validate_async(value).await else { return Ok(()) };
+        Err(OrError(left, right))
+    }
 }
 
 impl<A, B> Or<A, B> {
@@ -23,3 +85,9 @@ impl<A, B> Or<A, B> {
         Self(left, right)
     }
 }
+
+impl<A, B> OrError<A, B> {
+    pub fn new(left: A, right: B) -> Self {
+        Self(left, right)
+    }
+}
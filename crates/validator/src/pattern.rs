@@ -0,0 +1,67 @@
+use regex::Regex;
+use thiserror::Error;
+
+use crate::{ErrorCode, Validator};
+
+/// Validates that strings match a regular expression, anywhere in the string unless the pattern is anchored
+/// with `^` and `$`, such as `#[validator(regex = "^[a-z0-9_]+$")]`
+#[derive(Debug, Clone)]
+pub struct RegexValidator(Regex);
+
+#[derive(Debug, PartialEq, Clone, Error)]
+#[error("Value does not match the pattern {pattern}")]
+pub struct PatternMismatchError {
+    pub pattern: String,
+}
+
+impl RegexValidator {
+    /// Validates against a compiled regex, which is cheap to clone so that it can be kept in a static
+    pub fn new(regex: Regex) -> Self {
+        Self(regex)
+    }
+
+    pub fn pattern(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl Validator<str> for RegexValidator {
+    type Error = PatternMismatchError;
+
+    fn validate(&self, value: &str) -> Result<(), Self::Error> {
+        if self.0.is_match(value) {
+            Ok(())
+        } else {
+            Err(PatternMismatchError { pattern: self.pattern().to_string() })
+        }
+    }
+}
+
+leaf_validator!(RegexValidator);
+leaf_error!(PatternMismatchError);
+
+impl ErrorCode for PatternMismatchError {
+    fn code(&self) -> &str {
+        "pattern"
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        vec![("pattern", self.pattern.clone())]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use regex::Regex;
+
+    use crate::{PatternMismatchError, RegexValidator, Validator};
+
+    #[test]
+    fn matches() {
+        let validator = RegexValidator::new(Regex::new("^[a-z0-9_]+$").unwrap());
+        validator.validate("snake_case_42").expect("should be valid");
+        assert_eq!(validator.validate("Not Snake"), Err(PatternMismatchError { pattern: "^[a-z0-9_]+$".to_string() }));
+        // unanchored patterns match anywhere
+        RegexValidator::new(Regex::new("[0-9]").unwrap()).validate("abc1").expect("should be valid");
+    }
+}
@@ -0,0 +1,175 @@
+//! [RFC 7807](https://datatracker.ietf.org/doc/html/rfc7807) problem details, such as returned by HTTP APIs
+//! as `application/problem+json` when a request fails to validate.
+//!
+//! The errors found in the request are listed as `invalid-params`, named after their paths:
+//!
+//! ```json
+//! {
+//!   "type": "about:blank",
+//!   "title": "Your request parameters didn't validate.",
+//!   "status": 400,
+//!   "invalid-params": [
+//!     { "name": "emails[1]", "reason": "No '@' character was found in the given address" }
+//!   ]
+//! }
+//! ```
+//!
+//! The document is serialised as such when the `serde` feature is enabled.
+
+use crate::{MessageCatalog, ValidationError};
+
+/// The media type of problem details documents serialised as JSON
+pub const PROBLEM_JSON: &str = "application/problem+json";
+
+/// A problem details document describing the errors found in a value
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ProblemDetails {
+    type_uri: String,
+    title: String,
+    status: u16,
+    detail: Option<String>,
+    instance: Option<String>,
+    invalid_params: Vec<InvalidParam>,
+}
+
+/// An error found in a value, named after its path such as `dogs[3].name`, the errors of the value itself
+/// being named by the empty string
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct InvalidParam {
+    pub name: String,
+    pub reason: String,
+}
+
+impl ProblemDetails {
+    /// Describes the errors found in a value, their messages being the reasons
+    pub fn new<E: ValidationError + ?Sized>(error: &E) -> Self {
+        Self::with_params(error.errors().into_iter()
+            .map(|(path, error)| InvalidParam { name: path.to_string(), reason: error.to_string() })
+            .collect())
+    }
+
+    /// Describes the errors found in a value, their messages being rendered by the catalog in the given locale
+    pub fn localized<E, C>(error: &E, catalog: &C, locale: &str) -> Self
+    where
+        E: ValidationError + ?Sized,
+        C: MessageCatalog + ?Sized
+    {
+        Self::with_params(error.errors().into_iter()
+            .map(|(path, error)| InvalidParam { name: path.to_string(), reason: catalog.render(locale, error) })
+            .collect())
+    }
+
+    fn with_params(invalid_params: Vec<InvalidParam>) -> Self {
+        Self {
+            type_uri: "about:blank".to_string(),
+            title: "Your request parameters didn't validate.".to_string(),
+            status: 400,
+            detail: None,
+            instance: None,
+            invalid_params,
+        }
+    }
+
+    /// Sets the URI identifying the type of the problem, `about:blank` by default
+    pub fn with_type(self, type_uri: impl Into<String>) -> Self {
+        Self { type_uri: type_uri.into(), ..self }
+    }
+
+    pub fn with_title(self, title: impl Into<String>) -> Self {
+        Self { title: title.into(), ..self }
+    }
+
+    /// Sets the HTTP status code, 400 by default
+    pub fn with_status(self, status: u16) -> Self {
+        Self { status, ..self }
+    }
+
+    pub fn with_detail(self, detail: impl Into<String>) -> Self {
+        Self { detail: Some(detail.into()), ..self }
+    }
+
+    /// Sets the URI identifying this occurrence of the problem
+    pub fn with_instance(self, instance: impl Into<String>) -> Self {
+        Self { instance: Some(instance.into()), ..self }
+    }
+
+    pub fn type_uri(&self) -> &str {
+        &self.type_uri
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    pub fn status(&self) -> u16 {
+        self.status
+    }
+
+    pub fn detail(&self) -> Option<&str> {
+        self.detail.as_deref()
+    }
+
+    pub fn instance(&self) -> Option<&str> {
+        self.instance.as_deref()
+    }
+
+    pub fn invalid_params(&self) -> &[InvalidParam] {
+        &self.invalid_params
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ProblemDetails {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap;
+
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", &self.type_uri)?;
+        map.serialize_entry("title", &self.title)?;
+        map.serialize_entry("status", &self.status)?;
+        if let Some(detail) = &self.detail {
+            map.serialize_entry("detail", detail)?;
+        }
+        if let Some(instance) = &self.instance {
+            map.serialize_entry("instance", instance)?;
+        }
+        map.serialize_entry("invalid-params", &self.invalid_params)?;
+        map.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for InvalidParam {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let mut object = serializer.serialize_struct("InvalidParam", 2)?;
+        object.serialize_field("name", &self.name)?;
+        object.serialize_field("reason", &self.reason)?;
+        object.end()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{ElementsValidator, EmailValidator, InvalidParam, KeyValueCatalog, ProblemDetails, Validator};
+
+    #[test]
+    fn lists_invalid_params() {
+        let error = ElementsValidator::new(EmailValidator)
+            .validate(&vec!["someone@example.com".to_string(), "someone".to_string()])
+            .unwrap_err();
+        let problem = ProblemDetails::new(&error).with_status(422).with_detail("The sign up form is invalid");
+        assert_eq!(problem.type_uri(), "about:blank");
+        assert_eq!(problem.status(), 422);
+        assert_eq!(problem.detail(), Some("The sign up form is invalid"));
+        assert_eq!(problem.invalid_params(), [InvalidParam {
+            name: "[1]".to_string(),
+            reason: "No '@' character was found in the given address".to_string(),
+        }]);
+
+        let catalog = KeyValueCatalog::new().with_messages("fr", "email = Adresse invalide").unwrap();
+        let problem = ProblemDetails::localized(&error, &catalog, "fr");
+        assert_eq!(problem.invalid_params()[0].reason, "Adresse invalide");
+    }
+}
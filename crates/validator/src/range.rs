@@ -1,6 +1,6 @@
-use crate::Validator;
-use std::fmt::Debug;
-use std::ops::RangeBounds;
+use crate::{ErrorCode, Validator};
+use std::fmt::{Debug, Display};
+use std::ops::{Bound, RangeBounds};
 use thiserror::Error;
 
 pub struct RangeValidator<R> {
@@ -25,23 +25,71 @@ where T: PartialOrd<T>,
 
     fn validate(&self, value: &T) -> Result<(), Self::Error> {
         if self.range.contains(value) {
-            Err(NotInRangeError(self.range.clone()))
-        } else {
             Ok(())
+        } else {
+            Err(NotInRangeError(self.range.clone()))
+        }
+    }
+}
+
+leaf_validator!(<R> RangeValidator<R>);
+leaf_error!(<R> NotInRangeError<R>);
+
+// the range bounds are given as `start` and `end`, the ones which are not set being left out
+macro_rules! range_params {
+    ($($range:ident => |$bounds:ident| [$($name:literal: $bound:expr),*]),* $(,)?) => {
+        $(
+        impl<T: Display> ErrorCode for NotInRangeError<std::ops::$range<T>> {
+            fn code(&self) -> &str {
+                "range"
+            }
+
+            fn params(&self) -> Vec<(&'static str, String)> {
+                let $bounds = &self.0;
+                vec![$(($name, $bound.to_string())),*]
+            }
         }
+        )*
+    };
+}
+
+range_params!(
+    Range => |range| ["start": range.start, "end": range.end],
+    RangeInclusive => |range| ["start": range.start(), "end": range.end()],
+    RangeFrom => |range| ["start": range.start],
+    RangeTo => |range| ["end": range.end],
+    RangeToInclusive => |range| ["end": range.end],
+);
+
+// the bounds are given as `start` and `end` whether or not they are included
+impl<T: Display> ErrorCode for NotInRangeError<(Bound<T>, Bound<T>)> {
+    fn code(&self) -> &str {
+        "range"
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        let bound = |bound: &Bound<T>| match bound {
+            Bound::Included(bound) | Bound::Excluded(bound) => Some(bound.to_string()),
+            Bound::Unbounded => None,
+        };
+        let (start, end) = &self.0;
+        [("start", bound(start)), ("end", bound(end))].into_iter()
+            .filter_map(|(name, bound)| Some((name, bound?)))
+            .collect()
     }
 }
 
 #[cfg(test)]
 mod test {
+    use super::NotInRangeError;
     use crate::{RangeValidator, Validator};
 
     fn assert_is_in_range<R, T: PartialOrd<T>>(range: R, value: T) where RangeValidator<R>: Validator<T> {
-        RangeValidator{range}.validate(&value).expect_err("Should be in range");
+        RangeValidator{range}.validate(&value).unwrap_or_else(|_| panic!("Should be in range"));
     }
 
     fn assert_is_not_in_range<R, T: PartialOrd<T>>(range: R, value: T) where RangeValidator<R>: Validator<T> {
-        RangeValidator{range}.validate(&value).unwrap_or_else(|_| panic!("Should not be in range"));;
+        RangeValidator{range}.validate(&value).expect_err("Should not be in range");
     }
 
     #[test]
@@ -55,4 +103,12 @@ mod test {
         assert_is_not_in_range(0..=100, -101);
         assert_is_in_range(0.., 505);
     }
+
+    #[test]
+    fn values_inside_the_range_are_accepted() {
+        let validator = RangeValidator::new(18..120);
+        assert_eq!(validator.validate(&18), Ok(()));
+        assert_eq!(validator.validate(&119), Ok(()));
+        assert_eq!(validator.validate(&17), Err(NotInRangeError(18..120)));
+    }
 }
\ No newline at end of file
@@ -0,0 +1,197 @@
+use std::borrow::Cow;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use thiserror::Error;
+
+use crate::{CustomError, ErrorBudget, ErrorCode, ErrorList, ErrorPath, PathSegment, ValidationError};
+
+/// A value which may or may not be set, such as an optional field checked by the cross-field rules of the derive
+pub trait Presence {
+    fn is_present(&self) -> bool;
+}
+
+impl<T> Presence for Option<T> {
+    fn is_present(&self) -> bool {
+        self.is_some()
+    }
+}
+
+impl<T: Presence + ?Sized> Presence for &T {
+    fn is_present(&self) -> bool {
+        (**self).is_present()
+    }
+}
+
+/// An error of a rule involving several fields of a value, such as `must_match(password_confirmation, password)`
+#[derive(Debug, PartialEq, Clone, Error)]
+pub enum SchemaError {
+    #[error("{field} does not match {other}")]
+    Mismatch {
+        field: PathSegment,
+        other: PathSegment,
+    },
+    #[error("{field} is required when {condition} is {value}")]
+    Required {
+        field: PathSegment,
+        condition: PathSegment,
+        value: Cow<'static, str>,
+    },
+    #[error("exactly one of {} must be set, found {present}", Fields(.fields))]
+    NotExactlyOne {
+        fields: Vec<PathSegment>,
+        present: usize,
+    },
+    #[error("at least one of {} must be set", Fields(.fields))]
+    NoneSet {
+        fields: Vec<PathSegment>,
+    },
+    #[error(transparent)]
+    Custom(#[from] CustomError),
+}
+
+// formats the names of the fields, separated by commas
+struct Fields<'a>(&'a [PathSegment]);
+
+impl Display for Fields<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut separator = "";
+        for field in self.0 {
+            write!(f, "{separator}{field}")?;
+            separator = ", ";
+        }
+        Ok(())
+    }
+}
+
+// errors about a single field are reported at the path of that field, the others at the path of the value
+impl ValidationError for SchemaError {
+    fn collect_errors<'a>(&'a self, path: &mut ErrorPath, errors: &mut ErrorList<'a>) {
+        match self {
+            SchemaError::Mismatch { field, .. } | SchemaError::Required { field, .. } => {
+                path.push(field.clone());
+                errors.push((path.clone(), self));
+                path.pop();
+            }
+            _ => errors.push((path.clone(), self)),
+        }
+    }
+}
+
+impl ErrorCode for SchemaError {
+    fn code(&self) -> &str {
+        match self {
+            SchemaError::Mismatch { .. } => "must_match",
+            SchemaError::Required { .. } => "required_if",
+            SchemaError::NotExactlyOne { .. } => "exactly_one_of",
+            SchemaError::NoneSet { .. } => "at_least_one_of",
+            SchemaError::Custom(error) => error.code(),
+        }
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        match self {
+            SchemaError::Mismatch { field, other } => vec![("field", field.to_string()), ("other", other.to_string())],
+            SchemaError::Required { field, condition, value } => {
+                vec![("field", field.to_string()), ("condition", condition.to_string()), ("value", value.to_string())]
+            }
+            SchemaError::NotExactlyOne { fields, present } => {
+                vec![("fields", Fields(fields).to_string()), ("present", present.to_string())]
+            }
+            SchemaError::NoneSet { fields } => vec![("fields", Fields(fields).to_string())],
+            SchemaError::Custom(error) => error.params(),
+        }
+    }
+}
+
+/// The errors of the cross-field rules of a value which failed
+#[derive(Debug, PartialEq, Clone)]
+pub struct SchemaErrors(Vec<SchemaError>);
+
+/// A cross-field rule, checked against the value it was declared on
+pub type SchemaRule<'a> = &'a dyn std::ops::Fn() -> Result<(), SchemaError>;
+
+impl SchemaErrors {
+    /// Checks the rules in order, skipping the rules left once the budget is exhausted
+    pub fn check<'a>(rules: impl IntoIterator<Item=SchemaRule<'a>>, budget: &mut ErrorBudget) -> Result<(), Self> {
+        let mut errors = Vec::new();
+        for rule in rules {
+            if budget.is_exhausted() {
+                break;
+            }
+            if let Err(error) = rule() {
+                errors.push(error);
+                budget.spend();
+            }
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(Self(errors))
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item=&SchemaError> {
+        self.0.iter()
+    }
+}
+
+impl Display for SchemaErrors {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut separator = "";
+        for error in &self.0 {
+            write!(f, "{separator}{error}")?;
+            separator = ", ";
+        }
+        Ok(())
+    }
+}
+
+// the source is the first error found
+impl Error for SchemaErrors {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.0.first().map(|error| error as _)
+    }
+}
+
+impl ValidationError for SchemaErrors {
+    fn collect_errors<'a>(&'a self, path: &mut ErrorPath, errors: &mut ErrorList<'a>) {
+        for error in &self.0 {
+            error.collect_errors(path, errors);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{ErrorBudget, PathSegment, SchemaError, SchemaErrors, SchemaRule, ValidationOptions};
+
+    fn mismatch() -> Result<(), SchemaError> {
+        Err(SchemaError::Mismatch {
+            field: PathSegment::Field("password_confirmation".into()),
+            other: PathSegment::Field("password".into()),
+        })
+    }
+
+    fn none_set() -> Result<(), SchemaError> {
+        Err(SchemaError::NoneSet { fields: vec![PathSegment::Field("phone".into()), PathSegment::Field("email".into())] })
+    }
+
+    #[test]
+    fn reports_failed_rules() {
+        let rules: [SchemaRule; 3] = [&mismatch, &|| Ok(()), &none_set];
+        let error = SchemaErrors::check(rules, &mut ErrorBudget::unlimited()).unwrap_err();
+        assert_eq!(error.to_string(), "password_confirmation does not match password, at least one of phone, email must be set");
+        let rules: [SchemaRule; 1] = [&|| Ok(())];
+        SchemaErrors::check(rules, &mut ErrorBudget::unlimited()).expect("should be valid");
+    }
+
+    #[test]
+    fn stops_once_the_budget_is_exhausted() {
+        let mut budget = ValidationOptions::fail_fast().budget();
+        let rules: [SchemaRule; 2] = [&mismatch, &none_set];
+        let error = SchemaErrors::check(rules, &mut budget).unwrap_err();
+        assert_eq!(error.iter().count(), 1);
+        assert!(budget.is_exhausted());
+    }
+}
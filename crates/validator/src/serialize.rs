@@ -0,0 +1,201 @@
+//! Serialisation of errors, enabled by the `serde` feature.
+//!
+//! Every error is serialised as a map from the paths of the errors found in it to the list of the errors found
+//! at each path, each error being made of its `code`, its `message` and its `params`:
+//!
+//! ```json
+//! {
+//!   "password": [{ "code": "length.too_short", "message": "value of length 6 falls short of minimum of 8", "params": { "min": "8", "len": "6" } }],
+//!   "emails[0]": [{ "code": "email", "message": "No '@' character was found in the given address", "params": {} }]
+//! }
+//! ```
+//!
+//! The paths are formatted as by [ErrorPath](crate::ErrorPath), the errors of the value itself being found at the
+//! empty path. Errors which are not made up of other errors, such as [InvalidLengthError], are serialised as a single
+//! `{ code, message, params }` object when serialised on their own.
+
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+
+use serde::ser::{SerializeMap, SerializeStruct};
+use serde::{Serialize, Serializer};
+
+use crate::{
+    AllOfError, AndError, AnyOfError, CustomError, ElementsInvalid, EmptyValueError, EntryError, ExactlyOneError,
+    FailedRule, ForbiddenValueError, InvalidCharError, InvalidEmailError, InvalidLengthError, LeafError, MapInvalid,
+    MessageError, MissingPrefixError, MissingSubstringError, MissingSuffixError, MissingValueError, NegatedRuleError,
+    NotInRangeError, NotOneOfError, OrError, PatternMismatchError, SchemaError, SchemaErrors, SetInvalid,
+    SeveralMatchedError, ThenError, ValidationError, ValidationFailure,
+};
+
+/// Serialises the errors found in an error as a map from their paths to the list of the errors found at each path
+pub fn serialize_errors<E, S>(error: &E, serializer: S) -> Result<S::Ok, S::Error>
+where
+    E: ValidationError + ?Sized,
+    S: Serializer
+{
+    // the errors are grouped by path, the paths being kept in the order in which they were found and indexed so
+    // that grouping stays linear in the number of errors
+    let mut paths: Vec<(String, Vec<Leaf>)> = Vec::new();
+    let mut positions: HashMap<String, usize> = HashMap::new();
+    for (path, error) in error.errors() {
+        match positions.entry(path.to_string()) {
+            Entry::Occupied(position) => paths[*position.get()].1.push(Leaf(error)),
+            Entry::Vacant(position) => {
+                paths.push((position.key().clone(), vec![Leaf(error)]));
+                position.insert(paths.len() - 1);
+            }
+        }
+    }
+    let mut map = serializer.serialize_map(Some(paths.len()))?;
+    for (path, errors) in &paths {
+        map.serialize_entry(path, errors)?;
+    }
+    map.end()
+}
+
+/// Serialises an error which is not made up of other errors as its `code`, its `message` and its `params`
+pub fn serialize_error<S: Serializer>(error: &dyn LeafError, serializer: S) -> Result<S::Ok, S::Error> {
+    let mut object = serializer.serialize_struct("Error", 3)?;
+    object.serialize_field("code", error.code())?;
+    object.serialize_field("message", &error.to_string())?;
+    object.serialize_field("params", &Params(error.params()))?;
+    object.end()
+}
+
+struct Leaf<'a>(&'a dyn LeafError);
+
+impl Serialize for Leaf<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_error(self.0, serializer)
+    }
+}
+
+struct Params(Vec<(&'static str, String)>);
+
+impl Serialize for Params {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for (name, value) in &self.0 {
+            map.serialize_entry(name, value)?;
+        }
+        map.end()
+    }
+}
+
+// implements Serialize for errors which are not made up of other errors
+macro_rules! serialize_leaf {
+    ($(<$($generic:ident),*> $error:ty),* $(,)?) => {
+        $(
+        impl<$($generic),*> Serialize for $error
+        where
+            $error: LeafError
+        {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serialize_error(self, serializer)
+            }
+        }
+        )*
+    };
+}
+
+// implements Serialize for errors made up of other errors
+macro_rules! serialize_nested {
+    ($(<$($generic:ident),*> $error:ty),* $(,)?) => {
+        $(
+        impl<$($generic),*> Serialize for $error
+        where
+            $error: ValidationError
+        {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serialize_errors(self, serializer)
+            }
+        }
+        )*
+    };
+}
+
+serialize_leaf!(
+    <> EmptyValueError,
+    <> MissingValueError,
+    <> PatternMismatchError,
+    <> MissingSubstringError,
+    <> MissingPrefixError,
+    <> MissingSuffixError,
+    <> InvalidEmailError,
+    <> InvalidLengthError,
+    <> InvalidCharError,
+    <> CustomError,
+    <> SchemaError,
+    <> NegatedRuleError,
+    <> SeveralMatchedError,
+    <> FailedRule,
+    <R> NotInRangeError<R>,
+    <V> NotOneOfError<V>,
+    <V> ForbiddenValueError<V>,
+    <E> MessageError<E>,
+);
+
+serialize_nested!(
+    <A, B> AndError<A, B>,
+    <A, B> OrError<A, B>,
+    <A, B> ThenError<A, B>,
+    <E> AllOfError<E>,
+    <E> AnyOfError<E>,
+    <E> ExactlyOneError<E>,
+    <E> ElementsInvalid<E>,
+    <T, E> SetInvalid<T, E>,
+    <K, E> MapInvalid<K, E>,
+    <K, V> EntryError<K, V>,
+    <> SchemaErrors,
+    <> ValidationFailure,
+);
+
+#[cfg(feature = "json-schema")]
+serialize_leaf!(
+    <> crate::InvalidTypeError,
+    <> crate::UnexpectedValueError,
+    <> crate::NotAllowedError,
+);
+
+#[cfg(feature = "json-schema")]
+serialize_nested!(
+    <> crate::JsonSchemaErrors,
+);
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use crate::{And, ElementsValidator, EmailValidator, LengthValidator, NotEmptyValidator, ValidationFailure, Validator};
+
+    #[test]
+    fn leaf() {
+        let error = LengthValidator::new(Some(8), None).validate("secret").unwrap_err();
+        assert_eq!(serde_json::to_value(error).unwrap(), json!({
+            "code": "length.too_short",
+            "message": "value of length 6 falls short of minimum of 8",
+            "params": { "min": "8", "len": "6" },
+        }));
+    }
+
+    #[test]
+    fn nested() {
+        let validator = ElementsValidator::new(And::new(NotEmptyValidator, EmailValidator));
+        let error = validator.validate(&vec!["someone@example.com".to_string(), String::new()]).unwrap_err();
+        assert_eq!(serde_json::to_value(error).unwrap(), json!({
+            "[1]": [
+                { "code": "not_empty", "message": "Value should not be empty", "params": {} },
+                { "code": "email", "message": "An empty string is not a valid email", "params": {} },
+            ],
+        }));
+    }
+
+    #[test]
+    fn erased() {
+        let validator = ElementsValidator::new(And::new(NotEmptyValidator, EmailValidator));
+        let error = validator.validate(&vec![String::new()]).unwrap_err();
+        let failure = ValidationFailure::new(&error);
+        assert_eq!(serde_json::to_value(failure).unwrap(), serde_json::to_value(error).unwrap());
+    }
+}
@@ -0,0 +1,94 @@
+use thiserror::Error;
+use crate::{ErrorBudget, ErrorCode, ErrorList, ErrorPath, ValidationError, Validator, ValidatorWithContext};
+
+/// Validates values with the first validator, then with the second one only if the first one passed, such as
+/// `#[validator(then(not_empty, email))]` which does not report an empty string as an invalid email
+#[derive(Debug, Default, PartialEq)]
+pub struct Then<A, B>(A, B);
+
+/// The error of the stage which failed
+#[derive(Debug, Error, PartialEq, Clone)]
+pub enum ThenError<A, B> {
+    #[error(transparent)]
+    First(A),
+    #[error(transparent)]
+    Second(B),
+}
+
+impl<A: ValidationError, B: ValidationError> ValidationError for ThenError<A, B> {
+    fn collect_errors<'a>(&'a self, path: &mut ErrorPath, errors: &mut ErrorList<'a>) {
+        match self {
+            ThenError::First(first) => first.collect_errors(path, errors),
+            ThenError::Second(second) => second.collect_errors(path, errors),
+        }
+    }
+}
+
+impl<A: ErrorCode, B: ErrorCode> ErrorCode for ThenError<A, B> {
+    fn code(&self) -> &str {
+        match self {
+            ThenError::First(first) => first.code(),
+            ThenError::Second(second) => second.code(),
+        }
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        match self {
+            ThenError::First(first) => first.params(),
+            ThenError::Second(second) => second.params(),
+        }
+    }
+}
+
+impl<A, B, T> Validator<T> for Then<A, B> where A: Validator<T>, B: Validator<T> {
+    type Error = ThenError<A::Error, B::Error>;
+
+    fn validate(&self, value: &T) -> Result<(), Self::Error> {
+        self.0.validate(value).map_err(ThenError::First)?;
+        self.1.validate(value).map_err(ThenError::Second)
+    }
+
+    fn validate_budgeted(&self, value: &T, budget: &mut ErrorBudget) -> Result<(), Self::Error> {
+        self.0.validate_budgeted(value, budget).map_err(ThenError::First)?;
+        self.1.validate_budgeted(value, budget).map_err(ThenError::Second)
+    }
+}
+
+impl<A, B, T, C: ?Sized> ValidatorWithContext<T, C> for Then<A, B> where A: ValidatorWithContext<T, C>, B: ValidatorWithContext<T, C> {
+    type Error = ThenError<A::Error, B::Error>;
+
+    fn validate_with(&self, value: &T, context: &C) -> Result<(), Self::Error> {
+        self.0.validate_with(value, context).map_err(ThenError::First)?;
+        self.1.validate_with(value, context).map_err(ThenError::Second)
+    }
+}
+
+// unlike `And`, the validators are not run concurrently as the second one depends on the first one passing
+#[cfg(feature = "async")]
+impl<A, B, T> crate::AsyncValidator<T> for Then<A, B> where A: crate::AsyncValidator<T>, B: crate::AsyncValidator<T> {
+    type Error = ThenError<A::Error, B::Error>;
+
+    async fn validate_async(&self, value: &T) -> Result<(), Self::Error> {
+        self.0.validate_async(value).await.map_err(ThenError::First)?;
+        self.1.validate_async(value).await.map_err(ThenError::Second)
+    }
+}
+
+impl<A, B> Then<A, B> {
+    pub fn new(first: A, second: B) -> Self {
+        Self(first, second)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{EmailValidator, EmptyValueError, InvalidEmailError, NotEmptyValidator, Then, ThenError, Validator};
+
+    #[test]
+    fn stops_at_the_first_stage_which_fails() {
+        let validator = Then::new(NotEmptyValidator, EmailValidator);
+        validator.validate(&"someone@example.com".to_string()).expect("should be valid");
+        assert_eq!(validator.validate(&String::new()), Err(ThenError::First(EmptyValueError)));
+        assert_eq!(validator.validate(&"someone".to_string()), Err(ThenError::Second(InvalidEmailError::ATNotFound)));
+    }
+}
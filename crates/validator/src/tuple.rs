@@ -1,3 +1,13 @@
+// the elements are listed from last to first, so they are reversed to spend the budget in order
+macro_rules! validate_in_order {
+    ($self:ident, $value:ident, $budget:ident, [] [$(($v:ident, $i:tt))*]) => {
+        $(let $v = $self.$i.validate_budgeted(&$value.$i, $budget);)*
+    };
+    ($self:ident, $value:ident, $budget:ident, [$head:tt $($rest:tt)*] [$($reversed:tt)*]) => {
+        validate_in_order!($self, $value, $budget, [$($rest)*] [$head $($reversed)*])
+    };
+}
+
 macro_rules! tuple {
     (($t:ident), ($v:ident), ($e:ident), ($i:tt)) => {};
     (($v_head:ident $(,$v:ident)*), ($t_head:ident $(,$t:ident)*), ($e_head:ident $(,$e:ident)*), ($i_head:tt $(,$i:tt)*)) => {
@@ -9,6 +19,60 @@ macro_rules! tuple {
 #[derive(Debug, PartialEq, Clone)]
 pub struct $e< $($t),* >($(pub Option<$t>),*);
 
+impl<$($t: std::fmt::Display),*> std::fmt::Display for $e< $($t),* > {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let elements: [(usize, Option<&dyn std::fmt::Display>); [$($i),*].len()] = [
+            $(($i, self.$i.as_ref().map(|error| error as _))),*
+        ];
+        crate::elements::display_elements(f, elements.into_iter()
+            .rev()
+            .filter_map(|(index, error)| Some((index, error?))))
+    }
+}
+
+// the source is the error of the first invalid element
+impl<$($t: std::error::Error + 'static),*> std::error::Error for $e< $($t),* > {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        let elements: [Option<&(dyn std::error::Error + 'static)>; [$($i),*].len()] = [
+            $(self.$i.as_ref().map(|error| error as _)),*
+        ];
+        elements.into_iter().rev().flatten().next()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<$($t),*> serde::Serialize for $e< $($t),* >
+where
+    $e< $($t),* >: crate::ValidationError
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::serialize_errors(self, serializer)
+    }
+}
+
+impl<$($t),*> crate::ErrorCode for $e< $($t),* > {
+    fn code(&self) -> &str {
+        "tuple"
+    }
+}
+
+impl<$($t: crate::ValidationError),*> crate::ValidationError for $e< $($t),* > {
+    fn collect_errors<'a>(&'a self, path: &mut crate::ErrorPath, errors: &mut crate::ErrorList<'a>) {
+        // the elements are listed from last to first, so they are reversed to collect the errors in order
+        let elements: [(usize, Option<&dyn crate::ValidationError>); [$($i),*].len()] = [
+            $(($i, self.$i.as_ref().map(|error| error as _))),*
+        ];
+        for (index, error) in elements.into_iter().rev() {
+            if let Some(error) = error {
+                path.collect_nested(crate::PathSegment::Index(index), error, errors);
+            }
+        }
+    }
+}
+
+// a tuple is only a validator if its elements are
+impl<$($v: crate::ValidatorExt),*> crate::ValidatorExt for ($($v),*) {}
+
 impl<$($v: crate::Validator<$t>, $t),*> crate::Validator<( $($t),* )> for ($($v),*) {
     type Error = $e< $($v::Error),* >;
 
@@ -32,6 +96,84 @@ impl<$($v: crate::Validator<$t>, $t),*> crate::Validator<( $($t),* )> for ($($v)
             Err(error)
         }
     }
+
+    #[allow(non_snake_case)]
+    fn validate_budgeted(&self, value: &( $($t),* ), budget: &mut crate::ErrorBudget) -> Result<(), Self::Error> {
+        // the results are bound to the names of the validator type parameters
+        validate_in_order!(self, value, budget, [$(($v, $i))*] []);
+        let mut valid = true;
+        let error = $e {
+            $($i: {
+                match $v {
+                    Ok(()) => None,
+                    Err(error) => {
+                        valid = false;
+                        Some(error)
+                    }
+                }
+            }),*
+        };
+
+        if valid {
+            Ok(())
+        } else {
+            Err(error)
+        }
+    }
+}
+
+impl<C: ?Sized, $($v: crate::ValidatorWithContext<$t, C>, $t),*> crate::ValidatorWithContext<( $($t),* ), C> for ($($v),*) {
+    type Error = $e< $($v::Error),* >;
+
+    fn validate_with(&self, value: &( $($t),* ), context: &C) -> Result<(), Self::Error> {
+        let mut valid = true;
+        let error = $e {
+            $($i: {
+                match self.$i.validate_with(&value.$i, context) {
+                    Ok(()) => None,
+                    Err(error) => {
+                        valid = false;
+                        Some(error)
+                    }
+                }
+            }),*
+        };
+
+        if valid {
+            Ok(())
+        } else {
+            Err(error)
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl<$($v: crate::AsyncValidator<$t>, $t),*> crate::AsyncValidator<( $($t),* )> for ($($v),*) {
+    type Error = $e< $($v::Error),* >;
+
+    #[allow(non_snake_case)]
+    async fn validate_async(&self, value: &( $($t),* )) -> Result<(), Self::Error> {
+        // the results are bound to the names of the validator type parameters
+        let ($($v,)*) = futures::join!($(self.$i.validate_async(&value.$i)),*);
+        let mut valid = true;
+        let error = $e {
+            $($i: {
+                match $v {
+                    Ok(()) => None,
+                    Err(error) => {
+                        valid = false;
+                        Some(error)
+                    }
+                }
+            }),*
+        };
+
+        if valid {
+            Ok(())
+        } else {
+            Err(error)
+        }
+    }
 }
 
     };
@@ -1,4 +1,4 @@
-use crate::Validator;
+use crate::{ErrorCode, Validator};
 
 pub struct UrlValidator;
 pub use url::ParseError as InvalidUrlError;
@@ -9,4 +9,13 @@ impl Validator<str> for UrlValidator {
     fn validate(&self, value: &str) -> Result<(), Self::Error> {
         url::Url::parse(value).map(|_| ())
     }
-}
\ No newline at end of file
+}
+
+leaf_validator!(UrlValidator);
+leaf_error!(InvalidUrlError);
+
+impl ErrorCode for InvalidUrlError {
+    fn code(&self) -> &str {
+        "url"
+    }
+}
@@ -0,0 +1,427 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use thiserror::Error;
+
+use crate::or::spent;
+use crate::{ErrorBudget, ErrorCode, ErrorList, ErrorPath, ValidationError, Validator, ValidatorWithContext};
+
+/// Validates values with all the validators of a tuple, such as `#[validator(all(not_empty, email, length(max = 64)))]`,
+/// the errors of the validators being reported together
+#[derive(Debug, Default)]
+pub struct AllOf<V>(V);
+
+/// Validates values with the validators of a tuple until one of them passes, such as
+/// `#[validator(any(email, url, ip))]`
+#[derive(Debug, Default)]
+pub struct AnyOf<V>(V);
+
+/// Validates that values pass exactly one of the validators of a tuple, such as
+/// `#[validator(exactly_one(email, starts_with = "+"))]`
+#[derive(Debug, Default)]
+pub struct ExactlyOne<V>(V);
+
+/// The errors of the validators of [AllOf] as a tuple, `None` for the validators which passed
+#[derive(Debug, PartialEq, Clone)]
+pub struct AllOfError<E>(pub E);
+
+/// The errors of all the validators of [AnyOf] as a tuple
+#[derive(Debug, PartialEq, Clone)]
+pub struct AnyOfError<E>(pub E);
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum ExactlyOneError<E> {
+    /// None of the validators passed, along with the errors of all of them as a tuple
+    NoneMatched(E),
+    SeveralMatched(SeveralMatchedError),
+}
+
+#[derive(Debug, PartialEq, Clone, Error)]
+#[error("Value matches {matched} of the alternatives instead of exactly one")]
+pub struct SeveralMatchedError {
+    pub matched: usize,
+}
+
+impl<V> AllOf<V> {
+    pub fn new(validators: V) -> Self {
+        Self(validators)
+    }
+}
+
+impl<V> AnyOf<V> {
+    pub fn new(validators: V) -> Self {
+        Self(validators)
+    }
+}
+
+impl<V> ExactlyOne<V> {
+    pub fn new(validators: V) -> Self {
+        Self(validators)
+    }
+}
+
+leaf_error!(SeveralMatchedError);
+
+impl ErrorCode for SeveralMatchedError {
+    fn code(&self) -> &str {
+        "one_of"
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        vec![("matched", self.matched.to_string())]
+    }
+}
+
+// formats the errors separated by "and", as the errors of `And` and `Or`
+fn display_all<'a>(f: &mut Formatter<'_>, errors: impl IntoIterator<Item=&'a dyn Display>) -> std::fmt::Result {
+    let mut separator = "";
+    for error in errors {
+        write!(f, "{separator}{error}")?;
+        separator = " and ";
+    }
+    Ok(())
+}
+
+// implements the combinators for tuples of validators, the validators and their indices being added one at a time
+macro_rules! variadic {
+    ([$($done:tt)*] []) => {};
+    ([$($done:tt)*] [$next:tt $($rest:tt)*]) => {
+        variadic!(impl $($done)* $next);
+        variadic!([$($done)* $next] [$($rest)*]);
+    };
+    (impl $(($v:ident, $e:ident, $i:tt))*) => {
+
+impl<$($e: Display),*> Display for AllOfError<($(Option<$e>,)*)> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        display_all(f, [$(self.0.$i.as_ref().map(|error| error as &dyn Display)),*].into_iter().flatten())
+    }
+}
+
+impl<$($e: Display),*> Display for AnyOfError<($($e,)*)> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        display_all(f, [$(&self.0.$i as &dyn Display),*])
+    }
+}
+
+impl<$($e: Display),*> Display for ExactlyOneError<($($e,)*)> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExactlyOneError::NoneMatched(errors) => display_all(f, [$(&errors.$i as &dyn Display),*]),
+            ExactlyOneError::SeveralMatched(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+// the source is the error of the first validator which failed
+impl<$($e: Error + 'static),*> Error for AllOfError<($(Option<$e>,)*)> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        [$(self.0.$i.as_ref().map(|error| error as &(dyn Error + 'static))),*].into_iter().flatten().next()
+    }
+}
+
+impl<$($e: Error + 'static),*> Error for AnyOfError<($($e,)*)> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        [$(&self.0.$i as &(dyn Error + 'static)),*].into_iter().next()
+    }
+}
+
+impl<$($e: Error + 'static),*> Error for ExactlyOneError<($($e,)*)> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ExactlyOneError::NoneMatched(errors) => [$(&errors.$i as &(dyn Error + 'static)),*].into_iter().next(),
+            ExactlyOneError::SeveralMatched(_) => None,
+        }
+    }
+}
+
+impl<$($e: ValidationError),*> ValidationError for AllOfError<($(Option<$e>,)*)> {
+    fn collect_errors<'a>(&'a self, path: &mut ErrorPath, errors: &mut ErrorList<'a>) {
+        $(
+        if let Some(error) = &self.0.$i {
+            error.collect_errors(path, errors);
+        }
+        )*
+    }
+}
+
+// the value is at fault for all the alternatives, so the errors of all of them are reported
+impl<$($e: ValidationError),*> ValidationError for AnyOfError<($($e,)*)> {
+    fn collect_errors<'a>(&'a self, path: &mut ErrorPath, errors: &mut ErrorList<'a>) {
+        $(self.0.$i.collect_errors(path, errors);)*
+    }
+}
+
+impl<$($e: ValidationError),*> ValidationError for ExactlyOneError<($($e,)*)> {
+    fn collect_errors<'a>(&'a self, path: &mut ErrorPath, errors: &mut ErrorList<'a>) {
+        match self {
+            ExactlyOneError::NoneMatched(alternatives) => {
+                $(alternatives.$i.collect_errors(path, errors);)*
+            }
+            ExactlyOneError::SeveralMatched(error) => error.collect_errors(path, errors),
+        }
+    }
+}
+
+// the code and parameters are the ones of the first rule which failed
+impl<$($e: ErrorCode),*> ErrorCode for AllOfError<($(Option<$e>,)*)> {
+    fn code(&self) -> &str {
+        [$(self.0.$i.as_ref().map(|error| error as &dyn ErrorCode)),*].into_iter().flatten().next()
+            .map_or("all", |error| error.code())
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        [$(self.0.$i.as_ref().map(|error| error as &dyn ErrorCode)),*].into_iter().flatten().next()
+            .map_or_else(Vec::new, |error| error.params())
+    }
+}
+
+// the code and parameters are the ones of the first alternative
+impl<$($e: ErrorCode),*> ErrorCode for AnyOfError<($($e,)*)> {
+    fn code(&self) -> &str {
+        self.0.0.code()
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        self.0.0.params()
+    }
+}
+
+impl<$($e: ErrorCode),*> ErrorCode for ExactlyOneError<($($e,)*)> {
+    fn code(&self) -> &str {
+        match self {
+            ExactlyOneError::NoneMatched(alternatives) => alternatives.0.code(),
+            ExactlyOneError::SeveralMatched(error) => error.code(),
+        }
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        match self {
+            ExactlyOneError::NoneMatched(alternatives) => alternatives.0.params(),
+            ExactlyOneError::SeveralMatched(error) => error.params(),
+        }
+    }
+}
+
+impl<T, $($v: Validator<T>),*> Validator<T> for AllOf<($($v,)*)> {
+    type Error = AllOfError<($(Option<$v::Error>,)*)>;
+
+    fn validate(&self, value: &T) -> Result<(), Self::Error> {
+        let errors = ($(self.0.$i.validate(value).err(),)*);
+        if $(errors.$i.is_none())&&* {
+            Ok(())
+        } else {
+            Err(AllOfError(errors))
+        }
+    }
+
+    fn validate_budgeted(&self, value: &T, budget: &mut ErrorBudget) -> Result<(), Self::Error> {
+        let errors = ($(self.0.$i.validate_budgeted(value, budget).err(),)*);
+        if $(errors.$i.is_none())&&* {
+            Ok(())
+        } else {
+            Err(AllOfError(errors))
+        }
+    }
+}
+
+impl<T, C: ?Sized, $($v: ValidatorWithContext<T, C>),*> ValidatorWithContext<T, C> for AllOf<($($v,)*)> {
+    type Error = AllOfError<($(Option<$v::Error>,)*)>;
+
+    fn validate_with(&self, value: &T, context: &C) -> Result<(), Self::Error> {
+        let errors = ($(self.0.$i.validate_with(value, context).err(),)*);
+        if $(errors.$i.is_none())&&* {
+            Ok(())
+        } else {
+            Err(AllOfError(errors))
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl<T, $($v: crate::AsyncValidator<T>),*> crate::AsyncValidator<T> for AllOf<($($v,)*)> {
+    type Error = AllOfError<($(Option<$v::Error>,)*)>;
+
+    #[allow(non_snake_case)]
+    async fn validate_async(&self, value: &T) -> Result<(), Self::Error> {
+        // the results are bound to the names of the validator type parameters
+        let ($($v,)*) = futures::join!($(self.0.$i.validate_async(value)),*);
+        let errors = ($($v.err(),)*);
+        if $(errors.$i.is_none())&&* {
+            Ok(())
+        } else {
+            Err(AllOfError(errors))
+        }
+    }
+}
+
+// as with `Or`, the validators following the first one which passes are not run
+impl<T, $($v: Validator<T>),*> Validator<T> for AnyOf<($($v,)*)> {
+    type Error = AnyOfError<($($v::Error,)*)>;
+
+    fn validate(&self, value: &T) -> Result<(), Self::Error> {
+        Err(AnyOfError(($(
+            match self.0.$i.validate(value) {
+                Ok(()) => return Ok(()),
+                Err(error) => error,
+            },
+        )*)))
+    }
+
+    // as with `Or`, each alternative is validated against a copy of the budget, which is only spent if all of them fail
+    #[allow(non_snake_case)]
+    fn validate_budgeted(&self, value: &T, budget: &mut ErrorBudget) -> Result<(), Self::Error> {
+        // the copies of the budget are bound to the names of the validator type parameters
+        $(let mut $v = budget.clone();)*
+        let errors = ($(
+            match self.0.$i.validate_budgeted(value, &mut $v) {
+                Ok(()) => return Ok(()),
+                Err(error) => error,
+            },
+        )*);
+        for _ in 0..(0 $(+ spent(budget, &$v))*) {
+            budget.spend();
+        }
+        Err(AnyOfError(errors))
+    }
+}
+
+impl<T, C: ?Sized, $($v: ValidatorWithContext<T, C>),*> ValidatorWithContext<T, C> for AnyOf<($($v,)*)> {
+    type Error = AnyOfError<($($v::Error,)*)>;
+
+    fn validate_with(&self, value: &T, context: &C) -> Result<(), Self::Error> {
+        Err(AnyOfError(($(
+            match self.0.$i.validate_with(value, context) {
+                Ok(()) => return Ok(()),
+                Err(error) => error,
+            },
+        )*)))
+    }
+}
+
+#[cfg(feature = "async")]
+impl<T, $($v: crate::AsyncValidator<T>),*> crate::AsyncValidator<T> for AnyOf<($($v,)*)> {
+    type Error = AnyOfError<($($v::Error,)*)>;
+
+    async fn validate_async(&self, value: &T) -> Result<(), Self::Error> {
+        Err(AnyOfError(($(
+            match self.0.$i.validate_async(value).await {
+                Ok(()) => return Ok(()),
+                Err(error) => error,
+            },
+        )*)))
+    }
+}
+
+impl<T, $($v: Validator<T>),*> Validator<T> for ExactlyOne<($($v,)*)> {
+    type Error = ExactlyOneError<($($v::Error,)*)>;
+
+    #[allow(non_snake_case)]
+    fn validate(&self, value: &T) -> Result<(), Self::Error> {
+        // the results are bound to the names of the validator type parameters
+        $(let $v = self.0.$i.validate(value);)*
+        match ($($v,)*) {
+            ($(Err($v),)*) => Err(ExactlyOneError::NoneMatched(($($v,)*))),
+            results => exactly_one(&[$(results.$i.is_ok()),*]),
+        }
+    }
+
+    // the errors of the alternatives are only reported, and the budget spent, if none of them passes
+    #[allow(non_snake_case)]
+    fn validate_budgeted(&self, value: &T, budget: &mut ErrorBudget) -> Result<(), Self::Error> {
+        // the copies of the budget are bound to the names of the validator type parameters
+        $(let mut $v = budget.clone();)*
+        let results = ($(self.0.$i.validate_budgeted(value, &mut $v),)*);
+        let spent_by_all = 0 $(+ spent(budget, &$v))*;
+        match results {
+            ($(Err($v),)*) => {
+                for _ in 0..spent_by_all {
+                    budget.spend();
+                }
+                Err(ExactlyOneError::NoneMatched(($($v,)*)))
+            }
+            results => exactly_one(&[$(results.$i.is_ok()),*]),
+        }
+    }
+}
+
+impl<T, C: ?Sized, $($v: ValidatorWithContext<T, C>),*> ValidatorWithContext<T, C> for ExactlyOne<($($v,)*)> {
+    type Error = ExactlyOneError<($($v::Error,)*)>;
+
+    #[allow(non_snake_case)]
+    fn validate_with(&self, value: &T, context: &C) -> Result<(), Self::Error> {
+        $(let $v = self.0.$i.validate_with(value, context);)*
+        match ($($v,)*) {
+            ($(Err($v),)*) => Err(ExactlyOneError::NoneMatched(($($v,)*))),
+            results => exactly_one(&[$(results.$i.is_ok()),*]),
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl<T, $($v: crate::AsyncValidator<T>),*> crate::AsyncValidator<T> for ExactlyOne<($($v,)*)> {
+    type Error = ExactlyOneError<($($v::Error,)*)>;
+
+    #[allow(non_snake_case)]
+    async fn validate_async(&self, value: &T) -> Result<(), Self::Error> {
+        match futures::join!($(self.0.$i.validate_async(value)),*) {
+            ($(Err($v),)*) => Err(ExactlyOneError::NoneMatched(($($v,)*))),
+            results => exactly_one(&[$(results.$i.is_ok()),*]),
+        }
+    }
+}
+
+    };
+}
+
+/// The result of [ExactlyOne] when some of the validators passed
+fn exactly_one<E>(passed: &[bool]) -> Result<(), ExactlyOneError<E>> {
+    match passed.iter().filter(|&&passed| passed).count() {
+        1 => Ok(()),
+        matched => Err(ExactlyOneError::SeveralMatched(SeveralMatchedError { matched })),
+    }
+}
+
+variadic!([(V0, E0, 0)] [
+    (V1, E1, 1) (V2, E2, 2) (V3, E3, 3) (V4, E4, 4) (V5, E5, 5) (V6, E6, 6)
+    (V7, E7, 7) (V8, E8, 8) (V9, E9, 9) (V10, E10, 10) (V11, E11, 11)
+]);
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        AllOf, AllOfError, AnyOf, EmailValidator, ErrorCode, ExactlyOne, ExactlyOneError, LengthValidator,
+        NotEmptyValidator, PrefixValidator, SeveralMatchedError, UrlValidator, ValidationError, Validator,
+    };
+
+    #[test]
+    fn all_of() {
+        let validator = AllOf::new((NotEmptyValidator, EmailValidator, LengthValidator::new(None, Some(16))));
+        validator.validate(&"a@example.com".to_string()).expect("should be valid");
+        let error = validator.validate(&"someone.with.a.long.name".to_string()).unwrap_err();
+        assert!(matches!(error, AllOfError((None, Some(_), Some(_)))));
+        assert_eq!(error.code(), "email");
+        assert_eq!(error.errors().len(), 2);
+    }
+
+    #[test]
+    fn any_of() {
+        let validator = AnyOf::new((EmailValidator, UrlValidator, PrefixValidator::new("+")));
+        validator.validate(&"+33 1 23 45 67 89".to_string()).expect("should be valid");
+        validator.validate(&"https://example.com".to_string()).expect("should be valid");
+        let error = validator.validate(&"someone".to_string()).unwrap_err();
+        assert_eq!(error.errors().len(), 3);
+        assert!(error.to_string().ends_with("and Value should start with +"), "{error}");
+    }
+
+    #[test]
+    fn exactly_one() {
+        let validator = ExactlyOne::new((EmailValidator, PrefixValidator::new("a")));
+        validator.validate(&"someone@example.com".to_string()).expect("should be valid");
+        validator.validate(&"anyone".to_string()).expect("should be valid");
+        assert_eq!(
+            validator.validate(&"a@example.com".to_string()),
+            Err(ExactlyOneError::SeveralMatched(SeveralMatchedError { matched: 2 })),
+        );
+        assert!(matches!(validator.validate(&"someone".to_string()), Err(ExactlyOneError::NoneMatched(_))));
+    }
+}
@@ -0,0 +1,171 @@
+#![cfg(feature = "async")]
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::task::{Context, Poll};
+
+use futures::executor::block_on;
+use validator::{AsyncValidator, ElementsInvalid, ElementsValidator, EmptyValueError, Validate, Validator};
+
+/// In-memory stand-in for the services holding the users and orders
+struct Directory {
+    usernames: &'static [&'static str],
+    orders: &'static [u64],
+    in_flight: AtomicUsize,
+    max_in_flight: AtomicUsize,
+}
+
+static DIRECTORY: Directory = Directory::new();
+
+impl Directory {
+    const fn new() -> Self {
+        Self {
+            usernames: &["ferris", "corro"],
+            orders: &[1, 2, 3],
+            in_flight: AtomicUsize::new(0),
+            max_in_flight: AtomicUsize::new(0),
+        }
+    }
+
+    async fn lookup<T>(&self, lookup: impl FnOnce(&Self) -> T) -> T {
+        let in_flight = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+        self.max_in_flight.fetch_max(in_flight, Ordering::SeqCst);
+        YieldNow(false).await;
+        self.in_flight.fetch_sub(1, Ordering::SeqCst);
+        lookup(self)
+    }
+}
+
+// pending once, so that concurrently validated fields are all in flight at the same time
+struct YieldNow(bool);
+
+impl Future for YieldNow {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.0 {
+            Poll::Ready(())
+        } else {
+            self.0 = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+struct Username(String);
+
+struct UsernameAvailable(&'static Directory);
+
+#[derive(Debug, PartialEq, Clone)]
+struct UsernameTaken;
+
+impl AsyncValidator<Username> for UsernameAvailable {
+    type Error = UsernameTaken;
+
+    async fn validate_async(&self, value: &Username) -> Result<(), Self::Error> {
+        let taken = self.0.lookup(|directory| directory.usernames.contains(&value.0.as_str())).await;
+        if taken {
+            Err(UsernameTaken)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl Validate for Username {
+    type Validator = UsernameAvailable;
+
+    fn validator() -> Self::Validator {
+        UsernameAvailable(&DIRECTORY)
+    }
+}
+
+struct OrderId(u64);
+
+struct OrderExists(&'static Directory);
+
+#[derive(Debug, PartialEq, Clone)]
+struct UnknownOrder(u64);
+
+impl AsyncValidator<OrderId> for OrderExists {
+    type Error = UnknownOrder;
+
+    async fn validate_async(&self, value: &OrderId) -> Result<(), Self::Error> {
+        if self.0.lookup(|directory| directory.orders.contains(&value.0)).await {
+            Ok(())
+        } else {
+            Err(UnknownOrder(value.0))
+        }
+    }
+}
+
+impl Validate for OrderId {
+    type Validator = OrderExists;
+
+    fn validator() -> Self::Validator {
+        OrderExists(&DIRECTORY)
+    }
+}
+
+#[derive(Validator)]
+#[validator(async)]
+struct Signup {
+    username: Username,
+    #[validator(and(not_empty, email))]
+    email: String,
+    #[validator(elements)]
+    orders: Vec<OrderId>,
+    address: Address,
+}
+
+#[derive(Validator)]
+struct Address {
+    #[validator(not_empty)]
+    city: String,
+}
+
+fn signup(username: &str, orders: &[u64]) -> Signup {
+    Signup {
+        username: Username(username.to_string()),
+        email: "crab@example.com".to_string(),
+        orders: orders.iter().copied().map(OrderId).collect(),
+        address: Address { city: "Dublin".to_string() },
+    }
+}
+
+#[test]
+fn async_validation() {
+    block_on(signup("crab", &[1, 3]).validate_async()).expect("should be valid");
+
+    let error = block_on(signup("ferris", &[1, 4]).validate_async()).expect_err("should be invalid");
+    assert_eq!(error.username, Some(UsernameTaken));
+    assert!(error.email.is_none());
+    let orders: ElementsInvalid<_> = error.orders.expect("orders should be invalid");
+    assert_eq!(orders.get(0), None);
+    assert_eq!(orders.get(1), Some(&UnknownOrder(4)));
+
+    let mut invalid_address = signup("crab", &[]);
+    invalid_address.address.city = String::new();
+    let error = block_on(invalid_address.validate_async()).expect_err("should be invalid");
+    assert_eq!(error.address, Some(AddressValidationErrors { city: Some(EmptyValueError) }));
+
+    // synchronous validators are usable asynchronously too
+    block_on(Address { city: "Dublin".to_string() }.validate_async()).expect("should be valid");
+    Address { city: "Dublin".to_string() }.validate().expect("should be valid");
+}
+
+#[test]
+fn fields_are_validated_concurrently() {
+    // a directory of its own, so that the lookups of the other tests are not counted
+    let directory: &'static Directory = Box::leak(Box::new(Directory::new()));
+    let validator = SignupValidator {
+        username: UsernameAvailable(directory),
+        orders: ElementsValidator::new(OrderExists(directory)),
+        ..Signup::validator()
+    };
+    block_on(validator.validate_async(&signup("crab", &[1, 2, 3]))).expect("should be valid");
+    assert_eq!(directory.in_flight.load(Ordering::SeqCst), 0);
+    assert_eq!(directory.max_in_flight.load(Ordering::SeqCst), 4, "all lookups should be in flight at once");
+}
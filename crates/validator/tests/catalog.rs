@@ -0,0 +1,38 @@
+use validator::{EnglishCatalog, KeyValueCatalog, MessageCatalog, Validate, Validator};
+
+#[derive(Validator)]
+struct SignUp {
+    #[validator(not_empty)]
+    name: String,
+    #[validator(length(min = 8), code = "password_short")]
+    password: String,
+    #[validator(email)]
+    email: String,
+}
+
+const FRENCH: &str = "
+not_empty = Ce champ est obligatoire
+password_short = Le mot de passe doit contenir au moins {min} caractères
+";
+
+#[test]
+fn renders_errors_in_a_locale() {
+    let catalog = KeyValueCatalog::new()
+        .with_messages("fr", FRENCH)
+        .unwrap()
+        .or(EnglishCatalog);
+    let errors = SignUp {
+        name: String::new(),
+        password: "secret".to_string(),
+        email: "someone".to_string(),
+    }.validate().expect_err("should be invalid");
+
+    let messages: Vec<_> = catalog.render_all("fr-FR", &errors).into_iter()
+        .map(|(path, message)| (path.to_string(), message))
+        .collect();
+    assert_eq!(messages, vec![
+        ("name".to_string(), "Ce champ est obligatoire".to_string()),
+        ("password".to_string(), "Le mot de passe doit contenir au moins 8 caractères".to_string()),
+        ("email".to_string(), "Value is not a valid email address".to_string()),
+    ]);
+}
@@ -0,0 +1,37 @@
+use validator::{AndError, CharClass, ErrorCode, InvalidCharError, Validate, Validator};
+
+#[derive(Validator)]
+struct Profile {
+    #[validator(chars(ascii, alphanumeric, extra = "-_"))]
+    username: String,
+    #[validator(and(length(min = 1), chars(printable, trimmed)))]
+    display_name: String,
+    #[validator(chars(lowercase, non_control))]
+    slug: Option<String>,
+}
+
+#[test]
+fn character_classes() {
+    Profile {
+        username: "jane_doe-42".to_string(),
+        display_name: "Jane Doë".to_string(),
+        slug: Some("jane-doë".to_string()),
+    }.validate().expect("should be valid");
+
+    let errors = Profile {
+        username: "jane.doe".to_string(),
+        display_name: "Jane Doë ".to_string(),
+        slug: Some("Jane".to_string()),
+    }.validate().expect_err("should be invalid");
+    assert_eq!(errors.username, Some(InvalidCharError::NotAllowed {
+        character: '.',
+        byte_offset: 4,
+        char_offset: 4,
+        class: CharClass::Alphanumeric,
+    }));
+    // the offsets of characters after multibyte characters differ
+    let Some(AndError::Right(display_name)) = errors.display_name else { panic!("the display name should be untrimmed") };
+    assert_eq!(display_name.code(), "chars.whitespace");
+    assert_eq!((display_name.byte_offset(), display_name.char_offset()), (9, 8));
+    assert_eq!(errors.slug.unwrap().to_string(), "character 'J' at position 0 is not allowed, expected lowercase characters");
+}
@@ -0,0 +1,73 @@
+use validator::{
+    AndError, ForbiddenValueError, IgnoreCase, MissingPrefixError, MissingSubstringError, MissingSuffixError, NotOneOfError,
+    Validate, ValidationError, Validator,
+};
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum Plan {
+    Free,
+    Team,
+    Enterprise,
+}
+
+const RESERVED: &str = "admin";
+
+#[derive(Validator)]
+struct ApiKey {
+    #[validator(starts_with = "sk_")]
+    secret: String,
+    #[validator(and(not_empty, ends_with(".example.com", ignore_case)))]
+    host: String,
+    #[validator(contains = "@", message = "the contact should be an email address")]
+    contact: Option<String>,
+    #[validator(one_of("draft", "published", ignore_case))]
+    status: String,
+    #[validator(one_of(Plan::Team, Plan::Enterprise))]
+    plan: Plan,
+    #[validator(none_of("root", RESERVED))]
+    owner: String,
+}
+
+fn api_key() -> ApiKey {
+    ApiKey {
+        secret: "sk_live_42".to_string(),
+        host: "API.Example.com".to_string(),
+        contact: None,
+        status: "Published".to_string(),
+        plan: Plan::Team,
+        owner: "someone".to_string(),
+    }
+}
+
+#[test]
+fn valid_values_have_no_errors() {
+    api_key().validate().expect("should be valid");
+}
+
+#[test]
+fn errors_include_the_expected_values() {
+    let errors = ApiKey {
+        secret: "pk_live_42".to_string(),
+        host: "example.org".to_string(),
+        contact: Some("someone".to_string()),
+        status: "archived".to_string(),
+        plan: Plan::Free,
+        owner: RESERVED.to_string(),
+    }.validate().expect_err("should be invalid");
+    // the values of `Plan` are listed although it does not implement Display
+    let messages: Vec<_> = errors.errors().into_iter()
+        .map(|(path, error)| (path.to_string(), error.to_string()))
+        .collect();
+    assert_eq!(messages.len(), 6);
+    assert!(messages.contains(&("plan".to_string(), "Value should be one of Team, Enterprise".to_string())));
+    assert_eq!(errors.secret, Some(MissingPrefixError { prefix: "sk_".to_string() }));
+    assert!(matches!(&errors.host, Some(AndError::Right(MissingSuffixError { suffix })) if suffix == ".example.com"));
+    let contact = errors.contact.unwrap();
+    assert_eq!(contact.error(), &MissingSubstringError { substring: "@".to_string() });
+    assert_eq!(contact.to_string(), "the contact should be an email address");
+    assert_eq!(errors.status, Some(NotOneOfError { allowed: IgnoreCase(["draft", "published"]) }));
+    assert_eq!(errors.plan, Some(NotOneOfError { allowed: [Plan::Team, Plan::Enterprise] }));
+    let owner = errors.owner.unwrap();
+    assert_eq!(owner, ForbiddenValueError { forbidden: ["root", RESERVED] });
+    assert_eq!(owner.to_string(), r#"Value should not be one of "root", "admin""#);
+}
@@ -0,0 +1,111 @@
+use validator::{AndError, ElementsInvalid, EmptyValueError, Validate, Validator, ValidatorWithContext};
+
+struct Tenant {
+    max_name_length: usize,
+}
+
+struct TenantName(String);
+
+struct TenantNameValidator;
+
+#[derive(Debug, PartialEq, Clone)]
+struct NameTooLong {
+    max: usize,
+}
+
+impl ValidatorWithContext<TenantName, Tenant> for TenantNameValidator {
+    type Error = NameTooLong;
+
+    fn validate_with(&self, value: &TenantName, context: &Tenant) -> Result<(), Self::Error> {
+        if value.0.len() > context.max_name_length {
+            Err(NameTooLong { max: context.max_name_length })
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl Validate for TenantName {
+    type Validator = TenantNameValidator;
+
+    fn validator() -> Self::Validator {
+        TenantNameValidator
+    }
+}
+
+#[derive(Validator)]
+#[validator(context = Tenant)]
+struct Team {
+    name: TenantName,
+    #[validator(elements)]
+    members: Vec<Member>,
+    #[validator(and(not_empty, or(email, url)))]
+    contact: String,
+    #[validator(tuple(not_empty, not_empty))]
+    tags: (String, String),
+}
+
+#[derive(Validator)]
+#[validator(context = Tenant)]
+struct Member {
+    name: TenantName,
+    address: Address,
+}
+
+// does not need a context itself, but is still usable from types which do
+#[derive(Validator)]
+struct Address {
+    #[validator(not_empty)]
+    city: String,
+}
+
+fn team(member_name: &str, city: &str) -> Team {
+    Team {
+        name: TenantName("rustaceans".to_string()),
+        members: vec![Member {
+            name: TenantName(member_name.to_string()),
+            address: Address { city: city.to_string() },
+        }],
+        contact: "team@example.com".to_string(),
+        tags: ("a".to_string(), "b".to_string()),
+    }
+}
+
+#[test]
+fn context_is_passed_to_nested_fields() {
+    let tenant = Tenant { max_name_length: 10 };
+    team("ferris", "Dublin").validate_with(&tenant).expect("should be valid");
+
+    let error = team("ferris the crab", "Dublin").validate_with(&tenant).expect_err("should be invalid");
+    assert!(error.name.is_none());
+    assert!(error.contact.is_none());
+    assert_eq!(
+        error.members.expect("members should be invalid").get(0),
+        Some(&MemberValidationErrors {
+            name: Some(NameTooLong { max: 10 }),
+            address: None,
+        })
+    );
+
+    team("ferris the crab", "Dublin")
+        .validate_with(&Tenant { max_name_length: 20 })
+        .expect("should be valid with a larger limit");
+}
+
+#[test]
+fn context_free_validators_ignore_context() {
+    let tenant = Tenant { max_name_length: 10 };
+    let error = team("ferris", "").validate_with(&tenant).expect_err("should be invalid");
+    let members: ElementsInvalid<_> = error.members.expect("members should be invalid");
+    assert_eq!(
+        members.get(0).and_then(|member| member.address.clone()),
+        Some(AddressValidationErrors { city: Some(EmptyValueError) })
+    );
+
+    let mut team = team("ferris", "Dublin");
+    team.contact = String::new();
+    let error = team.validate_with(&tenant).expect_err("should be invalid");
+    assert!(matches!(error.contact, Some(AndError::Both(EmptyValueError, _))));
+
+    Address { city: "Dublin".to_string() }.validate_with(&()).expect("should be valid");
+}
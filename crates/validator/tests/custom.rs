@@ -0,0 +1,123 @@
+use validator::{CustomError, EmptyValueError, OrError, Validate, Validator};
+
+fn no_spaces(value: &str) -> Result<(), CustomError> {
+    if value.contains(' ') {
+        Err(CustomError::new("must not contain spaces"))
+    } else {
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+struct Odd(u32);
+
+impl std::fmt::Display for Odd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} is odd", self.0)
+    }
+}
+
+impl std::error::Error for Odd {}
+
+fn even(value: &u32) -> Result<(), Odd> {
+    if value.is_multiple_of(2) {
+        Ok(())
+    } else {
+        Err(Odd(*value))
+    }
+}
+
+struct Divisible(u32);
+
+#[derive(Debug, PartialEq, Clone)]
+struct NotDivisible;
+
+impl Validator<u32> for Divisible {
+    type Error = NotDivisible;
+
+    fn validate(&self, value: &u32) -> Result<(), Self::Error> {
+        if value.is_multiple_of(self.0) {
+            Ok(())
+        } else {
+            Err(NotDivisible)
+        }
+    }
+}
+
+mod rules {
+    pub struct Limit {
+        pub max: usize,
+    }
+
+    impl Limit {
+        pub fn new(max: usize) -> Self {
+            Self { max }
+        }
+    }
+
+    impl validator::Validator<Vec<u32>> for Limit {
+        type Error = usize;
+
+        fn validate(&self, value: &Vec<u32>) -> Result<(), Self::Error> {
+            if value.len() > self.max {
+                Err(value.len())
+            } else {
+                Ok(())
+            }
+        }
+    }
+}
+
+#[derive(Validator)]
+struct Account {
+    #[validator(and(not_empty, custom = no_spaces))]
+    username: String,
+    #[validator(or(custom(even, error = Odd), with = Divisible(3)))]
+    lucky_number: u32,
+    #[validator(elements(custom(even, error = Odd)))]
+    numbers: Vec<u32>,
+    #[validator(with = rules::Limit::new(2))]
+    limited: Vec<u32>,
+    #[validator(with(rules::Limit { max: 1 }, type = rules::Limit))]
+    single: Vec<u32>,
+}
+
+fn account() -> Account {
+    Account {
+        username: "someone".to_string(),
+        lucky_number: 3,
+        numbers: vec![2, 4],
+        limited: vec![1, 2],
+        single: vec![1],
+    }
+}
+
+#[test]
+fn valid() {
+    account().validate().expect("should be valid");
+}
+
+#[test]
+fn custom_functions() {
+    let error = Account { username: "some one".to_string(), numbers: vec![2, 3, 4, 5], ..account() }
+        .validate()
+        .expect_err("should be invalid");
+    assert_eq!(error.username.expect("username should be invalid").to_string(), "must not contain spaces");
+    let numbers = error.numbers.expect("numbers should be invalid");
+    assert_eq!(numbers.iter().collect::<Vec<_>>(), vec![(1, &Odd(3)), (3, &Odd(5))]);
+
+    let error = Account { username: String::new(), ..account() }
+        .validate()
+        .expect_err("should be invalid");
+    assert_eq!(error.username, Some(validator::AndError::Left(EmptyValueError)));
+}
+
+#[test]
+fn validator_expressions() {
+    let error = Account { lucky_number: 7, limited: vec![1, 2, 3], single: vec![1, 2], ..account() }
+        .validate()
+        .expect_err("should be invalid");
+    assert_eq!(error.lucky_number, Some(OrError::new(Odd(7), NotDivisible)));
+    assert_eq!(error.limited, Some(3));
+    assert_eq!(error.single, Some(2));
+}
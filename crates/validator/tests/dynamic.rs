@@ -0,0 +1,53 @@
+use std::collections::HashMap;
+
+use validator::{
+    ContainsValidator, DynValidator, EmailValidator, EnglishCatalog, LengthValidator, MessageCatalog, PrefixValidator,
+    Validate, ValidationError, Validator, ValidatorExt,
+};
+
+#[derive(Validator)]
+struct Contact {
+    #[validator(email)]
+    email: String,
+    #[validator(length(max = 32))]
+    name: String,
+}
+
+// the rules of each customer, as read from their configuration
+fn rules(config: &[(&str, &str)]) -> DynValidator<String> {
+    DynValidator::all(config.iter().map(|&(rule, argument)| match rule {
+        "email" => DynValidator::new(EmailValidator),
+        "max_length" => DynValidator::new(LengthValidator::new(None, Some(argument.parse().unwrap()))),
+        "domain" => DynValidator::new(ContainsValidator::new(format!("@{argument}")).with_code("domain")),
+        "prefix" => DynValidator::new(PrefixValidator::new(argument)),
+        _ => panic!("unknown rule {rule}"),
+    }))
+}
+
+#[test]
+fn assembles_rules_at_runtime() {
+    let customers = HashMap::from([
+        ("acme", rules(&[("email", ""), ("domain", "acme.com")])),
+        ("globex", rules(&[("max_length", "8"), ("prefix", "gx-")])),
+    ]);
+    customers["acme"].validate(&"jane@acme.com".to_string()).expect("should be valid");
+    customers["globex"].validate(&"gx-jane".to_string()).expect("should be valid");
+
+    let failure = customers["acme"].validate(&"jane@globex.com".to_string()).unwrap_err();
+    let codes: Vec<_> = failure.errors().into_iter().map(|(_, error)| error.code().to_string()).collect();
+    assert_eq!(codes, ["domain"]);
+
+    let failure = customers["globex"].validate(&"jane@acme.com".to_string()).unwrap_err();
+    let messages: Vec<_> = failure.errors().into_iter().map(|(_, error)| EnglishCatalog.render("en", error)).collect();
+    assert_eq!(messages, ["value of length 13 exceeds maximum of 8", "Value should start with gx-"]);
+}
+
+#[test]
+fn erases_derived_validators() {
+    let validator: DynValidator<Contact> = DynValidator::new(Contact::validator());
+    let failure = validator.validate(&Contact { email: "jane".to_string(), name: "Jane".to_string() }).unwrap_err();
+    assert_eq!(failure.to_string(), "email: No '@' character was found in the given address");
+
+    let error: Box<dyn std::error::Error + Send + Sync> = Box::new(failure);
+    assert_eq!(error.to_string(), "email: No '@' character was found in the given address");
+}
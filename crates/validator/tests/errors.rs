@@ -0,0 +1,139 @@
+use validator::{Validate, ValidationError, Validator};
+
+#[derive(Validator)]
+struct Kennel {
+    #[validator(not_empty)]
+    name: String,
+    #[validator(elements)]
+    dogs: Vec<Dog>,
+    owner: Owner,
+}
+
+#[derive(Validator)]
+struct Dog {
+    #[validator(length(min = 2))]
+    name: String,
+    #[validator(tuple(not_empty, email))]
+    tags: (String, String),
+}
+
+#[derive(Validator)]
+enum Owner {
+    Person {
+        #[validator(email)]
+        r#mail: String,
+    },
+    Company(#[validator(url)] String),
+}
+
+fn paths<E: ValidationError>(error: &E) -> Vec<(String, String)> {
+    error.errors()
+        .into_iter()
+        .map(|(path, _)| (path.to_string(), path.to_json_pointer()))
+        .collect()
+}
+
+fn dog(name: &str, email: &str) -> Dog {
+    Dog { name: name.to_string(), tags: ("tag".to_string(), email.to_string()) }
+}
+
+#[test]
+fn nested_errors_are_flattened_with_their_paths() {
+    let kennel = Kennel {
+        name: String::new(),
+        dogs: vec![
+            dog("Rex", "rex@example.com"),
+            dog("R", "rex@example.com"),
+            dog("Fido", "rex@example.com"),
+            dog("F", "not an email"),
+        ],
+        owner: Owner::Person { mail: "nobody".to_string() },
+    };
+    let error = kennel.validate().expect_err("should be invalid");
+    assert_eq!(paths(&error), vec![
+        ("name".to_string(), "/name".to_string()),
+        ("dogs[1].name".to_string(), "/dogs/1/name".to_string()),
+        ("dogs[3].name".to_string(), "/dogs/3/name".to_string()),
+        ("dogs[3].tags[1]".to_string(), "/dogs/3/tags/1".to_string()),
+        ("owner.mail".to_string(), "/owner/mail".to_string()),
+    ]);
+}
+
+#[test]
+fn leaf_errors_are_reported() {
+    let kennel = Kennel {
+        name: "Paws".to_string(),
+        dogs: vec![],
+        owner: Owner::Company("not a url".to_string()),
+    };
+    let error = kennel.validate().expect_err("should be invalid");
+    let errors = error.errors();
+    assert_eq!(errors.len(), 1);
+    let (path, error) = &errors[0];
+    assert_eq!(path.to_string(), "owner[0]");
+    assert_eq!(error.to_string(), "relative URL without a base");
+}
+
+#[test]
+fn valid_values_have_no_errors() {
+    Kennel {
+        name: "Paws".to_string(),
+        dogs: vec![dog("Rex", "rex@example.com")],
+        owner: Owner::Company("https://example.com".to_string()),
+    }.validate().expect("should be valid");
+}
+
+#[test]
+fn errors_are_displayed_by_field() {
+    let kennel = Kennel {
+        name: String::new(),
+        dogs: vec![dog("Rex", "rex@example.com"), dog("R", "not an email")],
+        owner: Owner::Company("https://example.com".to_string()),
+    };
+    let error = kennel.validate().expect_err("should be invalid");
+    assert_eq!(
+        error.to_string(),
+        "name: Value should not be empty; dogs: [1]: name: value of length 1 falls short of minimum of 2; tags: [1]: No '@' character was found in the given address",
+    );
+}
+
+#[test]
+fn errors_can_be_boxed() {
+    fn validate(kennel: &Kennel) -> Result<(), Box<dyn std::error::Error>> {
+        kennel.validate()?;
+        Ok(())
+    }
+
+    let error = validate(&Kennel {
+        name: "Paws".to_string(),
+        dogs: vec![],
+        owner: Owner::Person { mail: "nobody".to_string() },
+    }).expect_err("should be invalid");
+    assert_eq!(error.to_string(), "owner: mail: No '@' character was found in the given address");
+    let source = error.source().expect("the invalid field should be the source");
+    assert_eq!(source.to_string(), "mail: No '@' character was found in the given address");
+    let source = source.source().expect("the invalid field should be the source");
+    assert_eq!(source.to_string(), "No '@' character was found in the given address");
+}
+
+#[derive(Validator)]
+struct Mailing {
+    #[validator(elements(email))]
+    recipients: std::collections::HashSet<String>,
+}
+
+#[test]
+fn elements_of_sets_are_reported_by_value() {
+    let recipients = ["jane@example.com", "joe", "jim@example.com", "ann"].map(String::from).into();
+    let error = Mailing { recipients }.validate().expect_err("should be invalid");
+    let recipients = error.recipients.as_ref().expect("recipients should be invalid");
+    assert!(recipients.get("joe").is_some());
+    assert!(recipients.get("jane@example.com").is_none());
+
+    let mut paths = paths(&error);
+    paths.sort();
+    assert_eq!(paths, vec![
+        ("recipients.ann".to_string(), "/recipients/ann".to_string()),
+        ("recipients.joe".to_string(), "/recipients/joe".to_string()),
+    ]);
+}
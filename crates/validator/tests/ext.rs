@@ -0,0 +1,81 @@
+use validator::{
+    AndError, ContainsValidator, CustomError, EmailValidator, ErrorCode, InvalidEmailError, LengthValidator,
+    NotEmptyValidator, Validate, ValidationError, Validator, ValidatorExt,
+};
+
+#[test]
+fn combines_validators_at_runtime() {
+    let max_len = 32;
+    let validator = NotEmptyValidator
+        .then(LengthValidator::new(None, Some(max_len)).and(EmailValidator))
+        .with_code("contact_email");
+    validator.validate(&"someone@example.com".to_string()).expect("should be valid");
+    let error = validator.validate(&"someone".to_string()).unwrap_err();
+    assert_eq!(error.code(), "contact_email");
+    assert_eq!(error.errors().len(), 1);
+
+    let validator = ContainsValidator::new("..").not().or(EmailValidator);
+    validator.validate(&"docs/index.md".to_string()).expect("should be valid");
+    validator.validate(&"../secrets".to_string()).expect_err("should be invalid");
+}
+
+#[test]
+fn wraps_options_and_collections() {
+    let validator = EmailValidator.each().optional();
+    validator.validate(&None::<Vec<String>>).expect("should be valid");
+    validator.validate(&Some(vec!["someone@example.com".to_string()])).expect("should be valid");
+    let error = validator.validate(&Some(vec!["someone@example.com".to_string(), "someone".to_string()])).unwrap_err();
+    assert_eq!(error.get(1), Some(&InvalidEmailError::ATNotFound));
+    assert_eq!(error.get(0), None);
+}
+
+#[test]
+fn maps_errors_of_conditional_rules() {
+    let validator = LengthValidator::new(Some(8), None)
+        .and(ContainsValidator::new("-"))
+        .when(|value: &String| !value.starts_with("legacy:"))
+        .map_err(|error| match error {
+            AndError::Both(..) => "both",
+            AndError::Left(_) => "too short",
+            AndError::Right(_) => "no dash",
+        });
+    validator.validate(&"legacy:1".to_string()).expect("should be valid");
+    assert_eq!(validator.validate(&"short".to_string()), Err("both"));
+    assert_eq!(validator.validate(&"no-dash".to_string()), Err("too short"));
+    assert_eq!(validator.validate(&"long enough".to_string()), Err("no dash"));
+}
+
+#[derive(Validator)]
+struct Contact {
+    #[validator(email)]
+    email: String,
+}
+
+// a validator of this crate's users, which opts into the combinators
+struct Lowercase;
+
+impl Validator<str> for Lowercase {
+    type Error = CustomError;
+
+    fn validate(&self, value: &str) -> Result<(), Self::Error> {
+        if value.chars().any(char::is_uppercase) {
+            Err(CustomError::new("should be lowercase"))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl ValidatorExt for Lowercase {}
+
+#[test]
+fn combines_derived_and_custom_validators() {
+    let validator = Contact::validator().each();
+    validator.validate(&vec![Contact { email: "jane@example.com".to_string() }]).expect("should be valid");
+    let error = validator.validate(&vec![Contact { email: "jane".to_string() }]).unwrap_err();
+    assert!(error.get(0).expect("should be invalid").email.is_some());
+
+    let validator = Lowercase.and(EmailValidator).optional();
+    validator.validate(&Some("jane@example.com".to_string())).expect("should be valid");
+    assert_eq!(validator.validate(&Some("Jane".to_string())).unwrap_err().errors().len(), 2);
+}
@@ -0,0 +1,75 @@
+use validator::{Validate, ValidationError, Validator};
+
+#[derive(Validator, Debug, PartialEq, Clone)]
+struct Tag {
+    #[validator(length(min = 2))]
+    name: String,
+}
+
+#[derive(Validator)]
+struct Page<T> where T: Clone {
+    #[validator(elements)]
+    items: Vec<T>,
+    #[validator(range(1..=100))]
+    size: u32,
+}
+
+#[derive(Validator)]
+struct Request<'a> {
+    #[validator(length(min = 1))]
+    name: &'a str,
+    #[validator(ignore)]
+    body: &'a [u8],
+}
+
+#[derive(Validator)]
+enum Either<L, R> {
+    Left(#[validator(elements)] Vec<L>),
+    Right(R),
+}
+
+#[derive(Validator)]
+#[validator(bound = "T: Validate, T::Validator: Validator<T>")]
+struct Wrapper<T> {
+    #[validator(elements)]
+    items: Vec<T>,
+}
+
+fn tag(name: &str) -> Tag {
+    Tag { name: name.to_string() }
+}
+
+#[test]
+fn type_parameters() {
+    Page { items: vec![tag("rust")], size: 10 }.validate().expect("should be valid");
+    let error: PageValidationErrors<Tag> = Page { items: vec![tag("rust"), tag("r")], size: 0 }
+        .validate()
+        .expect_err("should be invalid");
+    assert!(error.size.is_some());
+    let paths: Vec<_> = error.errors().into_iter().map(|(path, _)| path.to_string()).collect();
+    assert_eq!(paths, vec!["items[1].name", "size"]);
+}
+
+#[test]
+fn lifetime_parameters() {
+    Request { name: "upload", body: b"data" }.validate().expect("should be valid");
+    let error = Request { name: "", body: b"data" }.validate().expect_err("should be invalid");
+    assert_eq!(error.to_string(), "name: value of length 0 falls short of minimum of 1");
+}
+
+#[test]
+fn generic_enums() {
+    Either::<Tag, Tag>::Right(tag("rust")).validate().expect("should be valid");
+    let error = Either::<Tag, Tag>::Left(vec![tag("r")]).validate().expect_err("should be invalid");
+    assert_eq!(error.to_string(), "0: [0]: name: value of length 1 falls short of minimum of 2");
+}
+
+#[test]
+fn bounds_can_be_set() {
+    Wrapper { items: vec![tag("rust")] }.validate().expect("should be valid");
+    let error = Wrapper { items: vec![tag("r"), tag("rust")] }.validate().expect_err("should be invalid");
+    let items = error.items.as_ref().expect("items should be invalid");
+    assert_eq!(items.iter().map(|(index, _)| index).collect::<Vec<_>>(), [0]);
+    let codes: Vec<_> = error.errors().into_iter().map(|(path, error)| (path.to_string(), error.code().to_string())).collect();
+    assert_eq!(codes, [("items[0].name".to_string(), "length.too_short".to_string())]);
+}
@@ -0,0 +1,182 @@
+#![cfg(feature = "json-schema")]
+
+use serde_json::json;
+use validator::{JsonSchema, Validator};
+
+#[derive(Validator)]
+struct SignUp {
+    #[validator(length(min = 3, max = 20, unit = "chars"))]
+    username: String,
+    #[validator(elements(email))]
+    emails: Vec<String>,
+    #[validator(range(18..120))]
+    age: u8,
+    #[validator(url)]
+    website: Option<String>,
+    #[validator(or(ip, not_empty))]
+    server: String,
+    #[validator(and(length(min = 1), elements))]
+    addresses: Vec<Address>,
+    #[validator(tuple(not_empty, range(1..=10)))]
+    rating: (String, u32),
+}
+
+#[derive(Validator)]
+struct Address {
+    #[validator(not_empty)]
+    city: String,
+    #[validator(required)]
+    country: Option<Country>,
+}
+
+#[derive(Validator)]
+enum Country {
+    Other,
+    Code(#[validator(length(equal = 2, unit = "chars"))] String),
+}
+
+#[test]
+fn generates_schema() {
+    assert_eq!(SignUp::root_json_schema(), json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "type": "object",
+        "properties": {
+            "username": { "type": "string", "minLength": 3, "maxLength": 20 },
+            "emails": { "type": "array", "items": { "type": "string", "format": "email" } },
+            "age": { "type": "integer", "minimum": 18, "exclusiveMaximum": 120 },
+            "website": { "anyOf": [{ "type": "string", "format": "uri" }, { "type": "null" }] },
+            "server": {
+                "type": "string",
+                "anyOf": [{ "type": "string", "anyOf": [{ "type": "string", "format": "ipv4" }, { "type": "string", "format": "ipv6" }] }, { "type": "string", "minLength": 1 }],
+            },
+            "addresses": { "type": "array", "items": { "$ref": "#/$defs/Address" }, "minItems": 1 },
+            "rating": {
+                "type": "array",
+                "prefixItems": [{ "type": "string", "minLength": 1 }, { "type": "integer", "minimum": 1, "maximum": 10 }],
+                "items": false,
+                "minItems": 2,
+                "maxItems": 2,
+            },
+        },
+        "required": ["username", "emails", "age", "server", "addresses", "rating"],
+        "$defs": {
+            "Address": {
+                "type": "object",
+                "properties": {
+                    "city": { "type": "string", "minLength": 1 },
+                    "country": { "$ref": "#/$defs/Country" },
+                },
+                "required": ["city", "country"],
+            },
+            "Country": {
+                "oneOf": [
+                    { "const": "Other" },
+                    {
+                        "type": "object",
+                        "properties": { "Code": { "type": "string", "minLength": 2, "maxLength": 2 } },
+                        "required": ["Code"],
+                        "additionalProperties": false,
+                    },
+                ],
+            },
+        },
+    }));
+}
+
+#[test]
+fn unit_variants_before_variants_with_fields() {
+    use validator::Validate;
+
+    Country::Other.validate().expect("should be valid");
+    Country::Code("FRA".to_string()).validate().expect_err("should be invalid");
+}
+
+#[derive(Validator)]
+struct Profile {
+    // JSON Schema measures strings in characters rather than in bytes
+    #[validator(length(max = 5))]
+    name: String,
+    #[validator(length(max = 5, unit = "chars"))]
+    nickname: String,
+    #[validator(not(length(max = 2)))]
+    bio: String,
+    #[validator(length(max = 2, unit = "utf16"))]
+    tags: Vec<String>,
+}
+
+#[test]
+fn lengths_in_characters() {
+    use validator::Validate;
+
+    assert_eq!(Profile::root_json_schema()["properties"], json!({
+        "name": { "type": "string" },
+        "nickname": { "type": "string", "maxLength": 5 },
+        "bio": { "type": "string" },
+        "tags": { "type": "array", "items": { "type": "string" }, "maxItems": 2 },
+    }));
+
+    // "Renée" has 5 characters but 7 bytes, so that a maximum length in bytes cannot be described as `maxLength`
+    let profile = Profile { name: "Zoé".to_string(), nickname: "Renée".to_string(), bio: "Hi!".to_string(), tags: vec![] };
+    profile.validate().expect("should be valid");
+    let errors = Profile { name: "Renée".to_string(), ..profile }.validate().unwrap_err();
+    assert!(errors.name.is_some());
+    assert!(errors.nickname.is_none());
+}
+
+#[derive(Validator)]
+struct Article {
+    #[validator(one_of("draft", "published"))]
+    status: String,
+    #[validator(none_of(0, 13))]
+    floor: u8,
+    // the case cannot be ignored by JSON Schema
+    #[validator(one_of("en", "fr", ignore_case))]
+    language: String,
+}
+
+#[test]
+fn allowed_values() {
+    assert_eq!(Article::root_json_schema(), json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "type": "object",
+        "properties": {
+            "status": { "type": "string", "enum": ["draft", "published"] },
+            "floor": { "type": "integer", "minimum": 0, "not": { "enum": [0, 13] } },
+            "language": { "type": "string" },
+        },
+        "required": ["status", "floor", "language"],
+    }));
+}
+
+#[derive(Validator)]
+struct Server {
+    #[validator(all(length(min = 3, unit = "chars"), not(one_of("localhost"))))]
+    host: String,
+    #[validator(exactly_one(range(..1024), range(8000..9000)))]
+    port: u16,
+    // a rule which cannot be described may pass any value, so the alternatives are left out
+    #[validator(any(ip, custom = is_known_alias))]
+    address: String,
+}
+
+fn is_known_alias(_: &str) -> Result<(), validator::CustomError> {
+    Ok(())
+}
+
+#[test]
+fn combinators() {
+    assert_eq!(Server::root_json_schema(), json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "type": "object",
+        "properties": {
+            "host": { "type": "string", "minLength": 3, "not": { "type": "string", "enum": ["localhost"] } },
+            "port": {
+                "type": "integer",
+                "minimum": 0,
+                "oneOf": [{ "type": "integer", "exclusiveMaximum": 1024 }, { "type": "integer", "minimum": 8000, "exclusiveMaximum": 9000 }],
+            },
+            "address": { "type": "string" },
+        },
+        "required": ["host", "port", "address"],
+    }));
+}
@@ -0,0 +1,96 @@
+#![cfg(feature = "json-schema")]
+
+use serde_json::json;
+use validator::{
+    InvalidLengthError, JsonSchema, JsonSchemaValidator, LengthUnit, MissingValueError, ValidationError, Validator,
+};
+
+fn partner_schema() -> JsonSchemaValidator {
+    JsonSchemaValidator::compile(&json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "type": "object",
+        "properties": {
+            "sku": { "type": "string", "pattern": "^[A-Z]{3}-[0-9]+$" },
+            "name": { "type": "string", "minLength": 1, "maxLength": 40 },
+            "price": { "type": "number", "exclusiveMinimum": 0, "maximum": 10000 },
+            "status": { "enum": ["draft", "published"] },
+            "contact": { "anyOf": [{ "type": "string", "format": "email" }, { "type": "string", "format": "uri" }] },
+            "tags": { "type": "array", "maxItems": 3, "items": { "$ref": "#/$defs/tag" } },
+        },
+        "required": ["sku", "name", "price"],
+        "allOf": [{ "required": ["status"] }],
+        "$defs": {
+            "tag": { "type": "string", "minLength": 2 },
+        },
+    })).expect("should compile")
+}
+
+#[test]
+fn validates_payloads() {
+    partner_schema().validate(&json!({
+        "sku": "ABC-42",
+        "name": "Chair",
+        "price": 49.5,
+        "status": "draft",
+        "contact": "https://example.com",
+        "tags": ["wood", "oak"],
+    })).expect("should be valid");
+}
+
+#[test]
+fn reports_errors_by_path() {
+    let errors = partner_schema().validate(&json!({
+        "sku": "abc",
+        "name": "",
+        "price": 0,
+        "contact": "someone",
+        "tags": ["wood", "x", 3],
+    })).expect_err("should be invalid");
+
+    let name = errors.property("name").expect("name should be invalid");
+    assert_eq!(name.errors()[0].to_string(), InvalidLengthError::TooShort { min: 1, len: 0, unit: Some(LengthUnit::Chars) }.to_string());
+    assert_eq!(errors.property("status").unwrap().errors()[0].to_string(), MissingValueError.to_string());
+
+    let errors: Vec<_> = ValidationError::errors(&errors).into_iter()
+        .map(|(path, error)| (path.to_string(), error.code().to_string()))
+        .collect();
+    // the properties are checked in the order of the payload, which serde_json sorts
+    assert_eq!(errors, [
+        ("contact".to_string(), "email".to_string()),
+        ("contact".to_string(), "url".to_string()),
+        ("name".to_string(), "length.too_short".to_string()),
+        ("price".to_string(), "range".to_string()),
+        ("sku".to_string(), "pattern".to_string()),
+        ("tags[1]".to_string(), "length.too_short".to_string()),
+        ("tags[2]".to_string(), "type".to_string()),
+        ("status".to_string(), "required".to_string()),
+    ]);
+}
+
+#[derive(validator::Validator)]
+struct SignUp {
+    #[validator(length(min = 3, max = 20, unit = "chars"))]
+    username: String,
+    #[validator(elements(email))]
+    emails: Vec<String>,
+    #[validator(range(18..120))]
+    age: u8,
+    address: Option<Address>,
+}
+
+#[derive(validator::Validator)]
+struct Address {
+    #[validator(not_empty)]
+    city: String,
+}
+
+#[test]
+fn validates_against_generated_schemas() {
+    let validator = JsonSchemaValidator::compile(&SignUp::root_json_schema()).expect("should compile");
+    validator.validate(&json!({ "username": "someone", "emails": [], "age": 30, "address": null })).expect("should be valid");
+    let errors = validator.validate(&json!({ "username": "so", "emails": ["someone"], "age": 12, "address": { "city": "" } }))
+        .expect_err("should be invalid");
+    let paths: Vec<_> = ValidationError::errors(&errors).into_iter().map(|(path, _)| path.to_string()).collect();
+    // the address is neither a valid address nor null
+    assert_eq!(paths, ["address.city", "address", "age", "emails[0]", "username"]);
+}
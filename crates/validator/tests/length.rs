@@ -0,0 +1,43 @@
+use validator::{InvalidLengthError, LengthUnit, Validate, Validator};
+
+#[derive(Validator)]
+struct User {
+    #[validator(length(max = 10, unit = "chars"))]
+    name: String,
+    #[validator(length(max = 16, unit = "utf16"))]
+    nickname: String,
+    #[validator(length(max = 32))]
+    bio: String,
+}
+
+#[test]
+fn units() {
+    User {
+        name: "山田太郎さん".to_string(),
+        nickname: "やまだ".to_string(),
+        bio: String::new(),
+    }.validate().expect("should be valid");
+
+    let errors = User {
+        name: "山田太郎さん山田太郎さん".to_string(),
+        nickname: "😀".repeat(9),
+        bio: "山田太郎さん山田太郎さん".to_string(),
+    }.validate().expect_err("should be invalid");
+    assert_eq!(errors.name, Some(InvalidLengthError::TooLong { max: 10, len: 12, unit: Some(LengthUnit::Chars) }));
+    assert_eq!(errors.nickname, Some(InvalidLengthError::TooLong { max: 16, len: 18, unit: Some(LengthUnit::Utf16) }));
+    assert_eq!(errors.bio, Some(InvalidLengthError::TooLong { max: 32, len: 36, unit: None }));
+}
+
+#[cfg(feature = "graphemes")]
+#[test]
+fn graphemes() {
+    #[derive(Validator)]
+    struct Reaction {
+        #[validator(length(max = 1, unit = "graphemes"))]
+        emoji: String,
+    }
+
+    Reaction { emoji: "👨‍👩‍👧".to_string() }.validate().expect("should be valid");
+    let errors = Reaction { emoji: "👍👍".to_string() }.validate().expect_err("should be invalid");
+    assert_eq!(errors.emoji, Some(InvalidLengthError::TooLong { max: 1, len: 2, unit: Some(LengthUnit::Graphemes) }));
+}
@@ -0,0 +1,52 @@
+use std::collections::{BTreeMap, HashMap};
+
+use validator::{EntryError, Validate, ValidationError, Validator};
+
+#[derive(Validator)]
+struct Config {
+    settings: HashMap<String, Setting>,
+    #[validator(keys(length(max = 8)))]
+    aliases: BTreeMap<String, String>,
+    #[validator(values(range(1..=10)))]
+    weights: BTreeMap<String, u32>,
+    #[validator(entries(not_empty, email))]
+    contacts: BTreeMap<String, String>,
+}
+
+#[derive(Validator)]
+struct Setting {
+    #[validator(not_empty)]
+    value: String,
+}
+
+fn config() -> Config {
+    Config {
+        settings: [("theme".to_string(), Setting { value: "dark".to_string() })].into_iter().collect(),
+        aliases: [("home".to_string(), "/".to_string())].into_iter().collect(),
+        weights: [("a".to_string(), 3)].into_iter().collect(),
+        contacts: [("jane".to_string(), "jane@example.com".to_string())].into_iter().collect(),
+    }
+}
+
+#[test]
+fn valid() {
+    config().validate().expect("should be valid");
+}
+
+#[test]
+fn reports_entries_by_key() {
+    let mut config = config();
+    config.settings.insert("locale".to_string(), Setting { value: String::new() });
+    config.aliases.insert("dashboard".to_string(), "/dashboard".to_string());
+    config.weights.insert("b".to_string(), 20);
+    config.contacts.insert(String::new(), "nobody".to_string());
+
+    let errors = config.validate().expect_err("should be invalid");
+    assert!(errors.settings.as_ref().unwrap().get("locale").is_some());
+    assert!(errors.settings.as_ref().unwrap().get("theme").is_none());
+    assert!(errors.aliases.as_ref().unwrap().get("dashboard").is_some());
+    assert!(matches!(errors.contacts.as_ref().unwrap().get(""), Some(EntryError::Both(_, _))));
+
+    let paths: Vec<_> = errors.errors().into_iter().map(|(path, _)| path.to_string()).collect();
+    assert_eq!(paths, vec!["settings.locale.value", "aliases.dashboard", "weights.b", "contacts.", "contacts."]);
+}
@@ -0,0 +1,44 @@
+use validator::{ErrorCode, Validate, ValidationError, Validator};
+
+#[derive(Validator)]
+struct SignUp {
+    #[validator(length(min = 8), code = "password_short", message = "Use at least {min} characters")]
+    password: String,
+    #[validator(range(18..), message = "You must be at least {start} years old")]
+    age: u32,
+    #[validator(and(not_empty, code = "name_missing", length(max = 5, unit = "chars")))]
+    name: String,
+    #[validator(elements(email, code = "invalid_email"))]
+    emails: Vec<String>,
+}
+
+#[test]
+fn codes_and_messages() {
+    let errors = SignUp {
+        password: "secret".to_string(),
+        age: 12,
+        name: String::new(),
+        emails: vec!["someone".to_string()],
+    }.validate().expect_err("should be invalid");
+
+    let password = errors.password.as_ref().unwrap();
+    assert_eq!(password.code(), "password_short");
+    assert_eq!(password.message(), "Use at least 8 characters");
+
+    let age = errors.age.as_ref().unwrap();
+    assert_eq!(age.code(), "range");
+    assert_eq!(age.message(), "You must be at least 18 years old");
+
+    assert_eq!(errors.name.as_ref().unwrap().code(), "name_missing");
+
+    let messages: Vec<_> = errors.errors().into_iter()
+        .map(|(path, error)| (path.to_string(), error.to_string()))
+        .collect();
+    assert_eq!(messages, vec![
+        ("password".to_string(), "Use at least 8 characters".to_string()),
+        ("age".to_string(), "You must be at least 18 years old".to_string()),
+        ("name".to_string(), "Value should not be empty".to_string()),
+        ("emails[0]".to_string(), "No '@' character was found in the given address".to_string()),
+    ]);
+    assert_eq!(errors.emails.unwrap().get(0).unwrap().code(), "invalid_email");
+}
@@ -0,0 +1,68 @@
+use validator::{MissingValueError, Validate, Validator};
+
+#[derive(Validator)]
+struct Profile {
+    #[validator(email)]
+    email: Option<String>,
+    #[validator(and(required, length(min = 2)))]
+    name: Option<String>,
+    #[validator(range(18..))]
+    age: Option<u32>,
+    #[validator(elements(not_empty))]
+    tags: Option<Vec<String>>,
+    address: Option<Address>,
+}
+
+#[derive(Validator)]
+struct Address {
+    #[validator(not_empty)]
+    city: String,
+}
+
+fn profile() -> Profile {
+    Profile {
+        email: None,
+        name: Some("Jane".to_string()),
+        age: None,
+        tags: None,
+        address: None,
+    }
+}
+
+#[test]
+fn none_is_valid() {
+    profile().validate().expect("should be valid");
+}
+
+#[test]
+fn some_is_validated() {
+    Profile {
+        email: Some("jane@example.com".to_string()),
+        age: Some(30),
+        tags: Some(vec!["admin".to_string()]),
+        address: Some(Address { city: "Dublin".to_string() }),
+        ..profile()
+    }.validate().expect("should be valid");
+
+    let errors = Profile {
+        email: Some("jane".to_string()),
+        age: Some(12),
+        tags: Some(vec!["".to_string()]),
+        address: Some(Address { city: "".to_string() }),
+        ..profile()
+    }.validate().expect_err("should be invalid");
+    assert!(errors.email.is_some());
+    assert!(errors.age.is_some());
+    assert!(errors.tags.is_some());
+    assert!(errors.address.is_some());
+    assert!(errors.name.is_none());
+}
+
+#[test]
+fn required() {
+    let errors = Profile {
+        name: None,
+        ..profile()
+    }.validate().expect_err("should be invalid");
+    assert_eq!(errors.name, Some(validator::AndError::Left(MissingValueError)));
+}
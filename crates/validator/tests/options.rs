@@ -0,0 +1,161 @@
+use validator::{Validate, ValidationError, ValidationOptions, Validator};
+
+#[derive(Validator)]
+struct Order {
+    #[validator(not_empty)]
+    id: String,
+    #[validator(email)]
+    contact: String,
+}
+
+#[derive(Validator)]
+struct Cart {
+    #[validator(not_empty)]
+    owner: String,
+    #[validator(elements)]
+    orders: Vec<Order>,
+}
+
+#[derive(Validator)]
+#[validator(fail_fast)]
+struct FailFastCart {
+    #[validator(not_empty)]
+    owner: String,
+    #[validator(elements)]
+    orders: Vec<Order>,
+}
+
+#[derive(Validator)]
+#[validator(max_errors = 2)]
+struct LimitedCart {
+    #[validator(not_empty)]
+    owner: String,
+    #[validator(elements)]
+    orders: Vec<Order>,
+}
+
+#[derive(Validator)]
+struct Profile {
+    #[validator(or(elements(email), elements(url)))]
+    contacts: Vec<String>,
+    #[validator(or(email, url))]
+    website: String,
+    #[validator(not_empty)]
+    name: String,
+}
+
+#[derive(Validator)]
+struct Channels {
+    #[validator(any(elements(email), elements(url)))]
+    contacts: Vec<String>,
+    #[validator(any(email, url))]
+    website: String,
+    #[validator(not_empty)]
+    name: String,
+}
+
+fn invalid_orders() -> Vec<Order> {
+    (0..4).map(|_| Order { id: String::new(), contact: "not an email".to_string() }).collect()
+}
+
+#[test]
+fn all_errors_are_reported_by_default() {
+    let errors = Cart { owner: String::new(), orders: invalid_orders() }
+        .validate()
+        .expect_err("should be invalid");
+    assert!(errors.owner.is_some());
+    let orders = errors.orders.expect("orders should be invalid");
+    assert_eq!(orders.iter().count(), 4);
+    assert!(orders.iter().all(|(_, order)| order.id.is_some() && order.contact.is_some()));
+}
+
+#[test]
+fn fail_fast_stops_at_the_first_error() {
+    let errors = Cart { owner: String::new(), orders: invalid_orders() }
+        .validate_with_options(ValidationOptions::fail_fast())
+        .expect_err("should be invalid");
+    assert!(errors.owner.is_some());
+    assert_eq!(errors.orders, None);
+
+    let errors = Cart { owner: "owner".to_string(), orders: invalid_orders() }
+        .validate_with_options(ValidationOptions::fail_fast())
+        .expect_err("should be invalid");
+    let orders = errors.orders.expect("orders should be invalid");
+    assert_eq!(orders.iter().count(), 1);
+    let first = orders.get(0).expect("first order should be invalid");
+    assert!(first.id.is_some());
+    assert_eq!(first.contact, None);
+}
+
+#[test]
+fn max_errors_is_shared_across_nested_values() {
+    let errors = Cart { owner: String::new(), orders: invalid_orders() }
+        .validate_with_options(ValidationOptions::max_errors(4))
+        .expect_err("should be invalid");
+    assert!(errors.owner.is_some());
+    let orders = errors.orders.expect("orders should be invalid");
+    let reported: Vec<_> = orders.iter().map(|(index, _)| index).collect();
+    assert_eq!(reported, vec![0, 1]);
+    assert!(orders.get(1).is_some_and(|order| order.id.is_some() && order.contact.is_none()));
+    assert_eq!(orders.get(2), None);
+}
+
+#[test]
+fn valid_values_pass_with_any_options() {
+    let cart = Cart {
+        owner: "owner".to_string(),
+        orders: vec![Order { id: "1".to_string(), contact: "someone@example.com".to_string() }],
+    };
+    cart.validate_with_options(ValidationOptions::fail_fast()).expect("should be valid");
+    cart.validate_with_options(ValidationOptions::max_errors(3)).expect("should be valid");
+}
+
+#[test]
+fn container_options_set_the_default_budget() {
+    let errors = FailFastCart { owner: String::new(), orders: invalid_orders() }
+        .validate()
+        .expect_err("should be invalid");
+    assert!(errors.owner.is_some());
+    assert_eq!(errors.orders, None);
+
+    let errors = LimitedCart { owner: String::new(), orders: invalid_orders() }
+        .validate()
+        .expect_err("should be invalid");
+    assert!(errors.owner.is_some());
+    let orders = errors.orders.expect("orders should be invalid");
+    assert_eq!(orders.iter().map(|(index, _)| index).collect::<Vec<_>>(), vec![0]);
+}
+
+#[test]
+fn budget_is_passed_to_the_alternatives() {
+    let contacts = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+    let errors = Profile { contacts, website: "https://example.com".to_string(), name: String::new() }
+        .validate_with_options(ValidationOptions::max_errors(2))
+        .expect_err("should be invalid");
+    // each alternative stops after two errors, which exhaust the budget before the name is validated
+    let paths: Vec<_> = errors.errors().into_iter().map(|(path, _)| path.to_string()).collect();
+    assert_eq!(paths, ["contacts[0]", "contacts[1]", "contacts[0]", "contacts[1]"]);
+    assert_eq!(errors.name, None);
+
+    // an alternative failing before the other one passes does not spend the budget
+    let errors = Profile { contacts: vec![], website: "https://example.com".to_string(), name: String::new() }
+        .validate_with_options(ValidationOptions::fail_fast())
+        .expect_err("should be invalid");
+    assert!(errors.name.is_some());
+}
+
+#[test]
+fn budget_is_passed_to_the_variadic_alternatives() {
+    let contacts = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+    let errors = Channels { contacts, website: "https://example.com".to_string(), name: String::new() }
+        .validate_with_options(ValidationOptions::max_errors(2))
+        .expect_err("should be invalid");
+    let paths: Vec<_> = errors.errors().into_iter().map(|(path, _)| path.to_string()).collect();
+    assert_eq!(paths, ["contacts[0]", "contacts[1]", "contacts[0]", "contacts[1]"]);
+    assert_eq!(errors.name, None);
+
+    let errors = Channels { contacts: vec![], website: "https://example.com".to_string(), name: String::new() }
+        .validate_with_options(ValidationOptions::fail_fast())
+        .expect_err("should be invalid");
+    assert!(errors.name.is_some());
+}
@@ -0,0 +1,58 @@
+use validator::{InvalidParam, ProblemDetails, Validate, Validator};
+
+#[derive(Validator)]
+struct Order {
+    #[validator(elements)]
+    lines: Vec<Line>,
+    #[validator(tuple(not_empty, range(1..=10)))]
+    rating: (String, u32),
+}
+
+#[derive(Validator)]
+struct Line {
+    #[validator(not_empty)]
+    product: String,
+    #[validator(range(1..))]
+    quantity: u32,
+}
+
+fn invalid_order() -> Order {
+    Order {
+        lines: vec![
+            Line { product: "book".to_string(), quantity: 1 },
+            Line { product: String::new(), quantity: 0 },
+        ],
+        rating: ("great".to_string(), 11),
+    }
+}
+
+#[test]
+fn names_params_after_paths() {
+    let error = invalid_order().validate().expect_err("should be invalid");
+    let problem = ProblemDetails::new(&error).with_instance("/orders/42");
+    assert_eq!(problem.status(), 400);
+    assert_eq!(problem.instance(), Some("/orders/42"));
+    let names: Vec<&str> = problem.invalid_params().iter().map(|param| param.name.as_str()).collect();
+    assert_eq!(names, ["lines[1].product", "lines[1].quantity", "rating[1]"]);
+    assert_eq!(problem.invalid_params()[2], InvalidParam {
+        name: "rating[1]".to_string(),
+        reason: "Value is not in range 1..=10".to_string(),
+    });
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serializes_as_problem_json() {
+    let error = invalid_order().validate().expect_err("should be invalid");
+    let problem = ProblemDetails::new(&error).with_type("https://example.com/problems/invalid-order").with_status(422);
+    assert_eq!(serde_json::to_value(&problem).unwrap(), serde_json::json!({
+        "type": "https://example.com/problems/invalid-order",
+        "title": "Your request parameters didn't validate.",
+        "status": 422,
+        "invalid-params": [
+            { "name": "lines[1].product", "reason": "Value should not be empty" },
+            { "name": "lines[1].quantity", "reason": "Value is not in range 1.." },
+            { "name": "rating[1]", "reason": "Value is not in range 1..=10" },
+        ],
+    }));
+}
@@ -0,0 +1,33 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+use validator::{PatternMismatchError, Validate, Validator};
+
+static SLUG: Lazy<Regex> = Lazy::new(|| Regex::new("^[a-z0-9]+(-[a-z0-9]+)*$").unwrap());
+
+#[derive(Validator)]
+struct Article {
+    #[validator(regex = "^[a-z0-9_]+$")]
+    author: String,
+    #[validator(regex(path = SLUG))]
+    slug: String,
+    #[validator(regex = "^#[0-9a-f]{6}$")]
+    color: Option<String>,
+}
+
+#[test]
+fn patterns() {
+    Article {
+        author: "jane_doe".to_string(),
+        slug: "hello-world".to_string(),
+        color: None,
+    }.validate().expect("should be valid");
+
+    let errors = Article {
+        author: "Jane Doe".to_string(),
+        slug: "hello--world".to_string(),
+        color: Some("red".to_string()),
+    }.validate().expect_err("should be invalid");
+    assert_eq!(errors.author, Some(PatternMismatchError { pattern: "^[a-z0-9_]+$".to_string() }));
+    assert_eq!(errors.slug, Some(PatternMismatchError { pattern: SLUG.as_str().to_string() }));
+    assert_eq!(errors.color, Some(PatternMismatchError { pattern: "^#[0-9a-f]{6}$".to_string() }));
+}
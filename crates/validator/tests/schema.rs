@@ -0,0 +1,126 @@
+use validator::{CustomError, PathSegment, SchemaError, Validate, ValidationError, ValidationOptions, Validator};
+
+#[derive(Debug, PartialEq)]
+enum AccountType {
+    Personal,
+    Business,
+}
+
+fn dates_in_order(signup: &Signup) -> Result<(), CustomError> {
+    if signup.end_date >= signup.start_date {
+        Ok(())
+    } else {
+        Err(CustomError::new("end_date must not be before start_date"))
+    }
+}
+
+#[derive(Validator)]
+#[validator(
+    must_match(password_confirmation, password),
+    required_if(company, account_type = AccountType::Business),
+    at_least_one_of(phone, email),
+    schema = dates_in_order,
+)]
+struct Signup {
+    #[validator(length(min = 8))]
+    password: String,
+    #[validator(ignore)]
+    password_confirmation: String,
+    #[validator(ignore)]
+    account_type: AccountType,
+    #[validator(ignore)]
+    company: Option<String>,
+    #[validator(ignore)]
+    phone: Option<String>,
+    #[validator(ignore)]
+    email: Option<String>,
+    #[validator(ignore)]
+    start_date: u32,
+    #[validator(ignore)]
+    end_date: u32,
+}
+
+#[derive(Validator)]
+#[validator(exactly_one_of(0, 1))]
+struct Contact(#[validator(ignore)] Option<String>, #[validator(ignore)] Option<String>);
+
+fn signup() -> Signup {
+    Signup {
+        password: "correct horse".to_string(),
+        password_confirmation: "correct horse".to_string(),
+        account_type: AccountType::Personal,
+        company: None,
+        phone: None,
+        email: Some("someone@example.com".to_string()),
+        start_date: 1,
+        end_date: 2,
+    }
+}
+
+#[test]
+fn valid() {
+    signup().validate().expect("should be valid");
+    Signup { account_type: AccountType::Business, company: Some("Acme".to_string()), ..signup() }
+        .validate()
+        .expect("should be valid");
+}
+
+#[test]
+fn errors_are_reported_next_to_the_fields() {
+    let error = Signup {
+        password: "short".to_string(),
+        account_type: AccountType::Business,
+        email: None,
+        end_date: 0,
+        ..signup()
+    }.validate().expect_err("should be invalid");
+    assert!(error.password.is_some());
+    let schema: Vec<_> = error.schema.expect("the cross-field rules should fail").iter().cloned().collect();
+    assert_eq!(schema, vec![
+        SchemaError::Mismatch {
+            field: PathSegment::Field("password_confirmation".into()),
+            other: PathSegment::Field("password".into()),
+        },
+        SchemaError::Required {
+            field: PathSegment::Field("company".into()),
+            condition: PathSegment::Field("account_type".into()),
+            value: "AccountType::Business".into(),
+        },
+        SchemaError::NoneSet { fields: vec![PathSegment::Field("phone".into()), PathSegment::Field("email".into())] },
+        SchemaError::Custom(CustomError::new("end_date must not be before start_date")),
+    ]);
+}
+
+#[test]
+fn errors_have_the_path_of_their_field() {
+    let error = Signup { password_confirmation: "wrong".to_string(), phone: None, email: None, ..signup() }
+        .validate()
+        .expect_err("should be invalid");
+    let errors: Vec<_> = error.errors()
+        .into_iter()
+        .map(|(path, error)| (path.to_string(), error.to_string()))
+        .collect();
+    assert_eq!(errors, vec![
+        ("password_confirmation".to_string(), "password_confirmation does not match password".to_string()),
+        (String::new(), "at least one of phone, email must be set".to_string()),
+    ]);
+    assert_eq!(
+        error.to_string(),
+        "password_confirmation does not match password, at least one of phone, email must be set",
+    );
+}
+
+#[test]
+fn fail_fast_skips_the_remaining_rules() {
+    let error = Signup { password_confirmation: "wrong".to_string(), phone: None, email: None, ..signup() }
+        .validate_with_options(ValidationOptions::fail_fast())
+        .expect_err("should be invalid");
+    assert_eq!(error.schema.expect("the cross-field rules should fail").iter().count(), 1);
+}
+
+#[test]
+fn tuple_structs() {
+    Contact(Some("a".to_string()), None).validate().expect("should be valid");
+    let error = Contact(Some("a".to_string()), Some("b".to_string())).validate().expect_err("should be invalid");
+    assert_eq!(error.2.expect("the cross-field rules should fail").to_string(), "exactly one of 0, 1 must be set, found 2");
+}
@@ -0,0 +1,54 @@
+#![cfg(feature = "serde")]
+
+use serde_json::json;
+use validator::{Validate, Validator};
+
+#[derive(Validator)]
+struct SignUp {
+    #[validator(length(min = 8), code = "password_short", message = "Use at least {min} characters")]
+    password: String,
+    #[validator(elements(email))]
+    emails: Vec<String>,
+    #[validator(tuple(not_empty, length(max = 3)))]
+    initials: (String, String),
+    address: Address,
+}
+
+#[derive(Validator)]
+struct Address {
+    #[validator(not_empty)]
+    city: String,
+}
+
+#[test]
+fn serializes_errors_by_path() {
+    let errors = SignUp {
+        password: "secret".to_string(),
+        emails: vec!["someone@example.com".to_string(), "someone".to_string()],
+        initials: ("J".to_string(), "JRRT".to_string()),
+        address: Address { city: String::new() },
+    }.validate().expect_err("should be invalid");
+
+    assert_eq!(serde_json::to_value(&errors).unwrap(), json!({
+        "password": [{
+            "code": "password_short",
+            "message": "Use at least 8 characters",
+            "params": { "min": "8", "len": "6" },
+        }],
+        "emails[1]": [{
+            "code": "email",
+            "message": "No '@' character was found in the given address",
+            "params": {},
+        }],
+        "initials[1]": [{
+            "code": "length.too_long",
+            "message": "value of length 4 exceeds maximum of 3",
+            "params": { "max": "3", "len": "4" },
+        }],
+        "address.city": [{
+            "code": "not_empty",
+            "message": "Value should not be empty",
+            "params": {},
+        }],
+    }));
+}
@@ -0,0 +1,37 @@
+use validator::{EmptyValueError, InvalidEmailError, InvalidLengthError, ThenError, Validate, ValidationError, Validator};
+
+#[derive(Validator)]
+struct SignUp {
+    #[validator(then(not_empty, then(length(max = 32), email)))]
+    email: String,
+    #[validator(and(not_empty, email))]
+    backup_email: String,
+}
+
+#[test]
+fn stops_at_the_first_stage_which_fails() {
+    SignUp {
+        email: "someone@example.com".to_string(),
+        backup_email: "someone@example.org".to_string(),
+    }.validate().expect("should be valid");
+
+    let errors = SignUp {
+        email: String::new(),
+        backup_email: String::new(),
+    }.validate().expect_err("should be invalid");
+    assert_eq!(errors.email, Some(ThenError::First(EmptyValueError)));
+    // unlike `then`, `and` reports the errors of both rules
+    assert_eq!(errors.errors().len(), 3);
+
+    let errors = SignUp {
+        email: "someone.with.a.very.long.name@example.com".to_string(),
+        backup_email: "someone@example.org".to_string(),
+    }.validate().expect_err("should be invalid");
+    assert_eq!(errors.email, Some(ThenError::Second(ThenError::First(InvalidLengthError::TooLong { max: 32, len: 41, unit: None }))));
+
+    let errors = SignUp {
+        email: "someone".to_string(),
+        backup_email: "someone@example.org".to_string(),
+    }.validate().expect_err("should be invalid");
+    assert_eq!(errors.email, Some(ThenError::Second(ThenError::Second(InvalidEmailError::ATNotFound))));
+}
@@ -0,0 +1,36 @@
+use validator::{Validate, ValidationError, Validator};
+
+// the rules of the elements are applied to the type of each element rather than to the whole tuple
+#[derive(Validator)]
+struct Review {
+    #[validator(tuple(not_empty, range(1..=10)))]
+    rating: (String, u32),
+    #[validator(tuple(length(max = 3), email, range(..=100u8)))]
+    author: (String, String, u8),
+}
+
+fn codes<E: ValidationError>(error: &E) -> Vec<(String, String)> {
+    error.errors()
+        .into_iter()
+        .map(|(path, error)| (path.to_string(), error.code().to_string()))
+        .collect()
+}
+
+#[test]
+fn elements_of_different_types() {
+    Review {
+        rating: ("great".to_string(), 9),
+        author: ("jd".to_string(), "jd@example.com".to_string(), 42),
+    }.validate().expect("should be valid");
+
+    let error = Review {
+        rating: (String::new(), 11),
+        author: ("jane".to_string(), "jane@example.com".to_string(), 101),
+    }.validate().expect_err("should be invalid");
+    assert_eq!(codes(&error), [
+        ("rating[0]".to_string(), "not_empty".to_string()),
+        ("rating[1]".to_string(), "range".to_string()),
+        ("author[0]".to_string(), "length.too_long".to_string()),
+        ("author[2]".to_string(), "range".to_string()),
+    ]);
+}
@@ -0,0 +1,58 @@
+use validator::{
+    AllOfError, AnyOfError, EmptyValueError, ErrorCode, ExactlyOneError, InvalidLengthError, NegatedRuleError,
+    SeveralMatchedError, Validate, ValidationError, Validator,
+};
+
+#[derive(Validator)]
+struct Contact {
+    #[validator(any(email, url, starts_with = "+"))]
+    address: String,
+    #[validator(all(not_empty, not(contains = ".."), length(max = 16)))]
+    directory: Option<String>,
+    #[validator(exactly_one(ends_with = ".com", ends_with = ".example.com"))]
+    host: String,
+}
+
+fn contact() -> Contact {
+    Contact {
+        address: "+33 1 23 45 67 89".to_string(),
+        directory: None,
+        host: "example.com".to_string(),
+    }
+}
+
+#[test]
+fn valid_values_have_no_errors() {
+    contact().validate().expect("should be valid");
+    Contact { directory: Some("docs/index.md".to_string()), ..contact() }.validate().expect("should be valid");
+}
+
+#[test]
+fn errors_are_flat() {
+    let errors = Contact {
+        address: "someone".to_string(),
+        directory: Some("../../../etc/passwd".to_string()),
+        host: "api.example.com".to_string(),
+    }.validate().expect_err("should be invalid");
+
+    let AnyOfError((_, _, prefix)) = errors.address.unwrap();
+    assert_eq!(prefix.prefix, "+");
+    assert_eq!(errors.directory, Some(AllOfError((
+        None,
+        Some(NegatedRuleError { rule: r#"contains = "..""#.to_string() }),
+        Some(InvalidLengthError::TooLong { max: 16, len: 19, unit: None }),
+    ))));
+    assert_eq!(errors.host, Some(ExactlyOneError::SeveralMatched(SeveralMatchedError { matched: 2 })));
+}
+
+#[test]
+fn all_the_errors_are_listed() {
+    let errors = Contact { directory: Some(String::new()), host: "example.org".to_string(), ..contact() }
+        .validate()
+        .expect_err("should be invalid");
+    let directory = errors.directory.as_ref().unwrap();
+    assert_eq!(directory.0.0, Some(EmptyValueError));
+    assert_eq!(directory.code(), "not_empty");
+    let codes: Vec<_> = errors.errors().into_iter().map(|(path, error)| format!("{path}: {}", error.code())).collect();
+    assert_eq!(codes, ["directory: not_empty", "host: ends_with", "host: ends_with"]);
+}
@@ -1,6 +1,10 @@
-use proc_macro2::{Ident, Span, TokenStream};
+use std::collections::HashSet;
+
+use proc_macro2::{Ident, Span, TokenStream, TokenTree};
 use quote::{quote, ToTokens};
-use syn::{AngleBracketedGenericArguments, Arm, Attribute, Block, Data, DataEnum, DataStruct, DeriveInput, Expr, ExprStruct, ExprTuple, FieldMutability, FieldPat, Fields, FieldsNamed, FieldsUnnamed, FieldValue, FnArg, GenericArgument, ImplItem, ImplItemFn, ImplItemType, Index, Item, ItemEnum, ItemImpl, ItemStruct, LitInt, Member, Meta, parenthesized, parse_quote, Pat, Path, PathArguments, PathSegment, PatIdent, PatStruct, PatTupleStruct, PatType, ReturnType, Signature, Stmt, Token, Type, TypePath, TypeReference, TypeTuple, Variant, Visibility, ExprRange, RangeLimits, token};
+use syn::{AngleBracketedGenericArguments, Arm, Attribute, Block, Data, DataEnum, DataStruct, DeriveInput, Expr, ExprStruct, ExprTuple, FieldMutability, FieldPat, Fields, FieldsNamed, FieldsUnnamed, FieldValue, GenericArgument, ImplItem, ImplItemFn, ImplItemType, Index, Item, ItemEnum, ItemImpl, ItemStruct, LitInt, Member, Meta, parenthesized, parse_quote, parse_quote_spanned, Pat, Path, PathArguments, PathSegment, PatIdent, PatStruct, PatTupleStruct, ReturnType, Signature, Stmt, Token, Type, TypePath, TypeTuple, Variant, Visibility, ExprRange, RangeLimits, token, WherePredicate, Generics, GenericParam, WhereClause, LitStr, ExprLit, Lit};
+use syn::punctuated::Punctuated;
+use syn::ext::IdentExt;
 use syn::parse::{Parse, Parser, ParseStream};
 use syn::spanned::Spanned;
 use syn::token::{Colon, Comma, Fn, PathSep, Semi};
@@ -50,17 +54,103 @@ impl Parse for Input {
             }
             Data::Union(data) => return Err(syn::Error::new(data.union_token.span, "Validator is not supported for unions"))
         };
+        let mut options = container_options_from_attrs(&input.attrs).with_message("failed to parse container options")?;
+        let mut data = data;
+        if !options.schema.is_empty() {
+            let InputData::Struct { fields, .. } = &mut data else {
+                return Err(syn::Error::new(input.ident.span(), "cross-field rules are only supported for structs"));
+            };
+            if let Some(field) = fields.fields.iter().find(|field| field.label() == "schema" && fields.named_fields) {
+                return Err(syn::Error::new(field.name.span(), "the 'schema' field name is reserved for the errors of cross-field rules"));
+            }
+            fields.schema = std::mem::take(&mut options.schema);
+        }
+        let (generics, predicates) = split_generics(input.generics);
         Ok(Self {
             vis: input.vis,
             name: input.ident,
+            generics,
+            predicates,
             data,
+            options,
         })
     }
 }
 
+/// Moves the bounds of the generic parameters into where predicates, along with those of the where clause,
+/// so that the generated types can keep only the parameters and predicates they use.
+/// Default values of the parameters are dropped.
+fn split_generics(generics: Generics) -> (Generics, Vec<WherePredicate>) {
+    let mut predicates = Vec::new();
+    let params = generics.params.into_iter().map(|param| match param {
+        GenericParam::Lifetime(mut param) => {
+            if !param.bounds.is_empty() {
+                let (lifetime, bounds) = (&param.lifetime, &param.bounds);
+                predicates.push(parse_quote!(#lifetime: #bounds));
+            }
+            param.colon_token = None;
+            param.bounds.clear();
+            GenericParam::Lifetime(param)
+        }
+        GenericParam::Type(mut param) => {
+            if !param.bounds.is_empty() {
+                let (ident, bounds) = (&param.ident, &param.bounds);
+                predicates.push(parse_quote!(#ident: #bounds));
+            }
+            param.colon_token = None;
+            param.bounds.clear();
+            param.eq_token = None;
+            param.default = None;
+            GenericParam::Type(param)
+        }
+        GenericParam::Const(mut param) => {
+            param.eq_token = None;
+            param.default = None;
+            GenericParam::Const(param)
+        }
+    }).collect();
+    predicates.extend(generics.where_clause.into_iter().flat_map(|where_clause| where_clause.predicates));
+    (Generics { lt_token: generics.lt_token, params, gt_token: generics.gt_token, where_clause: None }, predicates)
+}
+
+// the names of the identifiers and lifetimes found in the tokens, lifetimes including their quote
+fn collect_names(tokens: TokenStream, names: &mut HashSet<String>) {
+    let mut lifetime = false;
+    for token in tokens {
+        match &token {
+            TokenTree::Group(group) => collect_names(group.stream(), names),
+            TokenTree::Ident(ident) if lifetime => {
+                names.insert(format!("'{ident}"));
+            }
+            TokenTree::Ident(ident) => {
+                names.insert(ident.to_string());
+            }
+            _ => {}
+        }
+        lifetime = matches!(&token, TokenTree::Punct(punct) if punct.as_char() == '\'');
+    }
+}
+
+fn generic_param_name(param: &GenericParam) -> String {
+    match param {
+        GenericParam::Lifetime(param) => param.lifetime.to_string(),
+        GenericParam::Type(param) => param.ident.to_string(),
+        GenericParam::Const(param) => param.ident.to_string(),
+    }
+}
+
 pub fn derive(input: Input) -> TokenStream {
-    let mut items = Vec::new();
-    items.push(input.error_definition());
+    let mut items = vec![
+        input.error_definition(),
+        input.debug_impl(),
+        input.partial_eq_impl(),
+        input.clone_impl(),
+        input.validation_error_impl(),
+        input.display_impl(),
+        input.error_impl(),
+        input.serialize_impl(),
+        input.json_schema_impl(),
+    ];
     items.extend(input.validator());
     let validator_ext = input.validate_impl();
     quote! {
@@ -94,7 +184,48 @@ fn parse_enum_input(input: &DeriveInput, data: &DataEnum) -> syn::Result<InputDa
 pub struct Input {
     vis: Visibility,
     name: Ident,
+    // the parameters without their bounds, which are found in `predicates`
+    generics: Generics,
+    predicates: Vec<WherePredicate>,
     data: InputData,
+    options: ContainerOptions,
+}
+
+#[derive(Default)]
+struct ContainerOptions {
+    context: Option<Type>,
+    asyncness: bool,
+    fail_fast: bool,
+    max_errors: Option<LitInt>,
+    schema: Vec<SchemaRule>,
+    // replaces the bounds inferred from the fields
+    bound: Option<Vec<WherePredicate>>,
+}
+
+/// A rule involving several fields of a struct, declared on the struct itself
+enum SchemaRule {
+    /// `schema = path`, a function taking the whole value
+    Function(Path),
+    /// `must_match(field, other)`
+    MustMatch(Member, Member),
+    /// `required_if(field, condition = value)`
+    RequiredIf {
+        field: Member,
+        condition: Member,
+        value: Box<Expr>,
+    },
+    /// `exactly_one_of(a, b, ..)`
+    ExactlyOneOf(Vec<Member>),
+    /// `at_least_one_of(a, b, ..)`
+    AtLeastOneOf(Vec<Member>),
+}
+
+/// The validator trait which is being implemented
+#[derive(Clone, Copy)]
+enum Mode<'a> {
+    Sync,
+    Context(&'a Type),
+    Async,
 }
 
 enum InputData {
@@ -110,9 +241,180 @@ enum InputData {
 struct StructFields {
     fields: Vec<Field>,
     named_fields: bool,
+    // the cross-field rules, always empty for enum variants
+    schema: Vec<SchemaRule>,
 }
 
 impl StructFields {
+    fn error_declaration(&self, path: Path, mode: Mode) -> Expr {
+        Expr::Struct(ExprStruct {
+            attrs: vec![],
+            qself: None,
+            path,
+            brace_token: Default::default(),
+            fields: self.fields.iter()
+                .enumerate()
+                .map(|(index, field)| field.validate_field(mode, index))
+                .chain(self.check_schema(mode))
+                .collect(),
+            dot2_token: None,
+            rest: None,
+        })
+    }
+
+    /// The member of the generated error holding the errors of the cross-field rules, if there are any
+    fn schema_member(&self) -> Option<Member> {
+        if self.schema.is_empty() {
+            None
+        } else if self.named_fields {
+            Some(Member::Named(Ident::new("schema", Span::call_site())))
+        } else {
+            Some(Member::Unnamed(Index::from(self.fields.len())))
+        }
+    }
+
+    // the cross-field rules are checked once the fields have been validated, the value being bound to `value`
+    fn check_schema(&self, mode: Mode) -> Option<FieldValue> {
+        let member = self.schema_member()?;
+        let budget: Expr = match mode {
+            Mode::Sync => parse_quote!(budget),
+            Mode::Context(_) | Mode::Async => parse_quote!(&mut ::validator::ErrorBudget::unlimited()),
+        };
+        let count = self.schema.len();
+        let rules = self.schema.iter().map(SchemaRule::check);
+        Some(FieldValue {
+            attrs: vec![],
+            member,
+            colon_token: Some(Default::default()),
+            expr: parse_quote! {
+                {
+                    let rules: [::validator::SchemaRule; #count] = [#(&|| #rules),*];
+                    match ::validator::SchemaErrors::check(rules, #budget) {
+                        Ok(()) => None,
+                        Err(error) => {
+                            _valid = false;
+                            Some(error)
+                        }
+                    }
+                }
+            },
+        })
+    }
+
+    /// The members of the generated error along with the names they are bound to by `error_pat`
+    fn error_bindings(&self) -> Vec<(Member, Ident)> {
+        self.fields.iter()
+            .map(|field| (field.name.clone(), field.pattern_name()))
+            .chain(self.schema_member().map(|member| (member, Ident::new("schema", Span::call_site()))))
+            .collect()
+    }
+
+    fn display_fields(&self) -> Vec<ExprTuple> {
+        let mut display_fields: Vec<_> = self.fields.iter().map(Field::display_field).collect();
+        if self.schema_member().is_some() {
+            display_fields.push(parse_quote!(("", schema.as_ref().map(|error| error as &dyn ::std::fmt::Display))));
+        }
+        display_fields
+    }
+
+    fn sources(&self) -> Vec<Expr> {
+        let mut sources: Vec<_> = self.fields.iter().map(Field::source).collect();
+        if self.schema_member().is_some() {
+            sources.push(parse_quote!(schema.as_ref().map(|error| error as &(dyn ::std::error::Error + 'static))));
+        }
+        sources
+    }
+
+    fn collect_errors(&self) -> Vec<Stmt> {
+        let mut collect_errors: Vec<_> = self.fields.iter().map(Field::collect_error).collect();
+        if self.schema_member().is_some() {
+            collect_errors.push(parse_quote! {
+                if let Some(error) = schema {
+                    ::validator::ValidationError::collect_errors(error, path, errors);
+                }
+            });
+        }
+        collect_errors
+    }
+
+    // in async mode all fields are validated concurrently before the error is declared
+    fn join_validations(&self, mode: Mode) -> Option<Stmt> {
+        let Mode::Async = mode else { return None };
+        let validations = self.fields.iter().map(|field| {
+            let name = &field.name;
+            let value = field.pattern_name();
+            quote!(::validator::AsyncValidator::validate_async(&validator.#name, #value))
+        });
+        Some(parse_quote!(let __results = ::validator::__private::join!(#(#validations),*);))
+    }
+
+    /// The JSON Schema of the fields, an object for named fields, the schema of the field for a single unnamed field
+    /// and an array for several unnamed fields
+    fn json_schema(&self) -> Expr {
+        if self.named_fields {
+            let properties = self.fields.iter().map(|field| {
+                let name = field.label();
+                let schema = field.json_schema();
+                let required = field.is_required();
+                quote!((#name, #schema, #required))
+            });
+            parse_quote!(::validator::object_schema([#(#properties),*]))
+        } else if let [field] = self.fields.as_slice() {
+            field.json_schema()
+        } else {
+            let items = self.fields.iter().map(Field::json_schema);
+            parse_quote!(::validator::tuple_schema([#(#items),*]))
+        }
+    }
+
+    fn validator_types(&self) -> Vec<Type> {
+        self.fields.iter()
+            .map(|field| field.validator.validator_type(&field.ty))
+            .collect()
+    }
+
+    // bounds required for the field validators to pass on a generic context
+    fn context_bounds(&self) -> impl Iterator<Item=WherePredicate> + '_ {
+        self.fields.iter().map(Field::context_bound)
+    }
+
+    // matches the fields of the generated error
+    fn error_pat(&self, path: &Path) -> Pat {
+        if self.named_fields {
+            Pat::Struct(PatStruct {
+                attrs: vec![],
+                qself: None,
+                path: path.clone(),
+                brace_token: Default::default(),
+                fields: self.fields.iter()
+                    .map(Field::field_pat)
+                    .chain(self.schema_member().map(|member| FieldPat {
+                        attrs: vec![],
+                        member,
+                        colon_token: None,
+                        pat: Box::new(parse_quote!(schema)),
+                    }))
+                    .collect(),
+                rest: None,
+            })
+        } else {
+            Pat::TupleStruct(PatTupleStruct {
+                attrs: vec![],
+                qself: None,
+                path: path.clone(),
+                paren_token: Default::default(),
+                elems: self.fields.iter()
+                    .map(Field::pat)
+                    .chain(self.schema_member().map(|_| parse_quote!(schema)))
+                    .collect(),
+            })
+        }
+    }
+
+    fn error_bounds<'a>(&'a self, mode: Mode<'a>, bound: &'a TokenStream) -> impl Iterator<Item=WherePredicate> + 'a {
+        self.fields.iter().map(move |field| field.error_bound(mode, bound))
+    }
+
     fn validator_fields(&self) -> Fields {
         let define_validator_fields = self.fields.iter().map(Field::define_validator_field);
         if self.named_fields {
@@ -122,9 +424,16 @@ impl StructFields {
         }
     }
 
-    fn error_definition(&self) -> Fields {
+    fn error_definition(&self, mode: Mode) -> Fields {
+        let schema = self.schema_member().map(|member| -> syn::Field {
+            match member {
+                Member::Named(name) => parse_quote!(pub #name: Option<::validator::SchemaErrors>),
+                Member::Unnamed(_) => parse_quote!(pub Option<::validator::SchemaErrors>),
+            }
+        });
         let fields = self.fields.iter()
-            .map(Field::error_field)
+            .map(|field| field.error_field(mode))
+            .chain(schema)
             .collect();
         if self.named_fields {
             Fields::Named(FieldsNamed {
@@ -161,6 +470,7 @@ impl TryFrom<Fields> for StructFields {
                 Ok(Self {
                     fields,
                     named_fields: true,
+                    schema: vec![],
                 })
             }
             Fields::Unnamed(fields) => {
@@ -178,6 +488,7 @@ impl TryFrom<Fields> for StructFields {
                 Ok(Self {
                     fields,
                     named_fields: false,
+                    schema: vec![],
                 })
             }
         }
@@ -203,16 +514,62 @@ enum Validator {
     NotEmpty,
     And(Box<Self>, Box<Self>),
     Or(Box<Self>, Box<Self>),
+    Then(Box<Self>, Box<Self>),
+    /// The negated rule, along with its source to name it in the errors
+    Not(Box<Self>, String),
+    AllOf(Vec<Self>),
+    AnyOf(Vec<Self>),
+    ExactlyOne(Vec<Self>),
     Email,
     Url,
     IpAddr,
-    Length(Option<usize>, Option<usize>),
+    Length(Option<usize>, Option<usize>, Option<Ident>),
+    Chars {
+        classes: Vec<Ident>,
+        extra: Option<LitStr>,
+        trimmed: bool,
+    },
     Elements(Box<Self>),
+    Keys(Box<Self>),
+    Values(Box<Self>),
+    Entries(Box<Self>, Box<Self>),
+    Required,
     #[default]
     Default,
     Ignore,
     Tuple(Vec<Self>),
     Range(ExprRange),
+    Regex(RegexSource),
+    /// `contains`, `starts_with` and `ends_with`, along with whether the case is ignored
+    Contains(Expr, bool),
+    StartsWith(Expr, bool),
+    EndsWith(Expr, bool),
+    /// `one_of` and `none_of`, along with whether the case is ignored
+    OneOf(Vec<Expr>, bool),
+    NoneOf(Vec<Expr>, bool),
+    /// `custom = f`, whose function returns a `CustomError`, or `custom(f, error = E)`, whose function returns an `E`
+    Custom {
+        function: Path,
+        error: Type,
+    },
+    With {
+        validator: Expr,
+        ty: Type,
+    },
+    Message {
+        validator: Box<Self>,
+        code: Option<LitStr>,
+        message: Option<LitStr>,
+    },
+}
+
+/// The regex of a `regex` rule
+#[derive(Debug)]
+enum RegexSource {
+    /// `regex = "pattern"`, checked when expanding
+    Pattern(LitStr),
+    /// `regex(path = STATIC)`, a static dereferencing to a `Regex`
+    Path(Path),
 }
 
 fn parse_struct_input(data: &DataStruct) -> syn::Result<InputData> {
@@ -230,21 +587,126 @@ impl Input {
         Ident::new(&format!("{name}ValidationErrors", name = self.name), self.name.span())
     }
 
+    /// The derived type along with its generic arguments
+    fn derived_type(&self) -> Type {
+        let name = &self.name;
+        let (_, type_generics, _) = self.generics.split_for_impl();
+        parse_quote!(#name #type_generics)
+    }
+
+    fn fields(&self) -> Vec<&Field> {
+        match &self.data {
+            InputData::Struct { fields, .. } => fields.fields.iter().collect(),
+            InputData::Enum { variants } => variants.iter()
+                .filter_map(|variant| variant.fields.as_ref())
+                .flat_map(|fields| &fields.fields)
+                .collect(),
+        }
+    }
+
+    /// The predicates of the derived type, along with the bounds needed to validate its fields in the given mode,
+    /// which are inferred for the fields using a type parameter unless they are set by the `bound` option
+    fn bounds(&self, mode: Mode) -> Vec<WherePredicate> {
+        let mut bounds = self.predicates.clone();
+        match &self.options.bound {
+            Some(bound) => bounds.extend(bound.iter().cloned()),
+            None => {
+                let type_params: HashSet<_> = self.generics.params.iter()
+                    .filter(|param| !matches!(param, GenericParam::Lifetime(_)))
+                    .map(generic_param_name)
+                    .collect();
+                for bound in self.fields().into_iter().flat_map(|field| field.bounds(mode, &type_params)) {
+                    if !bounds.contains(&bound) {
+                        bounds.push(bound);
+                    }
+                }
+            }
+        }
+        bounds
+    }
+
+    /// The generics of the implementations for the derived type
+    fn impl_generics(&self, mode: Mode) -> Generics {
+        let mut generics = self.generics.clone();
+        generics.make_where_clause().predicates.extend(self.bounds(mode));
+        generics
+    }
+
+    /// The generics of a generated type made up of the given types, keeping only the parameters which they use
+    /// and the predicates which only use those, as unused parameters are rejected by the compiler
+    fn used_generics<T: ToTokens>(&self, types: impl IntoIterator<Item=T>) -> Generics {
+        let mut names = HashSet::new();
+        for ty in types {
+            collect_names(ty.to_token_stream(), &mut names);
+        }
+        let params: Punctuated<GenericParam, Comma> = self.generics.params.iter()
+            .filter(|param| names.contains(&generic_param_name(param)))
+            .cloned()
+            .collect();
+        let all_params: HashSet<_> = self.generics.params.iter().map(generic_param_name).collect();
+        let used_params: HashSet<_> = params.iter().map(generic_param_name).collect();
+        let predicates: Punctuated<WherePredicate, Comma> = self.bounds(self.mode()).into_iter()
+            .filter(|predicate| {
+                let mut names = HashSet::new();
+                collect_names(predicate.to_token_stream(), &mut names);
+                let mut predicate_params = names.intersection(&all_params).peekable();
+                predicate_params.peek().is_some() && predicate_params.all(|param| used_params.contains(param))
+            })
+            .collect();
+        Generics {
+            lt_token: Some(Default::default()),
+            params,
+            gt_token: Some(Default::default()),
+            where_clause: (!predicates.is_empty()).then(|| WhereClause {
+                where_token: Default::default(),
+                predicates,
+            }),
+        }
+    }
+
+    fn validator_generics(&self) -> Generics {
+        match &self.data {
+            InputData::Struct { fields, .. } => self.used_generics(fields.validator_types()),
+            InputData::Enum { variants } => self.used_generics(variants.iter()
+                .filter_map(|variant| variant.validator_type(self))),
+        }
+    }
+
+    fn error_generics(&self) -> Generics {
+        let mode = self.mode();
+        self.used_generics(self.fields().into_iter().map(|field| field.validator.error_type(&field.ty, mode)))
+    }
+
+    /// The generated validator type along with its generic arguments
+    fn validator_type_with_generics(&self) -> Type {
+        let name = self.validator_type();
+        let generics = self.validator_generics();
+        let (_, type_generics, _) = generics.split_for_impl();
+        parse_quote!(#name #type_generics)
+    }
+
+    /// The generated error type along with its generic arguments
+    fn error_type_with_generics(&self) -> Type {
+        let name = self.error_type();
+        let generics = self.error_generics();
+        let (_, type_generics, _) = generics.split_for_impl();
+        parse_quote!(#name #type_generics)
+    }
+
     fn error_definition(&self) -> Item {
-        let Input { vis, name: _, data } = self;
+        let Input { vis, data, .. } = self;
         let error_type = self.error_type();
+        let generics = self.error_generics();
         match data {
             InputData::Struct { fields, semi_token } => {
-                let fields = fields.error_definition();
+                let fields = fields.error_definition(self.mode());
                 Item::Struct(
                     ItemStruct {
-                        attrs: vec![
-                            parse_quote!(#[derive(Debug, PartialEq, Clone)])
-                        ],
+                        attrs: vec![],
                         vis: vis.clone(),
                         struct_token: Default::default(),
                         ident: error_type,
-                        generics: Default::default(),
+                        generics,
                         fields,
                         semi_token: semi_token.as_ref().cloned(),
                     },
@@ -253,258 +715,472 @@ impl Input {
             InputData::Enum { variants } => {
                 Item::Enum(
                     ItemEnum {
-                        attrs: vec![parse_quote!(#[derive(Debug, PartialEq, Clone)])],
+                        attrs: vec![],
                         vis: self.vis.clone(),
                         enum_token: Default::default(),
                         ident: error_type,
-                        generics: Default::default(),
+                        generics,
                         brace_token: Default::default(),
                         variants: variants.iter()
-                            .filter_map(EnumVariant::error_variant)
+                            .filter_map(|variant| variant.error_variant(self.mode()))
                             .collect(),
                     },
                 )
             }
         }
     }
+    /// Builds the where clause and the body of an implementation of a trait for the generated error type,
+    /// `body` being the code run for the fields of the struct or of the matched variant, given its name.
+    ///
+    /// Custom validators may return errors which do not implement the trait,
+    /// in which case the generated error does not implement it either.
+    /// The bounds are higher-ranked as the compiler rejects bounds on concrete types which do not hold.
+    fn error_impl_parts(&self, bound: TokenStream, body: impl std::ops::Fn(&Path, &StructFields) -> TokenStream) -> (Vec<WherePredicate>, Block) {
+        let error_type = self.error_type();
+        let mode = self.mode();
+        let mut bounds: Vec<_> = self.error_generics().where_clause
+            .into_iter()
+            .flat_map(|where_clause| where_clause.predicates)
+            .collect();
+        match &self.data {
+            InputData::Struct { fields, .. } => {
+                let path = parse_quote!(#error_type);
+                let pat = fields.error_pat(&path);
+                let body = body(&path, fields);
+                bounds.extend(fields.error_bounds(mode, &bound));
+                (bounds, parse_quote! {
+                    {
+                        let #pat = self;
+                        #body
+                    }
+                })
+            }
+            InputData::Enum { variants } => {
+                let variants: Vec<_> = variants.iter()
+                    .filter_map(|variant| Some((&variant.name, variant.fields.as_ref()?)))
+                    .collect();
+                let arms = variants.iter().map(|(name, fields)| -> Arm {
+                    let path = parse_quote!(#error_type::#name);
+                    let pat = fields.error_pat(&path);
+                    let body = body(&path, fields);
+                    parse_quote!(#pat => { #body })
+                });
+                bounds.extend(variants.iter().flat_map(|(_, fields)| fields.error_bounds(mode, &bound)));
+                let body = if variants.is_empty() {
+                    parse_quote!({ match *self {} })
+                } else {
+                    parse_quote!({ match self { #(#arms)* } })
+                };
+                (bounds, body)
+            }
+        }
+    }
+
+    /// Implements `ValidationError` for the generated error type, so that it can be flattened into a list of errors
+    fn validation_error_impl(&self) -> Item {
+        let error_type = self.error_type_with_generics();
+        let generics = self.error_generics();
+        let (impl_generics, _, _) = generics.split_for_impl();
+        let (bounds, body) = self.error_impl_parts(quote!(::validator::ValidationError), |_, fields| {
+            let collect_errors = fields.collect_errors();
+            quote!(#(#collect_errors)*)
+        });
+        parse_quote! {
+            impl #impl_generics ::validator::ValidationError for #error_type
+            where
+                #(#bounds),*
+            {
+                fn collect_errors<'__a>(&'__a self, path: &mut ::validator::ErrorPath, errors: &mut ::validator::ErrorList<'__a>) #body
+            }
+        }
+    }
+
+    /// Implements `Serialize` for the generated error type when the serde feature of the runtime crate is enabled,
+    /// the errors being serialised along with their paths
+    fn serialize_impl(&self) -> Item {
+        let error_type = self.error_type_with_generics();
+        let generics = self.error_generics();
+        let (impl_generics, _, where_clause) = generics.split_for_impl();
+        let bounds = where_clause.into_iter().flat_map(|where_clause| &where_clause.predicates);
+        parse_quote! {
+            ::validator::__if_serde! {
+                impl #impl_generics ::validator::__private::serde::Serialize for #error_type
+                where
+                    #(#bounds,)*
+                    // higher-ranked so that the bound is only checked where the implementation is used
+                    for<'__a> #error_type: ::validator::ValidationError
+                {
+                    fn serialize<__S: ::validator::__private::serde::Serializer>(&self, serializer: __S) -> ::core::result::Result<__S::Ok, __S::Error> {
+                        ::validator::serialize_errors(self, serializer)
+                    }
+                }
+            }
+        }
+    }
+
+    /// Implements `JsonSchema` for the derived type when the json-schema feature of the runtime crate is enabled,
+    /// non-generic types being defined in `$defs` under their name
+    fn json_schema_impl(&self) -> Item {
+        let derived_type = self.derived_type();
+        let (impl_generics, _, _) = self.generics.split_for_impl();
+        let predicates = &self.predicates;
+        let mut field_types: Vec<&Type> = vec![];
+        for field in self.fields() {
+            let ty = field.schema_type();
+            if !field_types.contains(&ty) {
+                field_types.push(ty);
+            }
+        }
+        let schema_name = self.generics.params.is_empty().then(|| {
+            let name = self.name.unraw().to_string();
+            quote! {
+                fn schema_name() -> ::core::option::Option<::std::borrow::Cow<'static, str>> {
+                    ::core::option::Option::Some(::std::borrow::Cow::Borrowed(#name))
+                }
+            }
+        });
+        let body: Expr = match &self.data {
+            InputData::Struct { fields, .. } => fields.json_schema(),
+            InputData::Enum { variants } => {
+                let variants = variants.iter().map(EnumVariant::json_schema);
+                parse_quote!(::validator::__private::serde_json::json!({ "oneOf": [#(#variants),*] }))
+            }
+        };
+        let defs = if field_types.is_empty() { quote!(_) } else { quote!(defs) };
+        parse_quote! {
+            ::validator::__if_json_schema! {
+                impl #impl_generics ::validator::JsonSchema for #derived_type
+                where
+                    #(#predicates,)*
+                    // higher-ranked so that the bounds are only checked where the implementation is used
+                    #(for<'__a> #field_types: ::validator::JsonSchema,)*
+                {
+                    #schema_name
+
+                    fn json_schema(#defs: &mut ::validator::SchemaDefinitions) -> ::validator::__private::serde_json::Value {
+                        #body
+                    }
+                }
+            }
+        }
+    }
+
+    /// Implements `Debug` for the generated error type, as deriving it would require the parameters of the derived type
+    /// to implement `Debug` rather than the errors of its fields, which the compiler cannot always find on its own
+    fn debug_impl(&self) -> Item {
+        let error_type = self.error_type_with_generics();
+        let generics = self.error_generics();
+        let (impl_generics, _, _) = generics.split_for_impl();
+        let (bounds, body) = self.error_impl_parts(quote!(::std::fmt::Debug), |path, fields| {
+            let name = path.segments.last().unwrap().ident.to_string();
+            let (members, values): (Vec<_>, Vec<_>) = fields.error_bindings().into_iter().unzip();
+            if fields.named_fields {
+                let labels = members.iter().map(member_label);
+                quote!(f.debug_struct(#name) #(.field(#labels, #values))* .finish())
+            } else {
+                quote!(f.debug_tuple(#name) #(.field(#values))* .finish())
+            }
+        });
+        parse_quote! {
+            impl #impl_generics ::std::fmt::Debug for #error_type
+            where
+                #(#bounds),*
+            {
+                fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result #body
+            }
+        }
+    }
+
+    /// Implements `PartialEq` for the generated error type, for the same reason as `Debug`
+    fn partial_eq_impl(&self) -> Item {
+        let error_type = self.error_type_with_generics();
+        let generics = self.error_generics();
+        let (impl_generics, _, _) = generics.split_for_impl();
+        let is_enum = matches!(self.data, InputData::Enum { .. });
+        let (bounds, body) = self.error_impl_parts(quote!(::std::cmp::PartialEq), |path, fields| {
+            let (members, values): (Vec<_>, Vec<_>) = fields.error_bindings().into_iter().unzip();
+            let others: Vec<_> = (0..members.len())
+                .map(|index| Ident::new(&format!("__other{index}"), Span::call_site()))
+                .collect();
+            let other_pat = quote!(#path { #(#members: #others),* });
+            if is_enum {
+                quote! {
+                    match other {
+                        #other_pat => true #(&& #values == #others)*,
+                        #[allow(unreachable_patterns)]
+                        _ => false,
+                    }
+                }
+            } else {
+                quote! {
+                    let #other_pat = other;
+                    true #(&& #values == #others)*
+                }
+            }
+        });
+        parse_quote! {
+            impl #impl_generics ::std::cmp::PartialEq for #error_type
+            where
+                #(#bounds),*
+            {
+                fn eq(&self, other: &Self) -> bool #body
+            }
+        }
+    }
+
+    /// Implements `Clone` for the generated error type, for the same reason as `Debug`
+    fn clone_impl(&self) -> Item {
+        let error_type = self.error_type_with_generics();
+        let generics = self.error_generics();
+        let (impl_generics, _, _) = generics.split_for_impl();
+        let (bounds, body) = self.error_impl_parts(quote!(::std::clone::Clone), |path, fields| {
+            let (members, values): (Vec<_>, Vec<_>) = fields.error_bindings().into_iter().unzip();
+            quote!(#path { #(#members: ::std::clone::Clone::clone(#values)),* })
+        });
+        parse_quote! {
+            impl #impl_generics ::std::clone::Clone for #error_type
+            where
+                #(#bounds),*
+            {
+                fn clone(&self) -> Self #body
+            }
+        }
+    }
+
+    /// Implements `Display` for the generated error type, listing the errors of the invalid fields
+    fn display_impl(&self) -> Item {
+        let error_type = self.error_type_with_generics();
+        let generics = self.error_generics();
+        let (impl_generics, _, _) = generics.split_for_impl();
+        let (bounds, body) = self.error_impl_parts(quote!(::std::fmt::Display), |_, fields| {
+            let display_fields = fields.display_fields();
+            quote!(::validator::__private::display_fields(f, &[#(#display_fields),*]))
+        });
+        parse_quote! {
+            impl #impl_generics ::std::fmt::Display for #error_type
+            where
+                #(#bounds),*
+            {
+                fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result #body
+            }
+        }
+    }
+
+    /// Implements `Error` for the generated error type, the source being the error of the first invalid field
+    fn error_impl(&self) -> Item {
+        let error_type = self.error_type_with_generics();
+        let generics = self.error_generics();
+        let (impl_generics, _, _) = generics.split_for_impl();
+        let (mut bounds, body) = self.error_impl_parts(quote!(::std::error::Error + 'static), |_, fields| {
+            let sources = fields.sources();
+            quote!(None #(.or(#sources))*)
+        });
+        // the sources are `'static`, which cannot be deduced from the bounds of the errors of the elements of
+        // collections, their type being only known through `HasElements`
+        bounds.extend(generics.type_params().map(|param| -> WherePredicate {
+            let ident = &param.ident;
+            parse_quote!(#ident: 'static)
+        }));
+        parse_quote! {
+            impl #impl_generics ::std::error::Error for #error_type
+            where
+                #(#bounds),*
+            {
+                fn source(&self) -> Option<&(dyn ::std::error::Error + 'static)> #body
+            }
+        }
+    }
+
     fn validator(&self) -> Vec<Item> {
         let Input { data, .. } = self;
         match data {
             InputData::Struct { fields, semi_token } => {
-                let derived_type = &self.name;
                 let name = self.validator_type();
                 let vis = &self.vis;
                 let error = self.error_type();
                 let define_validator_fields = fields.validator_fields();
-                let validate_fields = fields.fields.iter().map(Field::validate_field).collect();
 
-                let error_declaration = Expr::Struct(ExprStruct {
-                    attrs: vec![],
-                    qself: None,
-                    path: Path {
-                        leading_colon: None,
-                        segments: [PathSegment {
-                            ident: error.clone(),
-                            arguments: Default::default(),
-                        }].into_iter().collect(),
-                    },
-                    brace_token: Default::default(),
-                    fields: validate_fields,
-                    dot2_token: None,
-                    rest: None,
-                });
+                let value_pat = if fields.named_fields {
+                    Pat::Struct(PatStruct {
+                        attrs: vec![],
+                        qself: None,
+                        path: Path {
+                            leading_colon: None,
+                            segments: [
+                                PathSegment {
+                                    ident: self.name.clone(),
+                                    arguments: Default::default(),
+                                }
+                            ].into_iter().collect(),
+                        },
+                        brace_token: Default::default(),
+                        fields: fields.fields.iter()
+                            .map(Field::field_pat)
+                            .collect(),
+                        rest: None,
+                    })
+                } else {
+                    Pat::TupleStruct(PatTupleStruct {
+                        attrs: vec![],
+                        qself: None,
+                        path: Path {
+                            leading_colon: None,
+                            segments: [
+                                PathSegment {
+                                    ident: self.name.clone(),
+                                    arguments: Default::default(),
+                                }
+                            ].into_iter().collect(),
+                        },
+                        paren_token: Default::default(),
+                        elems: fields.fields.iter()
+                            .map(Field::pat)
+                            .collect(),
+                    })
+                };
+                // the cross-field rules need the whole value
+                let value_pat = if fields.schema.is_empty() {
+                    value_pat
+                } else {
+                    parse_quote!(value @ #value_pat)
+                };
 
-                vec![
+                let validate_body = |mode: Mode| -> Block {
+                    let error_declaration = fields.error_declaration(
+                        Path {
+                            leading_colon: None,
+                            segments: [PathSegment {
+                                ident: error.clone(),
+                                arguments: Default::default(),
+                            }].into_iter().collect(),
+                        },
+                        mode,
+                    );
+                    let join_validations = fields.join_validations(mode);
+                    parse_quote!(
+                        {
+                            let mut _valid = true;
+                            let validator = self;
+                            #join_validations
+                            let error = #error_declaration;
+                            if _valid {
+                                Ok(())
+                            } else {
+                                Err(error)
+                            }
+                        }
+                    )
+                };
+
+                let mut items = vec![
                     Item::Struct(ItemStruct {
                         attrs: vec![],
                         vis: vis.clone(),
                         struct_token: Default::default(),
                         ident: name.clone(),
-                        generics: Default::default(),
+                        generics: self.validator_generics(),
                         fields: define_validator_fields,
                         semi_token: semi_token.as_ref().cloned(),
                     }),
-                    Item::Impl(ItemImpl {
-                        attrs: vec![],
-                        defaultness: None,
-                        unsafety: None,
-                        impl_token: Default::default(),
-                        generics: Default::default(),
-                        trait_: Some((None, parse_quote!(::validator::Validator<#derived_type>), Default::default())),
-                        self_ty: Box::new(simple_type(name)),
-                        brace_token: Default::default(),
-                        items: vec![
-                            ImplItem::Type(ImplItemType {
-                                attrs: vec![],
-                                vis: Visibility::Inherited,
-                                defaultness: None,
-                                type_token: Default::default(),
-                                ident: Ident::new("Error", Span::call_site()),
-                                generics: Default::default(),
-                                eq_token: Default::default(),
-                                ty: simple_type(error.clone()),
-                                semi_token: Default::default(),
-                            }),
-                            ImplItem::Fn(ImplItemFn {
-                                attrs: vec![],
-                                vis: Visibility::Inherited,
-                                defaultness: None,
-                                sig: Signature {
-                                    constness: None,
-                                    asyncness: None,
-                                    unsafety: None,
-                                    abi: None,
-                                    fn_token: Default::default(),
-                                    ident: Ident::new("validate", Span::call_site()),
-                                    generics: Default::default(),
-                                    paren_token: Default::default(),
-                                    inputs: [
-                                        parse_quote!(&self),
-                                        FnArg::Typed(PatType {
-                                            attrs: vec![],
-                                            pat: Box::new(if fields.named_fields {
-                                                Pat::Struct(PatStruct {
-                                                    attrs: vec![],
-                                                    qself: None,
-                                                    path: Path {
-                                                        leading_colon: None,
-                                                        segments: [
-                                                            PathSegment {
-                                                                ident: self.name.clone(),
-                                                                arguments: Default::default(),
-                                                            }
-                                                        ].into_iter().collect(),
-                                                    },
-                                                    brace_token: Default::default(),
-                                                    fields: fields.fields.iter()
-                                                        .map(Field::field_pat)
-                                                        .collect(),
-                                                    rest: None,
-                                                })
-                                            } else {
-                                                Pat::TupleStruct(PatTupleStruct {
-                                                    attrs: vec![],
-                                                    qself: None,
-                                                    path: Path {
-                                                        leading_colon: None,
-                                                        segments: [
-                                                            PathSegment {
-                                                                ident: self.name.clone(),
-                                                                arguments: Default::default(),
-                                                            }
-                                                        ].into_iter().collect(),
-                                                    },
-                                                    paren_token: Default::default(),
-                                                    elems: fields.fields.iter()
-                                                        .map(Field::pat)
-                                                        .collect(),
-                                                })
-                                            }),
-                                            colon_token: Default::default(),
-                                            ty: Box::new(Type::Reference(TypeReference {
-                                                and_token: Default::default(),
-                                                lifetime: None,
-                                                mutability: None,
-                                                elem: Box::new(simple_type(self.name.clone())),
-                                            })),
-                                        })
-                                    ].into_iter().collect(),
-                                    variadic: None,
-                                    output: parse_quote!(-> Result<(), Self::Error>),
-                                },
-                                block: parse_quote!(
-                                    {
-                                        let mut _valid = true;
-                                        let validator = self;
-                                        let error = #error_declaration;
-                                        if _valid {
-                                            Ok(())
-                                        } else {
-                                            Err(error)
-                                        }
-                                    }
-                                ),
-                            }),
-                        ],
-                    })]
+                ];
+                items.extend(self.validator_impls(value_pat, fields.context_bounds().collect(), validate_body));
+                items
             }
             InputData::Enum { variants } => {
                 let derived_type = self.name.clone();
-                let match_arms = variants.iter()
-                    .enumerate().map(
-                    |(index, EnumVariant { name, fields, .. })| -> Arm {
-                        let Some(fields) = fields.as_ref() else {
-                            return parse_quote!(#derived_type::#name => Ok(()));
-                        };
-                        let error_declaration = Expr::Struct(ExprStruct {
-                            attrs: vec![],
-                            qself: None,
-                            path: Path {
-                                leading_colon: None,
-                                segments: [PathSegment {
-                                    ident: self.error_type(),
-                                    arguments: Default::default(),
-                                }, PathSegment {
-                                    ident: name.clone(),
-                                    arguments: Default::default(),
-                                }].into_iter().collect(),
-                            },
-                            brace_token: Default::default(),
-                            fields: fields.fields.iter()
-                                .map(Field::validate_field)
-                                .collect(),
-                            dot2_token: None,
-                            rest: None,
-                        }
-                        );
-
-                        let index: Index = index.into();
+                let match_arms = |mode: Mode| -> Vec<Arm> {
+                    // the validator only holds the validators of the variants which have fields
+                    let mut validator_index = 0;
+                    variants.iter()
+                        .map(
+                        |EnumVariant { name, fields, .. }| -> Arm {
+                            let Some(fields) = fields.as_ref() else {
+                                return parse_quote!(#derived_type::#name => Ok(()));
+                            };
+                            let index = Index::from(validator_index);
+                            validator_index += 1;
+                            let error_declaration = fields.error_declaration(
+                                Path {
+                                    leading_colon: None,
+                                    segments: [PathSegment {
+                                        ident: self.error_type(),
+                                        arguments: Default::default(),
+                                    }, PathSegment {
+                                        ident: name.clone(),
+                                        arguments: Default::default(),
+                                    }].into_iter().collect(),
+                                },
+                                mode,
+                            );
+                            let join_validations = fields.join_validations(mode);
 
-                        Arm {
-                            attrs: vec![],
-                            pat: if fields.named_fields {
-                                Pat::Struct(PatStruct {
-                                    attrs: vec![],
-                                    qself: None,
-                                    path: Path {
-                                        leading_colon: None,
-                                        segments: [
-                                            PathSegment {
-                                                ident: derived_type.clone(),
+                            Arm {
+                                attrs: vec![],
+                                pat: if fields.named_fields {
+                                    Pat::Struct(PatStruct {
+                                        attrs: vec![],
+                                        qself: None,
+                                        path: Path {
+                                            leading_colon: None,
+                                            segments: [
+                                                PathSegment {
+                                                    ident: derived_type.clone(),
+                                                    arguments: Default::default(),
+                                                },
+                                                PathSegment {
+                                                    ident: name.clone(),
+                                                    arguments: Default::default(),
+                                                }
+                                            ].into_iter().collect(),
+                                        },
+                                        brace_token: Default::default(),
+                                        fields: fields.fields
+                                            .iter()
+                                            .map(Field::field_pat)
+                                            .collect(),
+                                        rest: None,
+                                    })
+                                } else {
+                                    Pat::TupleStruct(PatTupleStruct {
+                                        attrs: vec![],
+                                        qself: None,
+                                        path: Path {
+                                            leading_colon: None,
+                                            segments: [PathSegment {
+                                                ident: self.name.clone(),
                                                 arguments: Default::default(),
-                                            },
-                                            PathSegment {
+                                            }, PathSegment {
                                                 ident: name.clone(),
                                                 arguments: Default::default(),
-                                            }
-                                        ].into_iter().collect(),
-                                    },
-                                    brace_token: Default::default(),
-                                    fields: fields.fields
-                                        .iter()
-                                        .map(Field::field_pat)
-                                        .collect(),
-                                    rest: None,
-                                })
-                            } else {
-                                Pat::TupleStruct(PatTupleStruct {
-                                    attrs: vec![],
-                                    qself: None,
-                                    path: Path {
-                                        leading_colon: None,
-                                        segments: [PathSegment {
-                                            ident: self.name.clone(),
-                                            arguments: Default::default(),
-                                        }, PathSegment {
-                                            ident: name.clone(),
-                                            arguments: Default::default(),
-                                        }].into_iter().collect(),
-                                    },
-                                    paren_token: Default::default(),
-                                    elems: fields.fields
-                                        .iter()
-                                        .map(Field::pat)
-                                        .collect(),
-                                })
-                            },
-                            guard: None,
-                            fat_arrow_token: Default::default(),
-                            body: Box::new(parse_quote!({
-                                let mut _valid = true;
-                                let validator = &self.#index;
-                                let error = #error_declaration;
-                                if _valid {
-                                    Ok(())
-                                } else {
-                                    Err(error)
-                                }
-                            })),
-                            comma: Default::default(),
-                        }
-                    });
+                                            }].into_iter().collect(),
+                                        },
+                                        paren_token: Default::default(),
+                                        elems: fields.fields
+                                            .iter()
+                                            .map(Field::pat)
+                                            .collect(),
+                                    })
+                                },
+                                guard: None,
+                                fat_arrow_token: Default::default(),
+                                body: Box::new(parse_quote!({
+                                    let mut _valid = true;
+                                    let validator = &self.#index;
+                                    #join_validations
+                                    let error = #error_declaration;
+                                    if _valid {
+                                        Ok(())
+                                    } else {
+                                        Err(error)
+                                    }
+                                })),
+                                comma: Default::default(),
+                            }
+                        }).collect()
+                };
 
                 let mut items: Vec<_> = variants.iter()
-                    .filter_map(EnumVariant::validator)
+                    .filter_map(|variant| variant.validator(self))
                     .collect();
 
                 items.push(Item::Struct(ItemStruct {
@@ -512,62 +1188,160 @@ impl Input {
                     vis: self.vis.clone(),
                     struct_token: Default::default(),
                     ident: self.validator_type(),
-                    generics: Default::default(),
+                    generics: self.validator_generics(),
                     fields: Fields::Unnamed(FieldsUnnamed {
                         paren_token: Default::default(),
                         unnamed: variants.iter().filter_map(|variant| {
-                            variant.fields.as_ref()?;
                             Some(syn::Field {
                                 attrs: vec![],
                                 vis: Visibility::Inherited,
                                 mutability: FieldMutability::None,
                                 ident: None,
                                 colon_token: None,
-                                ty: simple_type(variant.validator_name()),
+                                ty: variant.validator_type(self)?,
                             })
                         }).collect(),
                     }),
                     semi_token: Some(Default::default()),
                 }));
 
-                items.push(Item::Impl(ItemImpl {
-                    attrs: vec![],
-                    defaultness: None,
-                    unsafety: None,
-                    impl_token: Default::default(),
-                    generics: Default::default(),
-                    trait_: Some((None, parse_quote!(::validator::Validator<#derived_type>), Default::default())),
-                    self_ty: Box::new(simple_type(self.validator_type())),
-                    brace_token: Default::default(),
-                    items: vec![
-                        ImplItem::Type(ImplItemType {
-                            attrs: vec![],
-                            vis: Visibility::Inherited,
-                            defaultness: None,
-                            type_token: Default::default(),
-                            ident: Ident::new("Error", Span::call_site()),
-                            generics: Default::default(),
-                            eq_token: Default::default(),
-                            ty: simple_type(self.error_type()),
-                            semi_token: Default::default(),
-                        }),
-                        ImplItem::Fn(parse_quote! {
-                                fn validate(&self, value: &#derived_type) -> Result<(), Self::Error> {
-                                    match value {
-                                        #(#match_arms),*
-                                    }
+                let context_bounds = variants.iter()
+                    .filter_map(|variant| variant.fields.as_ref())
+                    .flat_map(StructFields::context_bounds)
+                    .collect();
+                items.extend(self.validator_impls(
+                    parse_quote!(value),
+                    context_bounds,
+                    |mode| {
+                        let match_arms = match_arms(mode);
+                        parse_quote! {
+                            {
+                                match value {
+                                    #(#match_arms),*
                                 }
-                            }),
-                    ],
-                }));
+                            }
+                        }
+                    },
+                ));
 
                 items
             }
         }
     }
 
+    fn mode(&self) -> Mode<'_> {
+        match &self.options.context {
+            _ if self.options.asyncness => Mode::Async,
+            Some(context) => Mode::Context(context),
+            None => Mode::Sync,
+        }
+    }
+
+    /// Implements the validator traits for the generated validator type.
+    ///
+    /// If the derived type requires a context then only `ValidatorWithContext` is implemented for that context,
+    /// and if it is async then only `AsyncValidator` is implemented.
+    /// Otherwise `Validator` is implemented along with `ValidatorWithContext` for any context
+    /// which the field validators accept, so that the context can be passed through this type,
+    /// and `AsyncValidator`, so that this type can be used by async validators.
+    /// In every case `ValidatorExt` is implemented, so that the combinators can be applied to this type.
+    fn validator_impls(&self, value_pat: Pat, context_bounds: Vec<WherePredicate>, body: impl std::ops::Fn(Mode) -> Block) -> Vec<Item> {
+        let derived_type = self.derived_type();
+        let validator_type = self.validator_type_with_generics();
+        let error_type = self.error_type_with_generics();
+        let generics = self.impl_generics(self.mode());
+        let (impl_generics, _, where_clause) = generics.split_for_impl();
+        let ext_impl = parse_quote! {
+            impl #impl_generics ::validator::ValidatorExt for #validator_type #where_clause {}
+        };
+        let mut items = match self.mode() {
+            Mode::Async => {
+                let body = body(Mode::Async);
+                vec![parse_quote! {
+                    impl #impl_generics ::validator::AsyncValidator<#derived_type> for #validator_type #where_clause {
+                        type Error = #error_type;
+                        async fn validate_async(&self, #value_pat: &#derived_type) -> Result<(), Self::Error> #body
+                    }
+                }]
+            }
+            Mode::Context(context) => {
+                let body = body(Mode::Context(context));
+                vec![parse_quote! {
+                    impl #impl_generics ::validator::ValidatorWithContext<#derived_type, #context> for #validator_type #where_clause {
+                        type Error = #error_type;
+                        fn validate_with(&self, #value_pat: &#derived_type, context: &#context) -> Result<(), Self::Error> #body
+                    }
+                }]
+            }
+            Mode::Sync => {
+                let generic_context = simple_type(Ident::new("__C", Span::call_site()));
+                let validation_options = self.options.validation_options();
+                let validate_body = body(Mode::Sync);
+                let validate_with_body = body(Mode::Context(&generic_context));
+                let mut context_generics = generics.clone();
+                context_generics.params.push(parse_quote!(#generic_context: ?Sized));
+                context_generics.make_where_clause().predicates.extend(context_bounds);
+                let (context_impl_generics, _, context_where_clause) = context_generics.split_for_impl();
+                vec![
+                    Item::Impl(ItemImpl {
+                        attrs: vec![],
+                        defaultness: None,
+                        unsafety: None,
+                        impl_token: Default::default(),
+                        generics: generics.clone(),
+                        trait_: Some((None, parse_quote!(::validator::Validator<#derived_type>), Default::default())),
+                        self_ty: Box::new(validator_type.clone()),
+                        brace_token: Default::default(),
+                        items: vec![
+                            ImplItem::Type(ImplItemType {
+                                attrs: vec![],
+                                vis: Visibility::Inherited,
+                                defaultness: None,
+                                type_token: Default::default(),
+                                ident: Ident::new("Error", Span::call_site()),
+                                generics: Default::default(),
+                                eq_token: Default::default(),
+                                ty: error_type.clone(),
+                                semi_token: Default::default(),
+                            }),
+                            ImplItem::Fn(parse_quote! {
+                                fn validate(&self, value: &#derived_type) -> Result<(), Self::Error> {
+                                    ::validator::Validator::validate_with_options(self, value, #validation_options)
+                                }
+                            }),
+                            ImplItem::Fn(parse_quote! {
+                                fn validate_budgeted(&self, #value_pat: &#derived_type, budget: &mut ::validator::ErrorBudget) -> Result<(), Self::Error> #validate_body
+                            }),
+                        ],
+                    }),
+                    parse_quote! {
+                        impl #context_impl_generics ::validator::ValidatorWithContext<#derived_type, #generic_context> for #validator_type
+                        #context_where_clause
+                        {
+                            type Error = #error_type;
+                            fn validate_with(&self, #value_pat: &#derived_type, context: &#generic_context) -> Result<(), Self::Error> #validate_with_body
+                        }
+                    },
+                    parse_quote! {
+                        ::validator::__if_async! {
+                            impl #impl_generics ::validator::AsyncValidator<#derived_type> for #validator_type #where_clause {
+                                type Error = #error_type;
+                                async fn validate_async(&self, value: &#derived_type) -> Result<(), Self::Error> {
+                                    ::validator::Validator::validate(self, value)
+                                }
+                            }
+                        }
+                    },
+                ]
+            }
+        };
+        items.push(ext_impl);
+        items
+    }
+
     fn validate_impl(&self) -> Item {
-        let Input { name: derived_type, data, .. } = self;
+        let Input { data, .. } = self;
+        let derived_type = self.derived_type();
         match data {
             InputData::Struct { fields: StructFields { fields, .. }, .. } => {
                 let create_validator = fields.iter()
@@ -597,13 +1371,13 @@ impl Input {
                     defaultness: None,
                     unsafety: None,
                     impl_token: Default::default(),
-                    generics: Default::default(),
+                    generics: self.impl_generics(self.mode()),
                     trait_: Some((
                         None,
                         parse_quote!(::validator::Validate),
                         Default::default()
                     )),
-                    self_ty: Box::new(simple_type(derived_type.clone())),
+                    self_ty: Box::new(derived_type.clone()),
                     brace_token: Default::default(),
                     items: vec![
                         ImplItem::Type(ImplItemType {
@@ -614,7 +1388,7 @@ impl Input {
                             ident: Ident::new("Validator", Span::call_site()),
                             generics: Default::default(),
                             eq_token: Default::default(),
-                            ty: simple_type(validator_type.clone()),
+                            ty: self.validator_type_with_generics(),
                             semi_token: Default::default(),
                         }),
                         ImplItem::Fn(ImplItemFn {
@@ -638,13 +1412,13 @@ impl Input {
                     defaultness: None,
                     unsafety: None,
                     impl_token: Default::default(),
-                    generics: Default::default(),
+                    generics: self.impl_generics(self.mode()),
                     trait_: Some((
                         None,
                         parse_quote!(::validator::Validate),
                         Default::default()
                     )),
-                    self_ty: Box::new(simple_type(derived_type.clone())),
+                    self_ty: Box::new(derived_type.clone()),
                     brace_token: Default::default(),
                     items: vec![
                         ImplItem::Type(ImplItemType {
@@ -655,7 +1429,7 @@ impl Input {
                             ident: Ident::new("Validator", Span::call_site()),
                             generics: Default::default(),
                             eq_token: Default::default(),
-                            ty: simple_type(self.validator_type()),
+                            ty: self.validator_type_with_generics(),
                             semi_token: Default::default(),
                         }),
                         ImplItem::Fn(ImplItemFn {
@@ -715,6 +1489,27 @@ fn named_fields(fields: impl Iterator<Item=syn::Field>) -> Fields {
     })
 }
 
+fn member_label(member: &Member) -> String {
+    match member {
+        Member::Named(name) => name.unraw().to_string(),
+        Member::Unnamed(index) => index.index.to_string(),
+    }
+}
+
+// the segment of the path to the given field of the validated value
+fn path_segment(member: &Member) -> Expr {
+    match member {
+        Member::Named(_) => {
+            let name = member_label(member);
+            parse_quote!(::validator::PathSegment::Field(#name.into()))
+        }
+        Member::Unnamed(index) => {
+            let index = index.index as usize;
+            parse_quote!(::validator::PathSegment::Index(#index))
+        }
+    }
+}
+
 fn simple_type(name: Ident) -> Type {
     Type::Path(TypePath {
         qself: None,
@@ -752,8 +1547,8 @@ impl Field {
         })
     }
 
-    fn error_field(&self) -> syn::Field {
-        let error_type = self.validator.error_type(&self.ty);
+    fn error_field(&self, mode: Mode) -> syn::Field {
+        let error_type = self.validator.error_type(&self.ty, mode);
         let ty = parse_quote!(Option<#error_type>);
         self.field(ty)
     }
@@ -762,12 +1557,20 @@ impl Field {
         self.field(self.validator.validator_type(&self.ty))
     }
 
-    fn validate_field(&self) -> FieldValue {
+    fn validate_field(&self, mode: Mode, index: usize) -> FieldValue {
         let name = self.name.clone();
         let value = self.pattern_name();
+        let validate: Expr = match mode {
+            Mode::Sync => parse_quote!(validator.#name.validate_budgeted(#value, budget)),
+            Mode::Context(_) => parse_quote!(::validator::ValidatorWithContext::validate_with(&validator.#name, #value, context)),
+            Mode::Async => {
+                let index = Index::from(index);
+                parse_quote!(__results.#index)
+            }
+        };
         let expr = parse_quote!(
                 {
-                    match validator.#name.validate(#value) {
+                    match #validate {
                         Ok(()) => None,
                         Err(error) => {
                             _valid = false;
@@ -783,6 +1586,98 @@ impl Field {
         self.field_value(self.validator.create(&self.ty))
     }
 
+    fn context_bound(&self) -> WherePredicate {
+        let ty = &self.ty;
+        let validator_type = self.validator.validator_type(ty);
+        let error_type = self.validator.error_type(ty, Mode::Sync);
+        parse_quote!(#validator_type: ::validator::ValidatorWithContext<#ty, __C, Error = #error_type>)
+    }
+
+    /// The bounds needed to validate this field in the given mode, if its type uses any of the given type parameters
+    fn bounds(&self, mode: Mode, type_params: &HashSet<String>) -> Vec<WherePredicate> {
+        let mut names = HashSet::new();
+        collect_names(self.ty.to_token_stream(), &mut names);
+        if names.is_disjoint(type_params) {
+            return vec![];
+        }
+        let ty = &self.ty;
+        let mut bounds = vec![];
+        self.validator.bounds(ty, mode, &mut bounds);
+        // the error is given so that the compiler does not lose it when the validator is found in the bounds
+        if !matches!(self.validator, Validator::Default) {
+            let validator_type = self.validator.validator_type(ty);
+            let error_type = self.validator.error_type(ty, mode);
+            let validator_trait = validator_trait(ty, mode, Some(&error_type));
+            bounds.push(parse_quote!(#validator_type: #validator_trait));
+        }
+        bounds
+    }
+
+    /// The type whose schema the rules are described in, which is the value of an `Option`
+    fn schema_type(&self) -> &Type {
+        option_inner(&self.ty).unwrap_or(&self.ty)
+    }
+
+    /// The JSON Schema of the field, in which its rules are described
+    fn json_schema(&self) -> Expr {
+        let ty = self.schema_type();
+        let schema: Expr = match self.validator.describe_schema() {
+            None => parse_quote!(defs.subschema::<#ty>()),
+            Some(describe) => parse_quote! {
+                {
+                    let mut __schema = defs.subschema::<#ty>();
+                    {
+                        let schema = &mut __schema;
+                        #describe
+                    }
+                    __schema
+                }
+            },
+        };
+        // a required `Option` rejects `None`, so its schema does not accept `null`
+        if option_inner(&self.ty).is_some() && !self.validator.requires_value() {
+            parse_quote!(::validator::nullable_schema(#schema))
+        } else {
+            schema
+        }
+    }
+
+    // an `Option` may be left out unless it is required
+    fn is_required(&self) -> bool {
+        option_inner(&self.ty).is_none() || self.validator.requires_value()
+    }
+
+    // the name of the field as shown to users
+    fn label(&self) -> String {
+        member_label(&self.name)
+    }
+
+    fn display_field(&self) -> ExprTuple {
+        let label = self.label();
+        let value = self.pattern_name();
+        parse_quote!((#label, #value.as_ref().map(|error| error as &dyn ::std::fmt::Display)))
+    }
+
+    fn source(&self) -> Expr {
+        let value = self.pattern_name();
+        parse_quote!(#value.as_ref().map(|error| error as &(dyn ::std::error::Error + 'static)))
+    }
+
+    fn collect_error(&self) -> Stmt {
+        let value = self.pattern_name();
+        let segment = path_segment(&self.name);
+        parse_quote! {
+            if let Some(error) = #value {
+                path.collect_nested(#segment, error, errors);
+            }
+        }
+    }
+
+    fn error_bound(&self, mode: Mode, bound: &TokenStream) -> WherePredicate {
+        let error_type = self.validator.error_type(&self.ty, mode);
+        parse_quote!(for<'__a> #error_type: #bound)
+    }
+
     fn field(&self, ty: Type) -> syn::Field {
         let (name, colon) = match &self.name {
             Member::Named(name) => (Some(name), Some(parse_quote!(:))),
@@ -838,34 +1733,279 @@ impl EnumVariant {
         }))
     }
 
-    fn validator(&self) -> Option<Item> {
-        let fields = self.fields.as_ref()?;
-        Some(Item::Struct(
-            ItemStruct {
-                attrs: vec![
-                    parse_quote!(#[allow(non_camel_case_types)]),
-                    parse_quote!(#[doc(hidden)]),
-                ],
-                vis: Visibility::Inherited,
-                struct_token: Default::default(),
-                ident: self.validator_name(),
-                generics: Default::default(),
-                fields: fields.validator_fields(),
-                semi_token: if fields.named_fields { None } else { Some(Semi::default()) },
+    /// The JSON Schema of this variant, represented as its name or as an object holding its fields under its name
+    fn json_schema(&self) -> Expr {
+        let name = self.name.unraw().to_string();
+        match &self.fields {
+            None => parse_quote!(::validator::variant_schema(#name, None)),
+            Some(fields) => {
+                let fields = fields.json_schema();
+                parse_quote!(::validator::variant_schema(#name, Some(#fields)))
+            }
+        }
+    }
+
+    /// The validator type of this variant along with its generic arguments, if it has any fields
+    fn validator_type(&self, input: &Input) -> Option<Type> {
+        let name = self.validator_name();
+        let generics = input.used_generics(self.fields.as_ref()?.validator_types());
+        let (_, type_generics, _) = generics.split_for_impl();
+        Some(parse_quote!(#name #type_generics))
+    }
+
+    fn validator(&self, input: &Input) -> Option<Item> {
+        let fields = self.fields.as_ref()?;
+        Some(Item::Struct(
+            ItemStruct {
+                attrs: vec![
+                    parse_quote!(#[allow(non_camel_case_types)]),
+                    parse_quote!(#[doc(hidden)]),
+                ],
+                vis: Visibility::Inherited,
+                struct_token: Default::default(),
+                ident: self.validator_name(),
+                generics: input.used_generics(fields.validator_types()),
+                fields: fields.validator_fields(),
+                semi_token: if fields.named_fields { None } else { Some(Semi::default()) },
+            }
+        ))
+    }
+    fn error_variant(&self, mode: Mode) -> Option<Variant> {
+        let fields = self.fields.as_ref()?;
+        Some(Variant {
+            attrs: vec![],
+            ident: self.name.clone(),
+            fields: fields.error_definition(mode),
+            discriminant: None,
+        })
+    }
+}
+
+fn container_options_from_attrs(attrs: &[Attribute]) -> syn::Result<ContainerOptions> {
+    let mut options = ContainerOptions::default();
+    for attr in attrs {
+        let Meta::List(list) = &attr.meta else { continue };
+        let path = &list.path.segments;
+        if path.len() != 1 || path[0].ident != "validator" {
+            continue;
+        }
+        list.parse_args_with(|input: ParseStream| options.parse_options(input))?;
+    }
+    Ok(options)
+}
+
+impl ContainerOptions {
+    fn validation_options(&self) -> Expr {
+        if self.fail_fast {
+            parse_quote!(::validator::ValidationOptions::fail_fast())
+        } else if let Some(max_errors) = &self.max_errors {
+            parse_quote!(::validator::ValidationOptions::max_errors(#max_errors))
+        } else {
+            parse_quote!(::validator::ValidationOptions::default())
+        }
+    }
+
+    fn parse_options(&mut self, input: ParseStream) -> syn::Result<()> {
+        loop {
+            if let Some(asyncness) = input.parse::<Option<Token![async]>>()? {
+                if self.asyncness {
+                    return Err(syn::Error::new(asyncness.span, "async may only be set once"));
+                }
+                self.asyncness = true;
+                if self.context.is_some() {
+                    return Err(syn::Error::new(asyncness.span, "async validators do not support a context"));
+                }
+                if input.is_empty() {
+                    return Ok(());
+                }
+                input.parse::<Comma>()?;
+                continue;
+            }
+            let name: Ident = input.parse().with_message("failed to parse container option name")?;
+            match name.to_string().as_str() {
+                "context" => {
+                    input.parse::<Token![=]>().with_message("failed to parse context '=' token")?;
+                    if self.context.is_some() {
+                        return Err(syn::Error::new(name.span(), "context may only be set once"));
+                    }
+                    if self.asyncness {
+                        return Err(syn::Error::new(name.span(), "async validators do not support a context"));
+                    }
+                    self.context = Some(input.parse().with_message("failed to parse context type")?);
+                }
+                "fail_fast" => {
+                    if self.fail_fast || self.max_errors.is_some() {
+                        return Err(syn::Error::new(name.span(), "only one of 'fail_fast' and 'max_errors' may be set"));
+                    }
+                    self.fail_fast = true;
+                }
+                "max_errors" => {
+                    input.parse::<Token![=]>().with_message("failed to parse max_errors '=' token")?;
+                    if self.fail_fast || self.max_errors.is_some() {
+                        return Err(syn::Error::new(name.span(), "only one of 'fail_fast' and 'max_errors' may be set"));
+                    }
+                    let max_errors: LitInt = input.parse().with_message("failed to parse max_errors value")?;
+                    if max_errors.base10_parse::<usize>()? == 0 {
+                        return Err(syn::Error::new(max_errors.span(), "max_errors must be at least 1"));
+                    }
+                    self.max_errors = Some(max_errors);
+                }
+                "bound" => {
+                    input.parse::<Token![=]>().with_message("failed to parse bound '=' token")?;
+                    if self.bound.is_some() {
+                        return Err(syn::Error::new(name.span(), "bound may only be set once"));
+                    }
+                    let bound: LitStr = input.parse().with_message("failed to parse bound value")?;
+                    let predicates = bound.parse_with(Punctuated::<WherePredicate, Comma>::parse_terminated)?;
+                    self.bound = Some(predicates.into_iter().collect());
+                }
+                "schema" => {
+                    input.parse::<Token![=]>().with_message("failed to parse schema '=' token")?;
+                    let function = input.parse().with_message("failed to parse schema function path")?;
+                    self.schema.push(SchemaRule::Function(function));
+                }
+                "must_match" => {
+                    let content;
+                    parenthesized!(content in input);
+                    let field = content.parse().with_message("failed to parse must_match field")?;
+                    content.parse::<Comma>().with_message("failed to parse must_match comma")?;
+                    let other = content.parse().with_message("failed to parse must_match other field")?;
+                    self.schema.push(SchemaRule::MustMatch(field, other));
+                }
+                "required_if" => {
+                    let content;
+                    parenthesized!(content in input);
+                    let field = content.parse().with_message("failed to parse required_if field")?;
+                    content.parse::<Comma>().with_message("failed to parse required_if comma")?;
+                    let condition = content.parse().with_message("failed to parse required_if condition field")?;
+                    content.parse::<Token![=]>().with_message("failed to parse required_if '=' token")?;
+                    let value = content.parse().with_message("failed to parse required_if value")?;
+                    self.schema.push(SchemaRule::RequiredIf { field, condition, value });
+                }
+                "exactly_one_of" | "at_least_one_of" => {
+                    let content;
+                    parenthesized!(content in input);
+                    let fields: Vec<Member> = content.parse_terminated(Member::parse, Comma)
+                        .with_message("failed to parse fields")?
+                        .into_iter()
+                        .collect();
+                    if fields.len() < 2 {
+                        return Err(syn::Error::new(name.span(), format!("{name} requires at least two fields")));
+                    }
+                    self.schema.push(if name == "exactly_one_of" {
+                        SchemaRule::ExactlyOneOf(fields)
+                    } else {
+                        SchemaRule::AtLeastOneOf(fields)
+                    });
+                }
+                other => return Err(syn::Error::new(name.span(), format!(r#"unknown container option: "{other}""#)))
+            }
+            if (self.fail_fast || self.max_errors.is_some()) && (self.asyncness || self.context.is_some()) {
+                return Err(syn::Error::new(name.span(), "'fail_fast' and 'max_errors' are not supported with a context or async validators"));
+            }
+            if input.is_empty() {
+                return Ok(());
+            }
+            input.parse::<Comma>()?;
+            if input.is_empty() {
+                return Ok(());
+            }
+        }
+    }
+}
+
+impl SchemaRule {
+    // the check of this rule, the validated value being bound to `value`
+    fn check(&self) -> Expr {
+        match self {
+            SchemaRule::Function(function) => {
+                parse_quote!(#function(value).map_err(::validator::SchemaError::from))
             }
-        ))
-    }
-    fn error_variant(&self) -> Option<Variant> {
-        let fields = self.fields.as_ref()?;
-        Some(Variant {
-            attrs: vec![],
-            ident: self.name.clone(),
-            fields: fields.error_definition(),
-            discriminant: None,
-        })
+            SchemaRule::MustMatch(field, other) => {
+                let field_segment = path_segment(field);
+                let other_segment = path_segment(other);
+                parse_quote! {
+                    if value.#field == value.#other {
+                        Ok(())
+                    } else {
+                        Err(::validator::SchemaError::Mismatch { field: #field_segment, other: #other_segment })
+                    }
+                }
+            }
+            SchemaRule::RequiredIf { field, condition, value: expected } => {
+                let field_segment = path_segment(field);
+                let condition_segment = path_segment(condition);
+                let expected_label = expected.to_token_stream().to_string().replace(" :: ", "::");
+                parse_quote! {
+                    if value.#condition != #expected || ::validator::Presence::is_present(&value.#field) {
+                        Ok(())
+                    } else {
+                        Err(::validator::SchemaError::Required {
+                            field: #field_segment,
+                            condition: #condition_segment,
+                            value: #expected_label.into(),
+                        })
+                    }
+                }
+            }
+            SchemaRule::ExactlyOneOf(fields) => {
+                let segments = fields.iter().map(path_segment);
+                parse_quote! {
+                    match [#(::validator::Presence::is_present(&value.#fields)),*].into_iter().filter(|present| *present).count() {
+                        1 => Ok(()),
+                        present => Err(::validator::SchemaError::NotExactlyOne { fields: vec![#(#segments),*], present }),
+                    }
+                }
+            }
+            SchemaRule::AtLeastOneOf(fields) => {
+                let segments = fields.iter().map(path_segment);
+                parse_quote! {
+                    if #(::validator::Presence::is_present(&value.#fields))||* {
+                        Ok(())
+                    } else {
+                        Err(::validator::SchemaError::NoneSet { fields: vec![#(#segments),*] })
+                    }
+                }
+            }
+        }
     }
 }
 
+/// Parses the unit of a length rule, such as `unit = "chars"`, into the name of its variant of `LengthUnit`
+fn parse_length_unit(input: ParseStream) -> syn::Result<Ident> {
+    let unit: LitStr = input.parse().with_message("failed to parse length unit")?;
+    let variant = match unit.value().as_str() {
+        "bytes" => "Bytes",
+        "chars" => "Chars",
+        "graphemes" => "Graphemes",
+        "utf16" => "Utf16",
+        other => return Err(syn::Error::new(
+            unit.span(),
+            format!(r#"unknown length unit: "{other}", expected one of "bytes", "chars", "graphemes", "utf16""#),
+        )),
+    };
+    Ok(Ident::new(variant, unit.span()))
+}
+
+/// Parses a character class of a `chars` rule, such as `ascii`, as the name of its variant
+fn parse_char_class(class: &Ident) -> syn::Result<Ident> {
+    let variant = match class.to_string().as_str() {
+        "ascii" => "Ascii",
+        "alphabetic" => "Alphabetic",
+        "alphanumeric" => "Alphanumeric",
+        "numeric" => "Numeric",
+        "lowercase" => "Lowercase",
+        "uppercase" => "Uppercase",
+        "non_control" => "NonControl",
+        "printable" => "Printable",
+        other => return Err(syn::Error::new(
+            class.span(),
+            format!(r#"unknown character class: "{other}", expected one of "ascii", "alphabetic", "alphanumeric", "numeric", "lowercase", "uppercase", "non_control", "printable""#),
+        )),
+    };
+    Ok(Ident::new(variant, class.span()))
+}
+
 fn validator_from_attrs(attrs: Vec<Attribute>) -> Result<Validator, syn::Error> {
     let attr: Vec<_> = attrs.iter().filter(|attr| {
         if let Meta::List(list) = &attr.meta {
@@ -887,6 +2027,36 @@ fn validator_from_attrs(attrs: Vec<Attribute>) -> Result<Validator, syn::Error>
 
 impl Parse for Validator {
     fn parse(input: ParseStream) -> syn::Result<Self> {
+        let validator = Self::parse_rule(input)?;
+        let mut code = None;
+        let mut message = None;
+        // the options follow the rule, they are told apart from the next rule of a combinator by their names
+        while input.peek(Token![,]) && input.peek2(syn::Ident) && input.peek3(Token![=]) {
+            let fork = input.fork();
+            fork.parse::<Token![,]>()?;
+            let name: Ident = fork.parse()?;
+            let option = match name.to_string().as_str() {
+                "code" => &mut code,
+                "message" => &mut message,
+                _ => break,
+            };
+            input.parse::<Token![,]>()?;
+            input.parse::<Ident>()?;
+            input.parse::<Token![=]>()?;
+            if option.is_some() {
+                return Err(syn::Error::new(name.span(), format!(r#""{name}" may only be set once"#)));
+            }
+            *option = Some(input.parse().with_message(&format!("failed to parse {name}"))?);
+        }
+        if code.is_none() && message.is_none() {
+            return Ok(validator);
+        }
+        Ok(Validator::Message { validator: Box::new(validator), code, message })
+    }
+}
+
+impl Validator {
+    fn parse_rule(input: ParseStream) -> syn::Result<Self> {
         let ident: Ident = input.parse().with_message("failed to parse validator type")?;
         let val_type = ident.to_string();
         match val_type.as_str() {
@@ -895,6 +2065,7 @@ impl Parse for Validator {
             "url" => Ok(Validator::Url),
             "ip" => Ok(Validator::IpAddr),
             "ignore" => Ok(Validator::Ignore),
+            "required" => Ok(Validator::Required),
             "elements" => Ok(Validator::Elements({
                 Box::new(if input.peek(token::Paren) {
                     let content;
@@ -904,6 +2075,28 @@ impl Parse for Validator {
                     Validator::Default
                 })
             })),
+            "keys" | "values" => {
+                let validator = Box::new(if input.peek(token::Paren) {
+                    let content;
+                    parenthesized!(content in input);
+                    content.parse()?
+                } else {
+                    Validator::Default
+                });
+                Ok(match val_type.as_str() {
+                    "keys" => Validator::Keys(validator),
+                    "values" => Validator::Values(validator),
+                    _ => unreachable!()
+                })
+            }
+            "entries" => {
+                let content;
+                parenthesized!(content in input);
+                let keys = content.parse().with_message("failed to parse entries keys")?;
+                let _ = content.parse::<Token![,]>().with_message("failed to parse entries comma")?;
+                let values = content.parse().with_message("failed to parse entries values")?;
+                Ok(Validator::Entries(Box::new(keys), Box::new(values)))
+            }
             "length" => {
                 let content;
                 parenthesized!(content in input);
@@ -911,9 +2104,17 @@ impl Parse for Validator {
                 let mut equal = None;
                 let mut min = None;
                 let mut max = None;
+                let mut unit = None;
                 loop {
                     let name: Ident = content.parse().with_message("failed to parse length option name")?;
                     content.parse::<Token![=]>().with_message("failed to parse length option '=' token")?;
+                    if name == "unit" {
+                        unit = Some(parse_length_unit(&content)?);
+                        if Comma::parse(&content).is_err() {
+                            break;
+                        }
+                        continue;
+                    }
                     let value: LitInt = content.parse().with_message("failed to parse length option value")?;
                     let value: usize = value.base10_parse().map_err(|err| {
                         syn::Error::new(value.span(), format!("failed to parse usize: {err}"))
@@ -940,14 +2141,39 @@ impl Parse for Validator {
                         ));
                     }
 
-                    Ok(Validator::Length(Some(equal), Some(equal)))
+                    Ok(Validator::Length(Some(equal), Some(equal), unit))
                 } else if min.is_some() || max.is_some() {
-                    Ok(Validator::Length(min, max))
+                    Ok(Validator::Length(min, max, unit))
                 } else {
                     Err(syn::Error::new(val_type.span(), "no options found, one of 'equal', 'min', 'max' must be set"))
                 }
             }
-            "and" | "or" => {
+            "chars" => {
+                let content;
+                parenthesized!(content in input);
+                let mut classes = Vec::new();
+                let mut extra = None;
+                let mut trimmed = false;
+                while !content.is_empty() {
+                    let name: Ident = content.parse().with_message("failed to parse chars option name")?;
+                    if name == "extra" {
+                        content.parse::<Token![=]>().with_message("failed to parse chars extra '=' token")?;
+                        extra = Some(content.parse().with_message("failed to parse chars extra characters")?);
+                    } else if name == "trimmed" {
+                        trimmed = true;
+                    } else {
+                        classes.push(parse_char_class(&name)?);
+                    }
+                    if Comma::parse(&content).is_err() {
+                        break;
+                    }
+                }
+                if classes.is_empty() && extra.is_none() && !trimmed {
+                    return Err(syn::Error::new(ident.span(), "no options found, a character class or 'trimmed' must be set"));
+                }
+                Ok(Validator::Chars { classes, extra, trimmed })
+            }
+            "and" | "or" | "then" => {
                 let content;
                 parenthesized!(content in input);
                 let left = content.parse().with_message("failed to parse binary left")?;
@@ -956,6 +2182,40 @@ impl Parse for Validator {
                 Ok(match val_type.as_str() {
                     "and" => Validator::And(Box::new(left), Box::new(right)),
                     "or" => Validator::Or(Box::new(left), Box::new(right)),
+                    "then" => Validator::Then(Box::new(left), Box::new(right)),
+                    _ => unreachable!()
+                })
+            }
+            "not" => {
+                let TokenTree::Group(group) = input.parse()? else {
+                    return Err(syn::Error::new(ident.span(), "expected the negated rule in parentheses"));
+                };
+                // the rule is named as written, the tokens being printed when the source is not available
+                let rule = group.span().source_text()
+                    .and_then(|source| Some(source.strip_prefix('(')?.strip_suffix(')')?.trim().to_string()))
+                    .unwrap_or_else(|| group.stream().to_string());
+                let validator = Validator::parse.parse2(group.stream()).with_message("failed to parse negated rule")?;
+                Ok(Validator::Not(Box::new(validator), rule))
+            }
+            "all" | "any" | "exactly_one" => {
+                let content;
+                parenthesized!(content in input);
+                let mut children = Vec::new();
+                while !content.is_empty() {
+                    let child = content.parse()
+                        .with_message(&format!("failed to parse {val_type} child {i}", i = children.len()))?;
+                    children.push(child);
+                    if Comma::parse(&content).is_err() {
+                        break;
+                    }
+                }
+                if !(2..=12).contains(&children.len()) {
+                    return Err(syn::Error::new(ident.span(), format!("{val_type} expects between 2 and 12 rules")));
+                }
+                Ok(match val_type.as_str() {
+                    "all" => Validator::AllOf(children),
+                    "any" => Validator::AnyOf(children),
+                    "exactly_one" => Validator::ExactlyOne(children),
                     _ => unreachable!()
                 })
             }
@@ -979,6 +2239,92 @@ impl Parse for Validator {
                 let range: ExprRange = content.parse()?;
                 Ok(Validator::Range(range))
             }
+            "regex" => {
+                if input.peek(Token![=]) {
+                    input.parse::<Token![=]>()?;
+                    let pattern: LitStr = input.parse().with_message("failed to parse regex pattern")?;
+                    if let Err(err) = regex::Regex::new(&pattern.value()) {
+                        return Err(syn::Error::new(pattern.span(), format!("invalid regex: {err}")));
+                    }
+                    return Ok(Validator::Regex(RegexSource::Pattern(pattern)));
+                }
+                let content;
+                parenthesized!(content in input);
+                let name: Ident = content.parse().with_message("failed to parse regex option name")?;
+                if name != "path" {
+                    return Err(syn::Error::new(name.span(), format!(r#"unknown option: "{name}", expected "path""#)));
+                }
+                content.parse::<Token![=]>().with_message("failed to parse regex path '=' token")?;
+                let path = content.parse().with_message("failed to parse regex path")?;
+                Ok(Validator::Regex(RegexSource::Path(path)))
+            }
+            "contains" | "starts_with" | "ends_with" => {
+                let (value, ignore_case) = if input.peek(Token![=]) {
+                    input.parse::<Token![=]>()?;
+                    (input.parse().with_message(&format!("failed to parse {val_type} value"))?, false)
+                } else {
+                    let content;
+                    parenthesized!(content in input);
+                    let (mut values, ignore_case) = parse_values(&content, &val_type)?;
+                    if values.len() != 1 {
+                        return Err(syn::Error::new(ident.span(), format!("{val_type} expects a single value")));
+                    }
+                    (values.remove(0), ignore_case)
+                };
+                Ok(match val_type.as_str() {
+                    "contains" => Validator::Contains(value, ignore_case),
+                    "starts_with" => Validator::StartsWith(value, ignore_case),
+                    "ends_with" => Validator::EndsWith(value, ignore_case),
+                    _ => unreachable!()
+                })
+            }
+            "one_of" | "none_of" => {
+                let content;
+                parenthesized!(content in input);
+                let (values, ignore_case) = parse_values(&content, &val_type)?;
+                if values.is_empty() {
+                    return Err(syn::Error::new(ident.span(), format!("{val_type} expects at least one value")));
+                }
+                Ok(match val_type.as_str() {
+                    "one_of" => Validator::OneOf(values, ignore_case),
+                    "none_of" => Validator::NoneOf(values, ignore_case),
+                    _ => unreachable!()
+                })
+            }
+            "custom" => {
+                if input.peek(Token![=]) {
+                    input.parse::<Token![=]>()?;
+                    let function = input.parse().with_message("failed to parse custom function path")?;
+                    return Ok(Validator::Custom { function, error: parse_quote!(::validator::CustomError) });
+                }
+                let content;
+                parenthesized!(content in input);
+                let function = content.parse().with_message("failed to parse custom function path")?;
+                content.parse::<Token![,]>().with_message("failed to parse custom comma")?;
+                let name: Ident = content.parse().with_message("failed to parse custom option name")?;
+                if name != "error" {
+                    return Err(syn::Error::new(name.span(), format!(r#"unknown option: "{name}", expected "error""#)));
+                }
+                content.parse::<Token![=]>().with_message("failed to parse custom error '=' token")?;
+                let error = content.parse().with_message("failed to parse custom error type")?;
+                Ok(Validator::Custom { function, error })
+            }
+            "with" => {
+                if input.peek(Token![=]) {
+                    input.parse::<Token![=]>()?;
+                    let validator: Expr = input.parse().with_message("failed to parse validator expression")?;
+                    let ty = infer_validator_type(&validator)?;
+                    return Ok(Validator::With { validator, ty });
+                }
+                let content;
+                parenthesized!(content in input);
+                let validator = content.parse().with_message("failed to parse validator expression")?;
+                content.parse::<Token![,]>().with_message("failed to parse with comma")?;
+                content.parse::<Token![type]>().with_message("expected the 'type' option")?;
+                content.parse::<Token![=]>().with_message("failed to parse with type '=' token")?;
+                let ty = content.parse().with_message("failed to parse validator type")?;
+                Ok(Validator::With { validator, ty })
+            }
             other => {
                 Err(syn::Error::new(ident.span(), format!("unknown validator type: \"{other}\"")))
             }
@@ -986,8 +2332,252 @@ impl Parse for Validator {
     }
 }
 
+/// Parses the values of a content rule separated by commas, followed by the `ignore_case` flag if the case is ignored
+fn parse_values(input: ParseStream, rule: &str) -> syn::Result<(Vec<Expr>, bool)> {
+    let mut values = Vec::new();
+    while !input.is_empty() {
+        let fork = input.fork();
+        if fork.parse::<Ident>().is_ok_and(|flag| flag == "ignore_case") && fork.is_empty() {
+            input.parse::<Ident>()?;
+            return Ok((values, true));
+        }
+        values.push(input.parse().with_message(&format!("failed to parse {rule} value {i}", i = values.len()))?);
+        if Comma::parse(input).is_err() {
+            break;
+        }
+    }
+    Ok((values, false))
+}
+
+/// The type of the set of values of a `one_of` or `none_of` rule, which are `&'static str` if any of them is a string
+/// literal and of the validated type otherwise
+fn value_set_type(values: &[Expr], ignore_case: bool, ty: &Type) -> Type {
+    let is_str = |value: &Expr| matches!(value, Expr::Lit(ExprLit { lit: Lit::Str(_), .. }));
+    let element: Type = if values.iter().any(is_str) {
+        parse_quote!(&'static str)
+    } else {
+        ty.clone()
+    };
+    let len = values.len();
+    if ignore_case {
+        parse_quote!(::validator::IgnoreCase<[#element; #len]>)
+    } else {
+        parse_quote!([#element; #len])
+    }
+}
+
+/// Describes the values of a `one_of` or `none_of` rule if they are literals, other values not being known to be
+/// serialisable
+fn describe_values(describe: TokenStream, values: &[Expr], ignore_case: bool) -> Option<TokenStream> {
+    if ignore_case || !values.iter().all(|value| matches!(value, Expr::Lit(_))) {
+        return None;
+    }
+    Some(quote!(#describe(schema, ::std::vec![#(::validator::__private::serde_json::json!(#values)),*]);))
+}
+
 impl Validator {
+    /// Adds the bounds needed for the types of this validator and of its error to be well-formed
+    /// when the validated type is generic
+    fn bounds(&self, ty: &Type, mode: Mode, bounds: &mut Vec<WherePredicate>) {
+        if let Some(inner) = self.unwrapped_option(ty) {
+            return self.bounds(inner, mode, bounds);
+        }
+        match self {
+            Validator::Default => {
+                let validator_trait = validator_trait(ty, mode, None);
+                bounds.push(parse_quote!(#ty: ::validator::Validate));
+                bounds.push(parse_quote!(<#ty as ::validator::Validate>::Validator: #validator_trait));
+            }
+            Validator::Elements(elements) => {
+                bounds.push(parse_quote!(#ty: ::validator::HasElements));
+                elements.bounds(&parse_quote!(<#ty as ::validator::HasElements>::Item), mode, bounds);
+            }
+            Validator::Keys(keys) => {
+                bounds.push(parse_quote!(#ty: ::validator::HasEntries));
+                keys.bounds(&parse_quote!(<#ty as ::validator::HasEntries>::Key), mode, bounds);
+            }
+            Validator::Values(values) => {
+                bounds.push(parse_quote!(#ty: ::validator::HasEntries));
+                values.bounds(&parse_quote!(<#ty as ::validator::HasEntries>::Value), mode, bounds);
+            }
+            Validator::Entries(keys, values) => {
+                bounds.push(parse_quote!(#ty: ::validator::HasEntries));
+                keys.bounds(&parse_quote!(<#ty as ::validator::HasEntries>::Key), mode, bounds);
+                values.bounds(&parse_quote!(<#ty as ::validator::HasEntries>::Value), mode, bounds);
+            }
+            Validator::And(left, right) | Validator::Or(left, right) | Validator::Then(left, right) => {
+                left.bounds(ty, mode, bounds);
+                right.bounds(ty, mode, bounds);
+            }
+            Validator::Not(validator, _) => validator.bounds(ty, mode, bounds),
+            Validator::AllOf(children) | Validator::AnyOf(children) | Validator::ExactlyOne(children) => {
+                for child in children {
+                    child.bounds(ty, mode, bounds);
+                }
+            }
+            Validator::Tuple(children) => {
+                for (index, child) in children.iter().enumerate() {
+                    child.bounds(tuple_element(ty, index), mode, bounds);
+                }
+            }
+            Validator::Message { validator, .. } => validator.bounds(ty, mode, bounds),
+            _ => {}
+        }
+    }
+
+    /// The type of the value of an `Option`, if the built-in rule applies to it rather than to the `Option`
+    fn unwrapped_option<'a>(&self, ty: &'a Type) -> Option<&'a Type> {
+        match self {
+            Validator::NotEmpty
+            | Validator::Email
+            | Validator::Url
+            | Validator::IpAddr
+            | Validator::Length(_, _, _)
+            | Validator::Chars { .. }
+            | Validator::Not(_, _)
+            | Validator::Elements(_)
+            | Validator::Keys(_)
+            | Validator::Values(_)
+            | Validator::Entries(_, _)
+            | Validator::Tuple(_)
+            | Validator::Range(_)
+            | Validator::Regex(_)
+            | Validator::Contains(_, _)
+            | Validator::StartsWith(_, _)
+            | Validator::EndsWith(_, _)
+            | Validator::OneOf(_, _)
+            | Validator::NoneOf(_, _) => option_inner(ty),
+            _ => None,
+        }
+    }
+
+    /// Whether the rule fails for values which are not set, such as `None`
+    fn requires_value(&self) -> bool {
+        match self {
+            Validator::Required => true,
+            Validator::And(left, right) | Validator::Then(left, right) => left.requires_value() || right.requires_value(),
+            Validator::AllOf(children) => children.iter().any(Validator::requires_value),
+            Validator::Message { validator, .. } => validator.requires_value(),
+            _ => false,
+        }
+    }
+
+    /// Describes the rule in the JSON Schema bound to `schema`, if it can be described
+    fn describe_schema(&self) -> Option<TokenStream> {
+        match self {
+            // a string has at least one byte if it has at least one character
+            Validator::NotEmpty => Some(quote!(::validator::describe_char_length(schema, Some(1), None);)),
+            Validator::And(left, right) | Validator::Then(left, right) => match (left.describe_schema(), right.describe_schema()) {
+                (None, None) => None,
+                (left, right) => Some(quote!(#left #right)),
+            },
+            // a rule which cannot be described may pass any value
+            Validator::Or(left, right) => {
+                let left = left.describe_schema()?;
+                let right = right.describe_schema()?;
+                Some(quote!(::validator::describe_any_of(schema, &[&|schema| { #left }, &|schema| { #right }]);))
+            }
+            Validator::Not(validator, _) => {
+                let validator = validator.describe_schema()?;
+                Some(quote!(::validator::describe_not(schema, |schema| { #validator });))
+            }
+            Validator::AllOf(children) => {
+                let children: Vec<_> = children.iter().filter_map(Validator::describe_schema).collect();
+                (!children.is_empty()).then(|| quote!(#(#children)*))
+            }
+            Validator::AnyOf(children) => {
+                let children = children.iter().map(Validator::describe_schema).collect::<Option<Vec<_>>>()?;
+                Some(quote!(::validator::describe_any_of(schema, &[#(&|schema| { #children }),*]);))
+            }
+            Validator::ExactlyOne(children) => {
+                let children = children.iter().map(Validator::describe_schema).collect::<Option<Vec<_>>>()?;
+                Some(quote!(::validator::describe_one_of(schema, &[#(&|schema| { #children }),*]);))
+            }
+            Validator::Email => Some(quote!(::validator::describe_format(schema, "email");)),
+            Validator::Url => Some(quote!(::validator::describe_format(schema, "uri");)),
+            Validator::IpAddr => Some(quote! {
+                ::validator::describe_any_of(schema, &[
+                    &|schema| ::validator::describe_format(schema, "ipv4"),
+                    &|schema| ::validator::describe_format(schema, "ipv6"),
+                ]);
+            }),
+            // JSON Schema measures strings in characters, other units only describing the length of collections
+            Validator::Length(min, max, unit) => {
+                let min = option_literal(min.as_ref());
+                let max = option_literal(max.as_ref());
+                match unit {
+                    Some(unit) if unit == "Chars" => Some(quote!(::validator::describe_char_length(schema, #min, #max);)),
+                    _ => Some(quote!(::validator::describe_length(schema, #min, #max);)),
+                }
+            }
+            Validator::Elements(elements) => {
+                let elements = elements.describe_schema()?;
+                Some(quote!(::validator::describe_items(schema, |schema| { #elements });))
+            }
+            Validator::Keys(keys) => {
+                let keys = keys.describe_schema()?;
+                Some(quote!(::validator::describe_keys(schema, |schema| { #keys });))
+            }
+            Validator::Values(values) => {
+                let values = values.describe_schema()?;
+                Some(quote!(::validator::describe_values(schema, |schema| { #values });))
+            }
+            Validator::Entries(keys, values) => {
+                let keys = keys.describe_schema()
+                    .map(|keys| quote!(::validator::describe_keys(schema, |schema| { #keys });));
+                let values = values.describe_schema()
+                    .map(|values| quote!(::validator::describe_values(schema, |schema| { #values });));
+                (keys.is_some() || values.is_some()).then(|| quote!(#keys #values))
+            }
+            Validator::Tuple(children) => {
+                let items: Vec<_> = children.iter().enumerate()
+                    .filter_map(|(index, child)| {
+                        let child = child.describe_schema()?;
+                        Some(quote!(::validator::describe_prefix_item(schema, #index, |schema| { #child });))
+                    })
+                    .collect();
+                (!items.is_empty()).then(|| quote!(#(#items)*))
+            }
+            Validator::Range(range) => {
+                let json = quote!(::validator::__private::serde_json::json!);
+                let start = match &range.start {
+                    Some(start) => quote!(::std::ops::Bound::Included(&#json(#start))),
+                    None => quote!(::std::ops::Bound::Unbounded),
+                };
+                let end = match (&range.end, &range.limits) {
+                    (Some(end), RangeLimits::HalfOpen(_)) => quote!(::std::ops::Bound::Excluded(&#json(#end))),
+                    (Some(end), RangeLimits::Closed(_)) => quote!(::std::ops::Bound::Included(&#json(#end))),
+                    (None, _) => quote!(::std::ops::Bound::Unbounded),
+                };
+                Some(quote!(::validator::describe_range(schema, #start, #end);))
+            }
+            Validator::Regex(RegexSource::Pattern(pattern)) => Some(quote!(::validator::describe_pattern(schema, #pattern);)),
+            Validator::Regex(RegexSource::Path(path)) => Some(quote!(::validator::describe_pattern(schema, #path.as_str());)),
+            Validator::OneOf(values, ignore_case) => {
+                describe_values(quote!(::validator::describe_enum), values, *ignore_case)
+            }
+            Validator::NoneOf(values, ignore_case) => {
+                describe_values(quote!(::validator::describe_forbidden), values, *ignore_case)
+            }
+            Validator::Message { validator, .. } => validator.describe_schema(),
+            // JSON Schema patterns have no flag ignoring the case, nor a standard way of escaping literals
+            Validator::Contains(_, _)
+            | Validator::StartsWith(_, _)
+            | Validator::EndsWith(_, _)
+            | Validator::Chars { .. }
+            | Validator::Required
+            | Validator::Default
+            | Validator::Ignore
+            | Validator::Custom { .. }
+            | Validator::With { .. } => None,
+        }
+    }
+
     fn create(&self, ty: &Type) -> Expr {
+        if let Some(inner) = self.unwrapped_option(ty) {
+            let validator = self.create(inner);
+            return parse_quote!(::validator::OptionValidator::new(#validator));
+        }
         match self {
             Validator::NotEmpty => parse_quote!(::validator::NotEmptyValidator),
             Validator::And(left, right) => {
@@ -1000,13 +2590,49 @@ impl Validator {
                 let right = right.create(ty);
                 parse_quote!(::validator::Or::new(#left, #right))
             }
+            Validator::Then(left, right) => {
+                let left = left.create(ty);
+                let right = right.create(ty);
+                parse_quote!(::validator::Then::new(#left, #right))
+            }
+            Validator::Not(validator, rule) => {
+                let validator = validator.create(ty);
+                parse_quote!(::validator::Not::new(#validator).with_rule(#rule))
+            }
+            Validator::AllOf(children) => {
+                let children = children.iter().map(|child| child.create(ty));
+                parse_quote!(::validator::AllOf::new((#(#children,)*)))
+            }
+            Validator::AnyOf(children) => {
+                let children = children.iter().map(|child| child.create(ty));
+                parse_quote!(::validator::AnyOf::new((#(#children,)*)))
+            }
+            Validator::ExactlyOne(children) => {
+                let children = children.iter().map(|child| child.create(ty));
+                parse_quote!(::validator::ExactlyOne::new((#(#children,)*)))
+            }
             Validator::Email => parse_quote!(::validator::EmailValidator),
             Validator::Url => parse_quote!(::validator::UrlValidator),
-            Validator::IpAddr => parse_quote!(::validator::IpAddrValidator),
-            Validator::Length(min, max) => {
+            Validator::IpAddr => parse_quote!(::validator::IpAddressValidator),
+            Validator::Length(min, max, unit) => {
                 let min = option_literal(min.as_ref());
                 let max = option_literal(max.as_ref());
-                parse_quote!(::validator::LengthValidator::new(#min, #max))
+                match unit {
+                    // the variant only exists with the graphemes feature, which is reported by name if it is disabled
+                    Some(unit) if unit == "Graphemes" => parse_quote! {
+                        ::validator::__require_graphemes!(::validator::LengthValidator::new(#min, #max).with_unit(::validator::LengthUnit::#unit))
+                    },
+                    Some(unit) => parse_quote!(::validator::LengthValidator::new(#min, #max).with_unit(::validator::LengthUnit::#unit)),
+                    None => parse_quote!(::validator::LengthValidator::new(#min, #max)),
+                }
+            }
+            Validator::Chars { classes, extra, trimmed } => {
+                let extra = extra.iter();
+                let trimmed = trimmed.then(|| quote!(.trimmed()));
+                parse_quote! {
+                    ::validator::CharsValidator::new([#(::validator::CharClass::#classes),*])
+                        #(.with_extra(#extra))* #trimmed
+                }
             }
             Validator::Default => parse_quote!(<#ty as ::validator::Validate>::validator()),
             Validator::Elements(elements) => {
@@ -1014,13 +2640,26 @@ impl Validator {
                 let elements = elements.create(&element_type);
                 parse_quote!(::validator::ElementsValidator::new(#elements))
             }
+            Validator::Keys(keys) => {
+                let keys = keys.create(&parse_quote!(<#ty as ::validator::HasEntries>::Key));
+                parse_quote!(::validator::KeysValidator::new(#keys))
+            }
+            Validator::Values(values) => {
+                let values = values.create(&parse_quote!(<#ty as ::validator::HasEntries>::Value));
+                parse_quote!(::validator::ValuesValidator::new(#values))
+            }
+            Validator::Entries(keys, values) => {
+                let keys = keys.create(&parse_quote!(<#ty as ::validator::HasEntries>::Key));
+                let values = values.create(&parse_quote!(<#ty as ::validator::HasEntries>::Value));
+                parse_quote!(::validator::EntriesValidator::new(#keys, #values))
+            }
             Validator::Tuple(children) => {
                 Expr::Tuple(ExprTuple {
                     attrs: vec![],
                     paren_token: Default::default(),
-                    elems: children.iter()
-                        .map(|child| {
-                            child.create(ty)
+                    elems: children.iter().enumerate()
+                        .map(|(index, child)| {
+                            child.create(tuple_element(ty, index))
                         }).collect(),
                 })
             }
@@ -1030,9 +2669,61 @@ impl Validator {
             Validator::Ignore => {
                 parse_quote!(::validator::IgnoreValidator)
             }
+            Validator::Required => parse_quote!(::validator::RequiredValidator),
+            // the regex is compiled once, its pattern having been checked when expanding
+            Validator::Regex(RegexSource::Pattern(pattern)) => parse_quote!({
+                static REGEX: ::validator::__private::Lazy<::validator::__private::Regex> = ::validator::__private::Lazy::new(|| {
+                    ::validator::__private::Regex::new(#pattern).expect("the pattern was checked by the derive")
+                });
+                ::validator::RegexValidator::new(::validator::__private::Regex::clone(&REGEX))
+            }),
+            Validator::Regex(RegexSource::Path(path)) => {
+                parse_quote!(::validator::RegexValidator::new(::validator::__private::Regex::clone(&#path)))
+            }
+            Validator::Contains(value, ignore_case) => {
+                let ignore_case = ignore_case.then(|| quote!(.ignore_case()));
+                parse_quote!(::validator::ContainsValidator::new(#value) #ignore_case)
+            }
+            Validator::StartsWith(value, ignore_case) => {
+                let ignore_case = ignore_case.then(|| quote!(.ignore_case()));
+                parse_quote!(::validator::PrefixValidator::new(#value) #ignore_case)
+            }
+            Validator::EndsWith(value, ignore_case) => {
+                let ignore_case = ignore_case.then(|| quote!(.ignore_case()));
+                parse_quote!(::validator::SuffixValidator::new(#value) #ignore_case)
+            }
+            Validator::OneOf(values, ignore_case) => {
+                let ignore_case = ignore_case.then(|| quote!(.ignore_case()));
+                parse_quote!(::validator::OneOfValidator::new([#(#values),*]) #ignore_case)
+            }
+            Validator::NoneOf(values, ignore_case) => {
+                let ignore_case = ignore_case.then(|| quote!(.ignore_case()));
+                parse_quote!(::validator::NoneOfValidator::new([#(#values),*]) #ignore_case)
+            }
+            // the closure lets the function take a dereferenced value, such as `&str` for a `String` field, and the
+            // errors of a function returning another error than the declared one point at the function
+            Validator::Custom { function, error } => {
+                let span = function.span();
+                parse_quote_spanned! {span=>
+                    ::validator::FnValidator::<_, #error>::new(|value| {
+                        ::validator::__private::CustomResult::<#error>::into_result(#function(value))
+                    })
+                }
+            }
+            Validator::With { validator, .. } => validator.clone(),
+            Validator::Message { validator, code, message } => {
+                let validator = validator.create(ty);
+                let code = code.iter();
+                let message = message.iter();
+                parse_quote!(::validator::MessageValidator::new(#validator) #(.with_code(#code))* #(.with_message(#message))*)
+            }
         }
     }
     fn validator_type(&self, ty: &Type) -> Type {
+        if let Some(inner) = self.unwrapped_option(ty) {
+            let validator = self.validator_type(inner);
+            return parse_quote!(::validator::OptionValidator<#validator>);
+        }
         match self {
             Validator::NotEmpty => parse_quote!(::validator::NotEmptyValidator),
             Validator::And(left, right) => {
@@ -1045,22 +2736,57 @@ impl Validator {
                 let right = right.validator_type(ty);
                 parse_quote!(::validator::Or<#left, #right>)
             }
+            Validator::Then(left, right) => {
+                let left = left.validator_type(ty);
+                let right = right.validator_type(ty);
+                parse_quote!(::validator::Then<#left, #right>)
+            }
+            Validator::Not(validator, _) => {
+                let validator = validator.validator_type(ty);
+                parse_quote!(::validator::Not<#validator>)
+            }
+            Validator::AllOf(children) => {
+                let children = children.iter().map(|child| child.validator_type(ty));
+                parse_quote!(::validator::AllOf<(#(#children,)*)>)
+            }
+            Validator::AnyOf(children) => {
+                let children = children.iter().map(|child| child.validator_type(ty));
+                parse_quote!(::validator::AnyOf<(#(#children,)*)>)
+            }
+            Validator::ExactlyOne(children) => {
+                let children = children.iter().map(|child| child.validator_type(ty));
+                parse_quote!(::validator::ExactlyOne<(#(#children,)*)>)
+            }
             Validator::Email => parse_quote!(::validator::EmailValidator),
             Validator::Url => parse_quote!(::validator::UrlValidator),
-            Validator::IpAddr => parse_quote!(::validator::IpAddrValidator),
-            Validator::Length(_, _) => parse_quote!(::validator::LengthValidator),
+            Validator::IpAddr => parse_quote!(::validator::IpAddressValidator),
+            Validator::Length(_, _, _) => parse_quote!(::validator::LengthValidator),
+            Validator::Chars { .. } => parse_quote!(::validator::CharsValidator),
             Validator::Default => parse_quote!(<#ty as ::validator::Validate>::Validator),
             Validator::Elements(elements) => {
                 let element_type = parse_quote!(<#ty as ::validator::HasElements>::Item);
                 let elements = elements.validator_type(&element_type);
                 parse_quote!(::validator::ElementsValidator<#elements>)
             }
+            Validator::Keys(keys) => {
+                let keys = keys.validator_type(&parse_quote!(<#ty as ::validator::HasEntries>::Key));
+                parse_quote!(::validator::KeysValidator<#keys>)
+            }
+            Validator::Values(values) => {
+                let values = values.validator_type(&parse_quote!(<#ty as ::validator::HasEntries>::Value));
+                parse_quote!(::validator::ValuesValidator<#values>)
+            }
+            Validator::Entries(keys, values) => {
+                let keys = keys.validator_type(&parse_quote!(<#ty as ::validator::HasEntries>::Key));
+                let values = values.validator_type(&parse_quote!(<#ty as ::validator::HasEntries>::Value));
+                parse_quote!(::validator::EntriesValidator<#keys, #values>)
+            }
             Validator::Tuple(children) => {
                 Type::Tuple(TypeTuple {
                     paren_token: Default::default(),
-                    elems: children.iter()
-                        .map(|child| {
-                            child.validator_type(ty)
+                    elems: children.iter().enumerate()
+                        .map(|(index, child)| {
+                            child.validator_type(tuple_element(ty, index))
                         }).collect(),
                 })
             }
@@ -1071,30 +2797,91 @@ impl Validator {
             Validator::Ignore => {
                 parse_quote!(::validator::IgnoreValidator)
             }
+            Validator::Required => parse_quote!(::validator::RequiredValidator),
+            Validator::Regex(_) => parse_quote!(::validator::RegexValidator),
+            Validator::Contains(_, _) => parse_quote!(::validator::ContainsValidator),
+            Validator::StartsWith(_, _) => parse_quote!(::validator::PrefixValidator),
+            Validator::EndsWith(_, _) => parse_quote!(::validator::SuffixValidator),
+            Validator::OneOf(values, ignore_case) => {
+                let values = value_set_type(values, *ignore_case, ty);
+                parse_quote!(::validator::OneOfValidator<#values>)
+            }
+            Validator::NoneOf(values, ignore_case) => {
+                let values = value_set_type(values, *ignore_case, ty);
+                parse_quote!(::validator::NoneOfValidator<#values>)
+            }
+            Validator::Custom { error, .. } => parse_quote!(::validator::FnValidator<#ty, #error>),
+            Validator::With { ty: validator, .. } => validator.clone(),
+            Validator::Message { validator, .. } => {
+                let validator = validator.validator_type(ty);
+                parse_quote!(::validator::MessageValidator<#validator>)
+            }
         }
     }
-    fn error_type(&self, ty: &Type) -> Type {
+    fn error_type(&self, ty: &Type, mode: Mode) -> Type {
+        if let Some(inner) = self.unwrapped_option(ty) {
+            return self.error_type(inner, mode);
+        }
         match self {
             Validator::NotEmpty => parse_quote!(::validator::EmptyValueError),
             Validator::And(left, right) => {
-                let left = left.error_type(ty);
-                let right = right.error_type(ty);
+                let left = left.error_type(ty, mode);
+                let right = right.error_type(ty, mode);
                 parse_quote!(::validator::AndError<#left, #right>)
             }
             Validator::Or(left, right) => {
-                let left = left.error_type(ty);
-                let right = right.error_type(ty);
-                parse_quote!((#left, #right))
+                let left = left.error_type(ty, mode);
+                let right = right.error_type(ty, mode);
+                parse_quote!(::validator::OrError<#left, #right>)
+            }
+            Validator::Then(left, right) => {
+                let left = left.error_type(ty, mode);
+                let right = right.error_type(ty, mode);
+                parse_quote!(::validator::ThenError<#left, #right>)
+            }
+            Validator::Not(_, _) => parse_quote!(::validator::NegatedRuleError),
+            Validator::AllOf(children) => {
+                let children = children.iter().map(|child| child.error_type(ty, mode));
+                parse_quote!(::validator::AllOfError<(#(::core::option::Option<#children>,)*)>)
+            }
+            Validator::AnyOf(children) => {
+                let children = children.iter().map(|child| child.error_type(ty, mode));
+                parse_quote!(::validator::AnyOfError<(#(#children,)*)>)
+            }
+            Validator::ExactlyOne(children) => {
+                let children = children.iter().map(|child| child.error_type(ty, mode));
+                parse_quote!(::validator::ExactlyOneError<(#(#children,)*)>)
             }
             Validator::Email => parse_quote!(::validator::InvalidEmailError),
             Validator::Url => parse_quote!(::validator::InvalidUrlError),
             Validator::IpAddr => parse_quote!(::std::net::AddrParseError),
-            Validator::Length(_, _) => parse_quote!(::validator::InvalidLengthError),
-            Validator::Default => parse_quote!(<<#ty as ::validator::Validate>::Validator as ::validator::Validator<#ty>>::Error),
+            Validator::Length(_, _, _) => parse_quote!(::validator::InvalidLengthError),
+            Validator::Chars { .. } => parse_quote!(::validator::InvalidCharError),
+            Validator::Default => match mode {
+                Mode::Sync => parse_quote!(<<#ty as ::validator::Validate>::Validator as ::validator::Validator<#ty>>::Error),
+                Mode::Context(context) => parse_quote!(<<#ty as ::validator::Validate>::Validator as ::validator::ValidatorWithContext<#ty, #context>>::Error),
+                Mode::Async => parse_quote!(<<#ty as ::validator::Validate>::Validator as ::validator::AsyncValidator<#ty>>::Error),
+            },
             Validator::Elements(elements) => {
                 let element_type = parse_quote!(<#ty as ::validator::HasElements>::Item);
-                let elements = elements.error_type(&element_type);
-                parse_quote!(::validator::ElementsInvalid<#elements>)
+                let elements = elements.error_type(&element_type, mode);
+                parse_quote!(<#ty as ::validator::HasElements>::Invalid<#elements>)
+            }
+            Validator::Keys(keys) => {
+                let key_type = parse_quote!(<#ty as ::validator::HasEntries>::Key);
+                let keys = keys.error_type(&key_type, mode);
+                parse_quote!(::validator::MapInvalid<#key_type, #keys>)
+            }
+            Validator::Values(values) => {
+                let key_type: Type = parse_quote!(<#ty as ::validator::HasEntries>::Key);
+                let values = values.error_type(&parse_quote!(<#ty as ::validator::HasEntries>::Value), mode);
+                parse_quote!(::validator::MapInvalid<#key_type, #values>)
+            }
+            Validator::Entries(keys, values) => {
+                let key_type = parse_quote!(<#ty as ::validator::HasEntries>::Key);
+                let keys = keys.error_type(&key_type, mode);
+                let values = values.error_type(&parse_quote!(<#ty as ::validator::HasEntries>::Value), mode);
+                parse_quote!(::validator::MapInvalid<#key_type, ::validator::EntryError<#keys, #values>>)
             }
             Validator::Tuple(children) => {
                 Type::Path(TypePath {
@@ -1111,9 +2898,9 @@ impl Validator {
                                 arguments: PathArguments::AngleBracketed(AngleBracketedGenericArguments {
                                     colon2_token: None,
                                     lt_token: Default::default(),
-                                    args: children.iter()
-                                        .map(|child| {
-                                            GenericArgument::Type(child.error_type(ty))
+                                    args: children.iter().enumerate()
+                                        .map(|(index, child)| {
+                                            GenericArgument::Type(child.error_type(tuple_element(ty, index), mode))
                                         }).collect(),
                                     gt_token: Default::default(),
                                 }),
@@ -1129,10 +2916,77 @@ impl Validator {
             Validator::Ignore => {
                 parse_quote!(::core::convert::Infallible)
             }
+            Validator::Required => parse_quote!(::validator::MissingValueError),
+            Validator::Regex(_) => parse_quote!(::validator::PatternMismatchError),
+            Validator::Contains(_, _) => parse_quote!(::validator::MissingSubstringError),
+            Validator::StartsWith(_, _) => parse_quote!(::validator::MissingPrefixError),
+            Validator::EndsWith(_, _) => parse_quote!(::validator::MissingSuffixError),
+            Validator::OneOf(values, ignore_case) => {
+                let values = value_set_type(values, *ignore_case, ty);
+                parse_quote!(::validator::NotOneOfError<#values>)
+            }
+            Validator::NoneOf(values, ignore_case) => {
+                let values = value_set_type(values, *ignore_case, ty);
+                parse_quote!(::validator::ForbiddenValueError<#values>)
+            }
+            Validator::Custom { error, .. } => error.clone(),
+            Validator::With { ty: validator, .. } => match mode {
+                Mode::Sync => parse_quote!(<#validator as ::validator::Validator<#ty>>::Error),
+                Mode::Context(context) => parse_quote!(<#validator as ::validator::ValidatorWithContext<#ty, #context>>::Error),
+                Mode::Async => parse_quote!(<#validator as ::validator::AsyncValidator<#ty>>::Error),
+            },
+            Validator::Message { validator, .. } => {
+                let error = validator.error_type(ty, mode);
+                parse_quote!(::validator::MessageError<#error>)
+            }
         }
     }
 }
 
+/// The trait implemented by validators of the given type in the given mode, along with their error if it is given
+fn validator_trait(ty: &Type, mode: Mode, error: Option<&Type>) -> Path {
+    let error = error.map(|error| quote!(, Error = #error));
+    match mode {
+        Mode::Sync => parse_quote!(::validator::Validator<#ty #error>),
+        Mode::Context(context) => parse_quote!(::validator::ValidatorWithContext<#ty, #context #error>),
+        Mode::Async => parse_quote!(::validator::AsyncValidator<#ty #error>),
+    }
+}
+
+/// Infers the type of a validator from the way it is constructed: `MyValidator`, `MyValidator(..)`,
+/// `MyValidator { .. }` or `MyValidator::new(..)`.
+fn infer_validator_type(validator: &Expr) -> syn::Result<Type> {
+    fn is_type_name(segment: &PathSegment) -> bool {
+        let name = segment.ident.to_string();
+        name.starts_with(|c: char| c.is_ascii_uppercase()) && name.chars().any(|c| c.is_ascii_lowercase())
+    }
+
+    let path = match validator {
+        Expr::Path(path) if path.qself.is_none() => Some(path.path.clone()),
+        Expr::Struct(expr) if expr.qself.is_none() => Some(expr.path.clone()),
+        Expr::Call(call) => match &*call.func {
+            Expr::Path(path) if path.qself.is_none() => {
+                let mut path = path.path.clone();
+                // a constructor function rather than a tuple struct
+                if path.segments.last().is_some_and(|segment| !is_type_name(segment)) {
+                    path.segments.pop();
+                    path.segments.pop_punct();
+                }
+                Some(path)
+            }
+            _ => None,
+        },
+        _ => None,
+    };
+    match path {
+        Some(path) if path.segments.last().is_some_and(is_type_name) => Ok(Type::Path(TypePath { qself: None, path })),
+        _ => Err(syn::Error::new(
+            validator.span(),
+            "failed to infer the type of the validator, use `with(validator, type = Type)` to specify it",
+        )),
+    }
+}
+
 fn range_type(range: &ExprRange, value: &Type) -> Type {
     match range {
         ExprRange {
@@ -1187,6 +3041,29 @@ fn range_type(range: &ExprRange, value: &Type) -> Type {
     }
 }
 
+/// The type of the value of an `Option` type, `Option<T>` being recognised by name
+fn option_inner(ty: &Type) -> Option<&Type> {
+    let Type::Path(TypePath { qself: None, path }) = ty else { return None; };
+    let segment = path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(arguments) = &segment.arguments else { return None; };
+    match arguments.args.first() {
+        Some(GenericArgument::Type(inner)) if arguments.args.len() == 1 => Some(inner),
+        _ => None,
+    }
+}
+
+/// The type of an element of a tuple type, the type itself being kept when it is not spelled as a tuple
+fn tuple_element(ty: &Type, index: usize) -> &Type {
+    match ty {
+        Type::Tuple(tuple) => tuple.elems.iter().nth(index).unwrap_or(ty),
+        Type::Paren(paren) => tuple_element(&paren.elem, index),
+        _ => ty,
+    }
+}
+
 fn option_literal<T: ToTokens>(opt: Option<T>) -> TokenStream {
     match opt {
         None => quote! { None },
@@ -1233,7 +3110,7 @@ impl<T> WithMessage for syn::Result<T> {
     #[cfg(test)]
     fn with_message(self, msg: &str) -> Self {
         self.map_err(|err| {
-            syn::Error::new(err.span(), format!("{msg}: {inner}", inner = err.to_string()))
+            syn::Error::new(err.span(), format!("{msg}: {err}"))
         })
     }
     #[cfg(not(test))]
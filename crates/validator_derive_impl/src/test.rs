@@ -1,5 +1,4 @@
 use quote::quote;
-use syn::DeriveInput;
 use crate::Input;
 
 #[test]
@@ -91,3 +90,329 @@ fn list_validator() {
 }
 
 
+
+#[test]
+fn context_validator() {
+    let input = quote! {
+        #[validator(context = Tenant)]
+        struct Team {
+            name: TenantName,
+            #[validator(elements)]
+            members: Vec<Member>,
+            #[validator(and(not_empty, email))]
+            contact: String,
+        }
+    };
+
+    let input: Input = syn::parse2(input).unwrap_or_else(|err| panic!("failed to parse input: {err}: {start:?} {end:?} ", start = err.span().start(), end = err.span().end()));
+    let output = super::derive(input);
+    let as_file = syn::parse_file(&output.to_string())
+        .unwrap_or_else(|err| panic!("failed to parse outputted code: {err}\n{}", &output.to_string()));
+    let formatted = prettyplease::unparse(&as_file);
+    insta::assert_snapshot!(formatted)
+}
+
+#[test]
+fn async_validator() {
+    let input = quote! {
+        #[validator(async)]
+        struct Signup {
+            username: Username,
+            #[validator(and(not_empty, email))]
+            email: String,
+            #[validator(elements)]
+            orders: Vec<OrderId>,
+        }
+    };
+
+    let input: Input = syn::parse2(input).unwrap_or_else(|err| panic!("failed to parse input: {err}: {start:?} {end:?} ", start = err.span().start(), end = err.span().end()));
+    let output = super::derive(input);
+    let as_file = syn::parse_file(&output.to_string())
+        .unwrap_or_else(|err| panic!("failed to parse outputted code: {err}\n{}", &output.to_string()));
+    let formatted = prettyplease::unparse(&as_file);
+    insta::assert_snapshot!(formatted)
+}
+
+#[test]
+fn fail_fast_validator() {
+    let input = quote! {
+        #[validator(fail_fast)]
+        struct Signup {
+            #[validator(and(not_empty, email))]
+            email: String,
+            #[validator(elements)]
+            orders: Vec<Order>,
+        }
+    };
+
+    let input: Input = syn::parse2(input).unwrap_or_else(|err| panic!("failed to parse input: {err}: {start:?} {end:?} ", start = err.span().start(), end = err.span().end()));
+    let output = super::derive(input);
+    let as_file = syn::parse_file(&output.to_string())
+        .unwrap_or_else(|err| panic!("failed to parse outputted code: {err}\n{}", &output.to_string()));
+    let formatted = prettyplease::unparse(&as_file);
+    insta::assert_snapshot!(formatted)
+}
+
+#[test]
+fn custom_validator() {
+    let input = quote! {
+        struct Account {
+            #[validator(and(not_empty, custom = no_spaces))]
+            username: String,
+            #[validator(elements(custom(even, error = Odd)))]
+            numbers: Vec<u32>,
+            #[validator(with = rules::Limit::new(2))]
+            limited: Vec<u32>,
+        }
+    };
+
+    let input: Input = syn::parse2(input).unwrap_or_else(|err| panic!("failed to parse input: {err}: {start:?} {end:?} ", start = err.span().start(), end = err.span().end()));
+    let output = super::derive(input);
+    let as_file = syn::parse_file(&output.to_string())
+        .unwrap_or_else(|err| panic!("failed to parse outputted code: {err}\n{}", &output.to_string()));
+    let formatted = prettyplease::unparse(&as_file);
+    insta::assert_snapshot!(formatted)
+}
+
+#[test]
+fn schema_validator() {
+    let input = quote! {
+        #[validator(must_match(password_confirmation, password), exactly_one_of(phone, email), schema = check_signup)]
+        struct Signup {
+            #[validator(length(min = 8))]
+            password: String,
+            #[validator(ignore)]
+            password_confirmation: String,
+            #[validator(ignore)]
+            phone: Option<String>,
+            #[validator(ignore)]
+            email: Option<String>,
+        }
+    };
+
+    let input: Input = syn::parse2(input).unwrap_or_else(|err| panic!("failed to parse input: {err}: {start:?} {end:?} ", start = err.span().start(), end = err.span().end()));
+    let output = super::derive(input);
+    let as_file = syn::parse_file(&output.to_string())
+        .unwrap_or_else(|err| panic!("failed to parse outputted code: {err}\n{}", &output.to_string()));
+    let formatted = prettyplease::unparse(&as_file);
+    insta::assert_snapshot!(formatted)
+}
+
+#[test]
+fn generic_validator() {
+    let input = quote! {
+        struct Page<'a, T: Clone> where T: 'a {
+            #[validator(elements)]
+            items: Vec<T>,
+            #[validator(length(min = 1))]
+            title: &'a str,
+            #[validator(range(1..=100))]
+            size: u32,
+        }
+    };
+
+    let input: Input = syn::parse2(input).unwrap_or_else(|err| panic!("failed to parse input: {err}: {start:?} {end:?} ", start = err.span().start(), end = err.span().end()));
+    let output = super::derive(input);
+    let as_file = syn::parse_file(&output.to_string())
+        .unwrap_or_else(|err| panic!("failed to parse outputted code: {err}\n{}", &output.to_string()));
+    let formatted = prettyplease::unparse(&as_file);
+    insta::assert_snapshot!(formatted)
+}
+
+#[test]
+fn optional_validator() {
+    let input = quote! {
+        struct Profile {
+            #[validator(email)]
+            email: Option<String>,
+            #[validator(and(required, length(min = 2)))]
+            name: Option<String>,
+            #[validator(range(18..))]
+            age: Option<u32>,
+            address: Option<Address>,
+        }
+    };
+
+    let input: Input = syn::parse2(input).unwrap_or_else(|err| panic!("failed to parse input: {err}: {start:?} {end:?} ", start = err.span().start(), end = err.span().end()));
+    let output = super::derive(input);
+    let as_file = syn::parse_file(&output.to_string())
+        .unwrap_or_else(|err| panic!("failed to parse outputted code: {err}\n{}", &output.to_string()));
+    let formatted = prettyplease::unparse(&as_file);
+    insta::assert_snapshot!(formatted)
+}
+
+#[test]
+fn map_validator() {
+    let input = quote! {
+        struct Config {
+            settings: HashMap<String, Setting>,
+            #[validator(keys(length(max = 8)))]
+            aliases: BTreeMap<String, String>,
+            #[validator(values(range(1..=10)))]
+            weights: BTreeMap<String, u32>,
+            #[validator(entries(not_empty, email))]
+            contacts: BTreeMap<String, String>,
+        }
+    };
+
+    let input: Input = syn::parse2(input).unwrap_or_else(|err| panic!("failed to parse input: {err}: {start:?} {end:?} ", start = err.span().start(), end = err.span().end()));
+    let output = super::derive(input);
+    let as_file = syn::parse_file(&output.to_string())
+        .unwrap_or_else(|err| panic!("failed to parse outputted code: {err}\n{}", &output.to_string()));
+    let formatted = prettyplease::unparse(&as_file);
+    insta::assert_snapshot!(formatted)
+}
+
+#[test]
+fn length_unit_validator() {
+    let input = quote! {
+        struct User {
+            #[validator(length(max = 10, unit = "chars"))]
+            name: String,
+            #[validator(length(min = 1, max = 16, unit = "utf16"))]
+            nickname: Option<String>,
+            #[validator(length(max = 1, unit = "graphemes"))]
+            emoji: String,
+        }
+    };
+
+    let input: Input = syn::parse2(input).unwrap_or_else(|err| panic!("failed to parse input: {err}: {start:?} {end:?} ", start = err.span().start(), end = err.span().end()));
+    let output = super::derive(input);
+    let as_file = syn::parse_file(&output.to_string())
+        .unwrap_or_else(|err| panic!("failed to parse outputted code: {err}\n{}", &output.to_string()));
+    let formatted = prettyplease::unparse(&as_file);
+    insta::assert_snapshot!(formatted)
+}
+
+#[test]
+fn message_validator() {
+    let input = quote! {
+        struct SignUp {
+            #[validator(length(min = 8), code = "password_short", message = "Use at least {min} characters")]
+            password: String,
+            #[validator(and(not_empty, code = "name_missing", email))]
+            email: Option<String>,
+        }
+    };
+
+    let input: Input = syn::parse2(input).unwrap_or_else(|err| panic!("failed to parse input: {err}: {start:?} {end:?} ", start = err.span().start(), end = err.span().end()));
+    let output = super::derive(input);
+    let as_file = syn::parse_file(&output.to_string())
+        .unwrap_or_else(|err| panic!("failed to parse outputted code: {err}\n{}", &output.to_string()));
+    let formatted = prettyplease::unparse(&as_file);
+    insta::assert_snapshot!(formatted)
+}
+
+#[test]
+fn regex_validator() {
+    let input = quote! {
+        struct User {
+            #[validator(regex = "^[a-z0-9_]+$")]
+            username: String,
+            #[validator(regex(path = PHONE_RE))]
+            phone: Option<String>,
+        }
+    };
+
+    let input: Input = syn::parse2(input).unwrap_or_else(|err| panic!("failed to parse input: {err}: {start:?} {end:?} ", start = err.span().start(), end = err.span().end()));
+    let output = super::derive(input);
+    let as_file = syn::parse_file(&output.to_string())
+        .unwrap_or_else(|err| panic!("failed to parse outputted code: {err}\n{}", &output.to_string()));
+    let formatted = prettyplease::unparse(&as_file);
+    insta::assert_snapshot!(formatted)
+}
+
+#[test]
+fn invalid_regex() {
+    let input = quote! {
+        struct User {
+            #[validator(regex = "^[a-z")]
+            username: String,
+        }
+    };
+
+    let Err(err) = syn::parse2::<Input>(input) else { panic!("the pattern should be rejected") };
+    assert!(err.to_string().contains("failed to parse validator from attrs"), "{err}");
+    let messages: Vec<_> = err.into_iter().map(|err| err.to_string()).collect();
+    assert!(messages.iter().any(|message| message.contains("invalid regex")), "{messages:?}");
+}
+
+#[test]
+fn content_validators() {
+    let input = quote! {
+        struct Post {
+            #[validator(starts_with = "sk_")]
+            key: String,
+            #[validator(contains(".com", ignore_case))]
+            site: Option<String>,
+            #[validator(one_of("draft", "published", ignore_case))]
+            status: String,
+            #[validator(none_of(0, 13))]
+            floor: u8,
+        }
+    };
+
+    let input: Input = syn::parse2(input).unwrap_or_else(|err| panic!("failed to parse input: {err}: {start:?} {end:?} ", start = err.span().start(), end = err.span().end()));
+    let output = super::derive(input);
+    let as_file = syn::parse_file(&output.to_string())
+        .unwrap_or_else(|err| panic!("failed to parse outputted code: {err}\n{}", &output.to_string()));
+    let formatted = prettyplease::unparse(&as_file);
+    insta::assert_snapshot!(formatted)
+}
+
+#[test]
+fn chars_validator() {
+    let input = quote! {
+        struct User {
+            #[validator(chars(ascii, alphanumeric, extra = "-_"))]
+            username: String,
+            #[validator(chars(printable, trimmed))]
+            display_name: Option<String>,
+        }
+    };
+
+    let input: Input = syn::parse2(input).unwrap_or_else(|err| panic!("failed to parse input: {err}: {start:?} {end:?} ", start = err.span().start(), end = err.span().end()));
+    let output = super::derive(input);
+    let as_file = syn::parse_file(&output.to_string())
+        .unwrap_or_else(|err| panic!("failed to parse outputted code: {err}\n{}", &output.to_string()));
+    let formatted = prettyplease::unparse(&as_file);
+    insta::assert_snapshot!(formatted)
+}
+
+#[test]
+fn variadic_validators() {
+    let input = quote! {
+        struct Contact {
+            #[validator(any(email, url, starts_with = "+"))]
+            address: String,
+            #[validator(all(not_empty, not(contains = ".."), length(max = 64)))]
+            path: Option<String>,
+            #[validator(exactly_one(email, ip))]
+            server: String,
+        }
+    };
+
+    let input: Input = syn::parse2(input).unwrap_or_else(|err| panic!("failed to parse input: {err}: {start:?} {end:?} ", start = err.span().start(), end = err.span().end()));
+    let output = super::derive(input);
+    let as_file = syn::parse_file(&output.to_string())
+        .unwrap_or_else(|err| panic!("failed to parse outputted code: {err}\n{}", &output.to_string()));
+    let formatted = prettyplease::unparse(&as_file);
+    insta::assert_snapshot!(formatted)
+}
+
+#[test]
+fn then_validator() {
+    let input = quote! {
+        struct User {
+            #[validator(then(not_empty, then(length(max = 254), email)))]
+            email: Option<String>,
+        }
+    };
+
+    let input: Input = syn::parse2(input).unwrap_or_else(|err| panic!("failed to parse input: {err}: {start:?} {end:?} ", start = err.span().start(), end = err.span().end()));
+    let output = super::derive(input);
+    let as_file = syn::parse_file(&output.to_string())
+        .unwrap_or_else(|err| panic!("failed to parse outputted code: {err}\n{}", &output.to_string()));
+    let formatted = prettyplease::unparse(&as_file);
+    insta::assert_snapshot!(formatted)
+}